@@ -0,0 +1,80 @@
+//!
+//! The `solc` import callback.
+//!
+
+use std::ffi::CStr;
+
+///
+/// The import callback.
+///
+/// Receives the `solc` source unit name of an imported file, and returns either its contents
+/// or an error message, which `solc` reports at the import directive.
+///
+pub type ImportCallback<'a> = dyn FnMut(&str) -> Result<String, String> + 'a;
+
+/// The `solc` callback kind requesting a source file.
+pub const KIND_SOURCE: &str = "source";
+
+extern "C" {
+    ///
+    /// Allocates memory that `solc` takes ownership of.
+    ///
+    fn solidity_alloc(size: usize) -> *mut ::libc::c_char;
+}
+
+///
+/// The C trampoline passed to `solidity_compile`.
+///
+/// `context` must point to a `&mut ImportCallback`.
+///
+/// Panics of the callback are caught and reported as errors, as unwinding into `solc` is undefined behavior.
+/// The contents with null bytes are rejected, as they would be truncated by `solc`.
+///
+pub(crate) unsafe extern "C" fn trampoline(
+    context: *mut ::libc::c_void,
+    kind: *const ::libc::c_char,
+    data: *const ::libc::c_char,
+    contents_pointer: *mut *mut ::libc::c_char,
+    error_pointer: *mut *mut ::libc::c_char,
+) {
+    *contents_pointer = std::ptr::null_mut();
+    *error_pointer = std::ptr::null_mut();
+
+    let kind = CStr::from_ptr(kind).to_string_lossy();
+    if kind != KIND_SOURCE {
+        *error_pointer = allocate(format!("Unsupported callback kind `{kind}`.").as_str());
+        return;
+    }
+
+    let callback = &mut *(context as *mut &mut ImportCallback);
+    let path = CStr::from_ptr(data).to_string_lossy();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(path.as_ref())))
+        .unwrap_or_else(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_owned());
+            Err(format!("Import callback for `{path}` panicked: {message}"))
+        });
+    match result {
+        Ok(contents) if contents.contains('\0') => {
+            *error_pointer = allocate(
+                format!("Contents of `{path}` contain a null byte, which is not supported.")
+                    .as_str(),
+            )
+        }
+        Ok(contents) => *contents_pointer = allocate(contents.as_str()),
+        Err(error) => *error_pointer = allocate(error.as_str()),
+    }
+}
+
+///
+/// Copies `value` into a null-terminated string allocated by `solc`.
+///
+unsafe fn allocate(value: &str) -> *mut ::libc::c_char {
+    let pointer = solidity_alloc(value.len() + 1);
+    std::ptr::copy_nonoverlapping(value.as_ptr(), pointer as *mut u8, value.len());
+    *pointer.add(value.len()) = 0;
+    pointer
+}
@@ -9,6 +9,14 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::result_large_err)]
 
+pub mod import_callback;
+pub mod version_mismatch;
+
+pub use self::import_callback::ImportCallback;
+pub use self::version_mismatch::VersionMismatch;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::ffi::CStr;
 use std::ffi::CString;
 use std::path::PathBuf;
@@ -39,6 +47,30 @@ extern "C" {
         error_pointer: *mut *mut ::libc::c_char,
     ) -> *const std::os::raw::c_char;
 
+    ///
+    /// Pass standard JSON input to the Solidity compiler, resolving imports via `read_callback`.
+    ///
+    /// The output must be released with `solidity_free`.
+    ///
+    fn solidity_compile(
+        input: *const ::libc::c_char,
+        read_callback: Option<
+            unsafe extern "C" fn(
+                *mut ::libc::c_void,
+                *const ::libc::c_char,
+                *const ::libc::c_char,
+                *mut *mut ::libc::c_char,
+                *mut *mut ::libc::c_char,
+            ),
+        >,
+        read_context: *mut ::libc::c_void,
+    ) -> *mut ::libc::c_char;
+
+    ///
+    /// Releases the memory returned by `solidity_compile`.
+    ///
+    fn solidity_free(data: *mut ::libc::c_char);
+
     ///
     /// Get the Solidity compiler version.
     ///
@@ -54,6 +86,23 @@ impl Default for Compiler {
 }
 
 impl Compiler {
+    /// The `stopAfter` value making `solc` stop after parsing and import resolution.
+    pub const STOP_AFTER_PARSING: &'static str = "parsing";
+
+    ///
+    /// Checks whether the `solc` version satisfies the `required` range.
+    ///
+    pub fn check_version(&self, required: &semver::VersionReq) -> Result<(), VersionMismatch> {
+        if required.matches(&self.version.default) {
+            return Ok(());
+        }
+
+        Err(VersionMismatch {
+            found: self.version.default.to_owned(),
+            required: required.to_owned(),
+        })
+    }
+
     ///
     /// The Solidity `--standard-json` mirror.
     ///
@@ -65,6 +114,42 @@ impl Compiler {
         include_paths: Vec<String>,
         allow_paths: Option<String>,
     ) -> anyhow::Result<solx_standard_json::Output> {
+        self.standard_json_with(input_json, messages, |input_json| {
+            self.standard_json_raw(input_json, base_path, include_paths, allow_paths)
+        })
+    }
+
+    ///
+    /// The Solidity `--standard-json` mirror, where the imports are resolved via `callback`.
+    ///
+    /// The file system is not accessed, so the sources may come from a virtual file system.
+    /// The callback errors are reported by `solc` at the import directives.
+    ///
+    pub fn standard_json_with_callback(
+        &self,
+        input_json: &mut solx_standard_json::Input,
+        messages: &mut Vec<solx_standard_json::OutputError>,
+        callback: &mut ImportCallback,
+    ) -> anyhow::Result<solx_standard_json::Output> {
+        self.standard_json_with(input_json, messages, |input_json| {
+            self.standard_json_raw_with_callback(input_json, callback)
+        })
+    }
+
+    ///
+    /// Prepares the output selection of `input_json` for `solc`, calls `compile`, and restores it.
+    ///
+    /// The ignored warnings are removed from the output, and `messages` are appended to it.
+    ///
+    fn standard_json_with<F>(
+        &self,
+        input_json: &mut solx_standard_json::Input,
+        messages: &mut Vec<solx_standard_json::OutputError>,
+        compile: F,
+    ) -> anyhow::Result<solx_standard_json::Output>
+    where
+        F: FnOnce(&solx_standard_json::Input) -> anyhow::Result<solx_standard_json::Output>,
+    {
         let original_output_selection = input_json.settings.output_selection.to_owned();
 
         input_json.settings.output_selection.retain_solc();
@@ -72,11 +157,47 @@ impl Compiler {
             .settings
             .output_selection
             .set_selector(solx_standard_json::InputSelector::Metadata);
+        if original_output_selection
+            .is_set_for_any(solx_standard_json::InputSelector::BytecodeObject)
+            || original_output_selection
+                .is_set_for_any(solx_standard_json::InputSelector::RuntimeBytecodeObject)
+        {
+            input_json
+                .settings
+                .output_selection
+                .set_file_selector(solx_standard_json::InputSelector::AST);
+        }
         input_json
             .settings
             .output_selection
             .set_selector(input_json.settings.via_ir.into());
 
+        let mut solc_output = compile(input_json)?;
+
+        input_json.settings.output_selection = original_output_selection;
+        solc_output
+            .errors
+            .retain(|error| match error.error_code.as_deref() {
+                Some(code) => {
+                    !solx_standard_json::OutputError::IGNORED_WARNING_CODES.contains(&code)
+                }
+                None => true,
+            });
+        solc_output.errors.append(messages);
+
+        Ok(solc_output)
+    }
+
+    ///
+    /// Passes `input_json` to `solc` as is, and parses the output.
+    ///
+    fn standard_json_raw(
+        &self,
+        input_json: &solx_standard_json::Input,
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+    ) -> anyhow::Result<solx_standard_json::Output> {
         let input_string = serde_json::to_string(input_json).expect("Always valid");
         let input_c_string = CString::new(input_string).expect("Always valid");
 
@@ -135,30 +256,74 @@ impl Compiler {
                 .into_owned()
         };
 
-        let mut solc_output = match era_compiler_common::deserialize_from_str::<
+        Self::parse_output(output_string.as_str())
+    }
+
+    ///
+    /// Passes `input_json` to `solc` as is, resolving the imports via `callback`, and parses the output.
+    ///
+    fn standard_json_raw_with_callback(
+        &self,
+        input_json: &solx_standard_json::Input,
+        callback: &mut ImportCallback,
+    ) -> anyhow::Result<solx_standard_json::Output> {
+        let input_string = serde_json::to_string(input_json).expect("Always valid");
+        let input_c_string = CString::new(input_string).expect("Always valid");
+
+        let mut context: &mut ImportCallback = callback;
+        let output_string = unsafe {
+            let output_pointer = solidity_compile(
+                input_c_string.as_ptr(),
+                Some(import_callback::trampoline),
+                &mut context as *mut &mut ImportCallback as *mut ::libc::c_void,
+            );
+            let output_string = CStr::from_ptr(output_pointer)
+                .to_string_lossy()
+                .into_owned();
+            solidity_free(output_pointer);
+            output_string
+        };
+
+        Self::parse_output(output_string.as_str())
+    }
+
+    ///
+    /// Parses the `solc` standard JSON output.
+    ///
+    fn parse_output(output_string: &str) -> anyhow::Result<solx_standard_json::Output> {
+        let solc_output = match era_compiler_common::deserialize_from_str::<
             solx_standard_json::Output,
-        >(output_string.as_str())
+        >(output_string)
         {
             Ok(solc_output) => solc_output,
             Err(error) => {
                 anyhow::bail!("solc standard JSON output parsing: {error:?}");
             }
         };
-
-        input_json.settings.output_selection = original_output_selection;
-        solc_output
-            .errors
-            .retain(|error| match error.error_code.as_deref() {
-                Some(code) => {
-                    !solx_standard_json::OutputError::IGNORED_WARNING_CODES.contains(&code)
-                }
-                None => true,
-            });
-        solc_output.errors.append(messages);
-
         Ok(solc_output)
     }
 
+    ///
+    /// Parses the Solidity sources of `input_json` and resolves their imports, without compiling them.
+    ///
+    /// Only the AST is requested, and `solc` stops after parsing, so the output `sources`
+    /// list all the files loaded via imports. Unresolved imports are reported as errors.
+    ///
+    pub fn parse_standard_json(
+        &self,
+        input_json: &mut solx_standard_json::Input,
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+    ) -> anyhow::Result<solx_standard_json::Output> {
+        input_json.settings.output_selection =
+            solx_standard_json::InputSelection::new(BTreeSet::from([
+                solx_standard_json::InputSelector::AST,
+            ]));
+        input_json.settings.stop_after = Some(Self::STOP_AFTER_PARSING.to_owned());
+        self.standard_json_raw(input_json, base_path, include_paths, allow_paths)
+    }
+
     ///
     /// Validates the Yul project as paths and libraries.
     ///
@@ -179,6 +344,26 @@ impl Compiler {
         self.validate_yul_standard_json(&mut solc_input, messages)
     }
 
+    ///
+    /// Validates the Yul project sources.
+    ///
+    pub fn validate_yul_sources(
+        &self,
+        sources: BTreeMap<String, solx_standard_json::InputSource>,
+        libraries: era_compiler_common::Libraries,
+        messages: &mut Vec<solx_standard_json::OutputError>,
+    ) -> anyhow::Result<solx_standard_json::Output> {
+        let mut solc_input = solx_standard_json::Input::from_yul_sources(
+            sources,
+            libraries,
+            solx_standard_json::InputOptimizer::default(),
+            solx_standard_json::InputSelection::default(),
+            solx_standard_json::InputMetadata::default(),
+            vec![],
+        );
+        self.validate_yul_standard_json(&mut solc_input, messages)
+    }
+
     ///
     /// Validates the Yul project as standard JSON input.
     ///
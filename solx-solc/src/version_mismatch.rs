@@ -0,0 +1,28 @@
+//!
+//! The `solc` version mismatch error.
+//!
+
+///
+/// The `solc` version mismatch error.
+///
+/// Returned if the detected `solc` version does not satisfy the required range.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionMismatch {
+    /// The detected `solc` version.
+    pub found: semver::Version,
+    /// The required version range.
+    pub required: semver::VersionReq,
+}
+
+impl std::error::Error for VersionMismatch {}
+
+impl std::fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`solc` version {} does not satisfy the required range `{}`.",
+            self.found, self.required
+        )
+    }
+}
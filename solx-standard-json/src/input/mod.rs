@@ -3,6 +3,7 @@
 //!
 
 pub mod language;
+pub mod schema;
 pub mod settings;
 pub mod source;
 
@@ -20,6 +21,7 @@ use crate::input::settings::optimizer::Optimizer as InputSettingsOptimizer;
 use crate::input::settings::selection::Selection as InputSettingsSelection;
 
 use self::language::Language;
+use self::schema::Schema;
 use self::settings::Settings;
 use self::source::Source;
 
@@ -43,14 +45,33 @@ impl Input {
     ///
     /// If the `path` is `None`, the input is read from the stdin.
     ///
-    pub fn try_from(path: Option<&Path>) -> anyhow::Result<Self> {
+    /// The input is validated against the [`Schema`] before being deserialized.
+    /// If `strict_settings` is set, unknown keys in the settings are rejected instead of being ignored.
+    ///
+    pub fn try_from(path: Option<&Path>, strict_settings: bool) -> anyhow::Result<Self> {
         let input_json = match path {
             Some(path) => std::fs::read_to_string(path)
                 .map_err(|error| anyhow::anyhow!("Standard JSON file {path:?} reading: {error}")),
             None => std::io::read_to_string(std::io::stdin())
                 .map_err(|error| anyhow::anyhow!("Standard JSON reading from stdin: {error}")),
         }?;
-        era_compiler_common::deserialize_from_str::<Self>(input_json.as_str())
+        Self::try_from_str(input_json.as_str(), strict_settings)
+    }
+
+    ///
+    /// A shortcut constructor from the standard JSON `input_json` string.
+    ///
+    /// See [`Self::try_from`] for the validation details.
+    ///
+    pub fn try_from_str(input_json: &str, strict_settings: bool) -> anyhow::Result<Self> {
+        let input_value =
+            era_compiler_common::deserialize_from_str::<serde_json::Value>(input_json)
+                .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))?;
+        let errors = Schema::input(strict_settings).validate(&input_value);
+        if !errors.is_empty() {
+            anyhow::bail!("Standard JSON validation: {}.", errors.join(".\n"));
+        }
+        era_compiler_common::deserialize_from_str::<Self>(input_json)
             .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))
     }
 
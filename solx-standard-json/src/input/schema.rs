@@ -0,0 +1,342 @@
+//!
+//! The `solc --standard-json` input schema.
+//!
+
+///
+/// The `solc --standard-json` input schema.
+///
+/// Describes the expected types of the known input fields, so that malformed input is reported
+/// with the path of the offending field instead of a deserialization error.
+/// Unknown fields are ignored unless the object is strict, and missing required fields are left
+/// to the deserializer.
+///
+#[derive(Debug)]
+pub enum Schema {
+    /// Any value.
+    Any,
+    /// A boolean.
+    Boolean,
+    /// A non-negative integer.
+    Integer,
+    /// A string.
+    String,
+    /// A single-character string.
+    Character,
+    /// An array of elements of the same schema.
+    Array(Box<Self>),
+    /// An object with arbitrary keys and values of the same schema.
+    Map(Box<Self>),
+    /// An object with known keys.
+    Object(Vec<(&'static str, Self)>),
+    /// An object with known keys only.
+    StrictObject(Vec<(&'static str, Self)>),
+}
+
+impl Schema {
+    /// The maximum edit distance between an unknown key and the known one suggested instead.
+    pub const SUGGESTION_MAX_DISTANCE: usize = 2;
+
+    ///
+    /// Returns the schema of the whole input.
+    ///
+    /// If `strict_settings` is set, unknown keys in the settings are reported as violations.
+    ///
+    pub fn input(strict_settings: bool) -> Self {
+        let settings = Self::settings();
+        Self::Object(vec![
+            ("language", Self::String),
+            (
+                "sources",
+                Self::Map(Box::new(Self::Object(vec![
+                    ("content", Self::String),
+                    ("urls", Self::Array(Box::new(Self::String))),
+                ]))),
+            ),
+            (
+                "settings",
+                if strict_settings {
+                    settings.into_strict()
+                } else {
+                    settings
+                },
+            ),
+        ])
+    }
+
+    ///
+    /// Validates `value` against the schema.
+    ///
+    /// Returns the list of violations, each starting with the path of the offending field.
+    ///
+    pub fn validate(&self, value: &serde_json::Value) -> Vec<String> {
+        let mut errors = Vec::new();
+        self.validate_at(value, String::new(), &mut errors);
+        errors
+    }
+
+    ///
+    /// Returns the schema of the compiler settings.
+    ///
+    fn settings() -> Self {
+        Self::Object(vec![
+            (
+                "optimizer",
+                Self::Object(vec![
+                    ("enabled", Self::Boolean),
+                    ("mode", Self::Character),
+                    ("sizeFallback", Self::Boolean),
+                    ("contractModes", Self::Map(Box::new(Self::Character))),
+                    ("runs", Self::Integer),
+                    ("details", Self::Any),
+                ]),
+            ),
+            (
+                "libraries",
+                Self::Map(Box::new(Self::Map(Box::new(Self::String)))),
+            ),
+            ("remappings", Self::Array(Box::new(Self::String))),
+            ("evmVersion", Self::String),
+            ("viaIR", Self::Boolean),
+            (
+                "outputSelection",
+                Self::Map(Box::new(Self::Map(Box::new(Self::Array(Box::new(
+                    Self::String,
+                )))))),
+            ),
+            (
+                "metadata",
+                Self::Object(vec![
+                    ("useLiteralContent", Self::Boolean),
+                    ("appendCBOR", Self::Boolean),
+                    ("bytecodeHash", Self::String),
+                    ("compilerVersion", Self::String),
+                ]),
+            ),
+            ("llvmOptions", Self::Array(Box::new(Self::String))),
+            ("outputTimings", Self::Boolean),
+            ("strictSizeLimit", Self::Boolean),
+        ])
+    }
+
+    ///
+    /// Makes all objects in the schema strict.
+    ///
+    fn into_strict(self) -> Self {
+        match self {
+            Self::Array(element) => Self::Array(Box::new(element.into_strict())),
+            Self::Map(element) => Self::Map(Box::new(element.into_strict())),
+            Self::Object(fields) | Self::StrictObject(fields) => Self::StrictObject(
+                fields
+                    .into_iter()
+                    .map(|(key, schema)| (key, schema.into_strict()))
+                    .collect(),
+            ),
+            schema => schema,
+        }
+    }
+
+    ///
+    /// Validates `value` located at `path`, appending the violations to `errors`.
+    ///
+    fn validate_at(&self, value: &serde_json::Value, path: String, errors: &mut Vec<String>) {
+        let is_valid = match self {
+            Self::Any => true,
+            Self::Boolean => value.is_boolean(),
+            Self::Integer => value.is_u64(),
+            Self::String => value.is_string(),
+            Self::Character => value
+                .as_str()
+                .map(|string| string.chars().count() == 1)
+                .unwrap_or_default(),
+            Self::Array(element) => match value.as_array() {
+                Some(array) => {
+                    for (index, item) in array.iter().enumerate() {
+                        element.validate_at(item, format!("{path}[{index}]"), errors);
+                    }
+                    true
+                }
+                None => false,
+            },
+            Self::Map(element) => match value.as_object() {
+                Some(object) => {
+                    for (key, item) in object.iter() {
+                        element.validate_at(item, format!("{path}[{key:?}]"), errors);
+                    }
+                    true
+                }
+                None => false,
+            },
+            Self::Object(fields) | Self::StrictObject(fields) => match value.as_object() {
+                Some(object) => {
+                    for (key, schema) in fields.iter() {
+                        if let Some(item) = object.get(*key) {
+                            schema.validate_at(item, Self::field_path(path.as_str(), key), errors);
+                        }
+                    }
+                    if let Self::StrictObject(_) = self {
+                        for key in object.keys() {
+                            if fields.iter().any(|(known, _schema)| known == key) {
+                                continue;
+                            }
+                            let path = Self::field_path(path.as_str(), key);
+                            match Self::suggest(key.as_str(), fields.as_slice()) {
+                                Some(suggestion) => errors.push(format!(
+                                    "{path} is an unknown field (did you mean `{suggestion}`)"
+                                )),
+                                None => errors.push(format!("{path} is an unknown field")),
+                            }
+                        }
+                    }
+                    true
+                }
+                None => false,
+            },
+        };
+        if !is_valid {
+            let path = if path.is_empty() {
+                "input"
+            } else {
+                path.as_str()
+            };
+            errors.push(format!("{path} must be {self}"));
+        }
+    }
+
+    ///
+    /// Returns the path of the field `key` of the object located at `path`.
+    ///
+    fn field_path(path: &str, key: &str) -> String {
+        if path.is_empty() {
+            key.to_owned()
+        } else {
+            format!("{path}.{key}")
+        }
+    }
+
+    ///
+    /// Returns the known key closest to the unknown `key`, if it is close enough to be a typo.
+    ///
+    fn suggest(key: &str, fields: &[(&'static str, Self)]) -> Option<&'static str> {
+        fields
+            .iter()
+            .map(|(known, _schema)| (Self::edit_distance(key, known), *known))
+            .filter(|(distance, _known)| *distance <= Self::SUGGESTION_MAX_DISTANCE)
+            .min_by_key(|(distance, _known)| *distance)
+            .map(|(_distance, known)| known)
+    }
+
+    ///
+    /// Returns the case-insensitive Levenshtein distance between `a` and `b`.
+    ///
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let a = a.to_lowercase().chars().collect::<Vec<char>>();
+        let b = b.to_lowercase().chars().collect::<Vec<char>>();
+
+        let mut previous = (0..=b.len()).collect::<Vec<usize>>();
+        for (i, a_char) in a.iter().enumerate() {
+            let mut current = vec![i + 1; b.len() + 1];
+            for (j, b_char) in b.iter().enumerate() {
+                let substitution = previous[j] + (a_char != b_char) as usize;
+                current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            }
+            previous = current;
+        }
+        previous[b.len()]
+    }
+}
+
+impl std::fmt::Display for Schema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "any value"),
+            Self::Boolean => write!(f, "a boolean"),
+            Self::Integer => write!(f, "a non-negative integer"),
+            Self::String => write!(f, "a string"),
+            Self::Character => write!(f, "a single-character string"),
+            Self::Array(element) => write!(f, "an array of elements, each being {element}"),
+            Self::Map(_) | Self::Object(_) | Self::StrictObject(_) => write!(f, "an object"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Schema;
+
+    #[test]
+    fn default() {
+        let input = serde_json::json!({
+            "language": "Solidity",
+            "sources": {
+                "Test.sol": {
+                    "content": "contract Test {}"
+                }
+            },
+            "settings": {
+                "optimizer": {
+                    "mode": "3",
+                    "runs": 200
+                },
+                "outputSelection": {
+                    "*": {
+                        "*": ["abi"]
+                    }
+                }
+            }
+        });
+        assert!(Schema::input(false).validate(&input).is_empty());
+    }
+
+    #[test]
+    fn invalid_type() {
+        let input = serde_json::json!({
+            "language": "Solidity",
+            "sources": {
+                "Test.sol": {
+                    "urls": "Test.sol"
+                }
+            },
+            "settings": {
+                "optimizer": {
+                    "runs": "200"
+                }
+            }
+        });
+        let errors = Schema::input(false).validate(&input);
+        assert_eq!(
+            errors,
+            vec![
+                "sources[\"Test.sol\"].urls must be an array of elements, each being a string"
+                    .to_owned(),
+                "settings.optimizer.runs must be a non-negative integer".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn strict_settings() {
+        let input = serde_json::json!({
+            "language": "Solidity",
+            "sources": {},
+            "settings": {
+                "optimiser": {
+                    "mode": "3"
+                },
+                "metadata": {
+                    "bytecodeHash": "none",
+                    "unknown": true
+                }
+            }
+        });
+        assert!(Schema::input(false).validate(&input).is_empty());
+
+        let errors = Schema::input(true).validate(&input);
+        assert_eq!(
+            errors,
+            vec![
+                "settings.metadata.unknown is an unknown field".to_owned(),
+                "settings.optimiser is an unknown field (did you mean `optimizer`)".to_owned(),
+            ]
+        );
+    }
+}
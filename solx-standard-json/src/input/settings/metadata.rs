@@ -21,8 +21,16 @@ pub struct Metadata {
     pub append_cbor: bool,
 
     /// The metadata hash type.
-    #[serde(default = "Metadata::default_bytecode_hash", skip_serializing)]
+    #[serde(
+        default = "Metadata::default_bytecode_hash",
+        deserialize_with = "Metadata::deserialize_bytecode_hash",
+        skip_serializing
+    )]
     pub bytecode_hash: era_compiler_common::EVMMetadataHashType,
+
+    /// The compiler version overriding the one in the metadata `compiler` field.
+    #[serde(default, skip_serializing)]
+    pub compiler_version: Option<String>,
 }
 
 impl Default for Metadata {
@@ -32,6 +40,9 @@ impl Default for Metadata {
 }
 
 impl Metadata {
+    /// The Swarm metadata hash type supported by `solc`, but not by `solx`.
+    pub const BYTECODE_HASH_BZZR1: &'static str = "bzzr1";
+
     ///
     /// A shortcut constructor.
     ///
@@ -44,9 +55,28 @@ impl Metadata {
             bytecode_hash: hash_type,
             append_cbor,
             use_literal_content,
+            compiler_version: None,
         }
     }
 
+    ///
+    /// Parses the metadata hash type.
+    ///
+    /// `bzzr1` is rejected explicitly, as the EVM assembler only appends the IPFS hash to the CBOR metadata.
+    ///
+    pub fn parse_bytecode_hash(
+        value: &str,
+    ) -> anyhow::Result<era_compiler_common::EVMMetadataHashType> {
+        if value == Self::BYTECODE_HASH_BZZR1 {
+            anyhow::bail!(
+                "Metadata hash `{value}` is not supported. Available types: `none`, `ipfs`."
+            );
+        }
+        serde_json::from_value(serde_json::Value::String(value.to_owned())).map_err(|_| {
+            anyhow::anyhow!("Metadata hash `{value}` is unknown. Available types: `none`, `ipfs`.")
+        })
+    }
+
     ///
     /// The default metadata hash type.
     ///
@@ -54,6 +84,19 @@ impl Metadata {
         era_compiler_common::EVMMetadataHashType::IPFS
     }
 
+    ///
+    /// Deserializes the metadata hash type with the errors of `parse_bytecode_hash`.
+    ///
+    fn deserialize_bytecode_hash<'de, D>(
+        deserializer: D,
+    ) -> Result<era_compiler_common::EVMMetadataHashType, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::parse_bytecode_hash(value.as_str()).map_err(serde::de::Error::custom)
+    }
+
     ///
     /// The default append CBOR flag.
     ///
@@ -49,10 +49,20 @@ pub struct Settings {
     /// The metadata settings.
     #[serde(default)]
     pub metadata: Metadata,
+    /// The compilation stage `solc` stops after, e.g. `parsing`.
+    /// Only set internally, as the outputs produced by `solx` require the full compilation.
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub stop_after: Option<String>,
 
     /// The extra LLVM options.
     #[serde(default, skip_serializing)]
     pub llvm_options: Vec<String>,
+    /// Whether to output the per-contract code generation timings.
+    #[serde(default, skip_serializing)]
+    pub output_timings: bool,
+    /// Whether exceeding the runtime code size limit is an error.
+    #[serde(default, skip_serializing)]
+    pub strict_size_limit: bool,
 }
 
 impl Settings {
@@ -86,7 +96,10 @@ impl Settings {
 
             output_selection,
             metadata,
+            stop_after: None,
             llvm_options,
+            output_timings: false,
+            strict_size_limit: false,
         }
     }
 
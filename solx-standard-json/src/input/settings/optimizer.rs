@@ -2,6 +2,8 @@
 //! The `solc --standard-json` input settings optimizer.
 //!
 
+use std::collections::BTreeMap;
+
 ///
 /// The `solc --standard-json` input settings optimizer.
 ///
@@ -14,6 +16,10 @@ pub struct Optimizer {
     /// Whether to try to recompile with -Oz if the bytecode is too large.
     #[serde(default, skip_serializing)]
     pub size_fallback: bool,
+    /// The per-contract optimization mode overrides.
+    /// Keys are matched against the full contract path, the contract name, or the source path.
+    #[serde(default, skip_serializing)]
+    pub contract_modes: BTreeMap<String, char>,
 
     /// Enable the solc optimizer.
     /// Always `true` in order to allow library inlining.
@@ -35,6 +41,7 @@ impl Optimizer {
         Self {
             mode,
             size_fallback,
+            contract_modes: BTreeMap::new(),
 
             enabled: Self::default_enabled(),
         }
@@ -67,20 +67,36 @@ impl Selection {
     ///
     /// Checks if the output element of the specified contract is selected.
     ///
+    /// Following `solc`, the selections of the `*` wildcard and of the exact path or name are merged
+    /// on both levels, so e.g. the bytecode can be requested for a single file on top of the ABI for all files.
+    ///
+    /// The AST is only selected with the file-level `""` key, as it is not produced per contract.
+    ///
     pub fn check_selection(&self, path: &str, name: Option<&str>, selector: Selector) -> bool {
-        if let Some(file) = self.inner.get("*").or(self.inner.get(path)) {
-            if let (Some(any), selector @ Selector::AST) = (file.get(""), selector) {
-                return any.contains(&selector);
-            }
-            if let Some(name) = name {
-                if let Some(contract) = file.get("*").or(file.get(name)) {
-                    return contract.contains(&selector);
-                }
-            } else {
-                return true;
-            }
+        let mut files = [self.inner.get("*"), self.inner.get(path)]
+            .into_iter()
+            .flatten()
+            .peekable();
+        if files.peek().is_none() {
+            return false;
         }
-        false
+
+        if let Selector::AST = selector {
+            return files.any(|file| {
+                file.get("")
+                    .map(|any| any.contains(&selector))
+                    .unwrap_or_default()
+            });
+        }
+        let Some(name) = name else {
+            return true;
+        };
+        files.any(|file| {
+            [file.get("*"), file.get(name)]
+                .into_iter()
+                .flatten()
+                .any(|contract| contract.contains(&selector))
+        })
     }
 
     ///
@@ -94,6 +110,15 @@ impl Selection {
         }
     }
 
+    ///
+    /// Adds the specified file-level selector, e.g. the AST, to the output selection of all files.
+    ///
+    pub fn set_file_selector(&mut self, selector: Selector) {
+        for file in self.inner.values_mut() {
+            file.entry("".to_owned()).or_default().insert(selector);
+        }
+    }
+
     ///
     /// Retains only the selectors that request data from `solc`.
     ///
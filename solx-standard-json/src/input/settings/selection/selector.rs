@@ -46,6 +46,9 @@ pub enum Selector {
     /// The runtime bytecode.
     #[serde(rename = "evm.deployedBytecode.object")]
     RuntimeBytecodeObject,
+    /// The gas estimates.
+    #[serde(rename = "evm.gasEstimates")]
+    GasEstimates,
 
     /// The catch-all variant.
     #[serde(other)]
@@ -59,7 +62,7 @@ impl Selector {
     pub fn is_received_from_solc(&self) -> bool {
         !matches!(
             self,
-            Self::BytecodeObject | Self::RuntimeBytecodeObject | Self::Other
+            Self::BytecodeObject | Self::RuntimeBytecodeObject | Self::GasEstimates | Self::Other
         )
     }
 }
@@ -8,7 +8,7 @@ use std::path::PathBuf;
 ///
 /// The `solc --standard-json` input source.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Source {
     /// The source code file content.
@@ -20,11 +20,16 @@ pub struct Source {
 }
 
 impl Source {
+    /// The path denoting the standard input.
+    pub const STDIN_PATH: &'static str = "-";
+
     ///
     /// Reads the source from the file system.
     ///
+    /// If `path` is [`Self::STDIN_PATH`], the source is read from the standard input.
+    ///
     pub fn try_read(path: &Path) -> anyhow::Result<Self> {
-        let content = if path.to_string_lossy() == "-" {
+        let content = if path.to_string_lossy() == Self::STDIN_PATH {
             std::io::read_to_string(std::io::stdin())
                 .map_err(|error| anyhow::anyhow!("<stdin> reading: {error}"))
         } else {
@@ -24,10 +24,18 @@ pub use self::input::Input;
 pub use self::output::contract::evm::bytecode::Bytecode as OutputContractEVMBytecode;
 pub use self::output::contract::evm::extra_metadata::recursive_function::RecursiveFunction as OutputContractEVMExtraMetadataRecursiveFunction;
 pub use self::output::contract::evm::extra_metadata::ExtraMetadata as OutputContractEVMExtraMetadata;
+pub use self::output::contract::evm::gas_estimates::GasEstimates as OutputContractEVMGasEstimates;
+pub use self::output::contract::evm::immutable_reference::ImmutableReference as OutputContractEVMImmutableReference;
 pub use self::output::contract::evm::EVM as OutputContractEVM;
 pub use self::output::contract::Contract as OutputContract;
 pub use self::output::error::collectable::Collectable as CollectableError;
+pub use self::output::error::color::Color as OutputErrorColor;
+pub use self::output::error::escalation::Escalation as OutputErrorEscalation;
+pub use self::output::error::exit_reason::ExitReason as OutputErrorExitReason;
 pub use self::output::error::source_location::SourceLocation as OutputErrorSourceLocation;
+pub use self::output::error::suppression::Suppression as OutputErrorSuppression;
 pub use self::output::error::Error as OutputError;
+pub use self::output::source::Source as OutputSource;
+pub use self::output::timing::Timing as OutputTiming;
 pub use self::output::Output;
 pub use self::version::Version;
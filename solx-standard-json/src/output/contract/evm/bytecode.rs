@@ -2,8 +2,11 @@
 //! The `solc --standard-json` output contract EVM bytecode.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::output::contract::evm::immutable_reference::ImmutableReference;
+
 ///
 /// The `solc --standard-json` output contract EVM bytecode.
 ///
@@ -23,6 +26,18 @@ pub struct Bytecode {
     /// Binary object format.
     #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
     pub format: Option<era_compiler_common::ObjectFormat>,
+    /// Source map.
+    /// Serialized as `null` if not available for the compilation mode.
+    #[serde(default, skip_deserializing)]
+    pub source_map: Option<String>,
+    /// Immutable identifiers mapped to their references in the runtime bytecode.
+    /// Only set for the runtime bytecode, where it is serialized even if empty.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub immutable_references: Option<BTreeMap<String, Vec<ImmutableReference>>>,
+    /// Whether the CBOR metadata is appended to the bytecode.
+    /// Only set for the runtime bytecode, so consumers know if the metadata has been omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub cbor_metadata: Option<bool>,
 }
 
 impl Bytecode {
@@ -33,11 +48,15 @@ impl Bytecode {
         object: String,
         unlinked_references: BTreeSet<String>,
         format: era_compiler_common::ObjectFormat,
+        source_map: Option<String>,
     ) -> Self {
         Self {
             object,
             unlinked_references,
             format: Some(format),
+            source_map,
+            immutable_references: None,
+            cbor_metadata: None,
         }
     }
 }
@@ -0,0 +1,27 @@
+//!
+//! The `solc --standard-json` output contract EVM creation gas estimates.
+//!
+
+///
+/// The `solc --standard-json` output contract EVM creation gas estimates.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Creation {
+    /// The cost of storing the runtime code.
+    pub code_deposit_cost: String,
+}
+
+impl Creation {
+    /// The code deposit cost per byte of the runtime code.
+    pub const CODE_DEPOSIT_COST_PER_BYTE: usize = 200;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(runtime_size: usize) -> Self {
+        Self {
+            code_deposit_cost: (runtime_size * Self::CODE_DEPOSIT_COST_PER_BYTE).to_string(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! The `solc --standard-json` output contract EVM gas estimates.
+//!
+
+pub mod creation;
+
+use self::creation::Creation;
+
+///
+/// The `solc --standard-json` output contract EVM gas estimates.
+///
+/// The estimates are derived from the bytecode only, so only the costs that do not depend on the
+/// control flow are reported. The execution costs of the deploy code and functions are omitted.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimates {
+    /// The contract creation estimates.
+    pub creation: Creation,
+}
+
+impl GasEstimates {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(runtime_size: usize) -> Self {
+        Self {
+            creation: Creation::new(runtime_size),
+        }
+    }
+}
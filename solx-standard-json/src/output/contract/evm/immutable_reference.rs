@@ -0,0 +1,29 @@
+//!
+//! The `solc --standard-json` output contract EVM immutable reference.
+//!
+
+///
+/// The `solc --standard-json` output contract EVM immutable reference.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ImmutableReference {
+    /// The offset of the immutable value in the runtime bytecode.
+    pub start: u64,
+    /// The size of the immutable value.
+    pub length: u64,
+}
+
+impl ImmutableReference {
+    /// The size of an immutable value, which always occupies a full EVM word.
+    pub const LENGTH: u64 = era_compiler_common::BYTE_LENGTH_FIELD as u64;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: u64) -> Self {
+        Self {
+            start,
+            length: Self::LENGTH,
+        }
+    }
+}
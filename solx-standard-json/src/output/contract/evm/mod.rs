@@ -4,12 +4,15 @@
 
 pub mod bytecode;
 pub mod extra_metadata;
+pub mod gas_estimates;
+pub mod immutable_reference;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use self::bytecode::Bytecode;
 use self::extra_metadata::ExtraMetadata;
+use self::gas_estimates::GasEstimates;
 
 ///
 /// The `solc --standard-json` output contract EVM data.
@@ -29,6 +32,9 @@ pub struct EVM {
     /// The contract function signatures.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub method_identifiers: BTreeMap<String, String>,
+    /// The contract gas estimates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_estimates: Option<GasEstimates>,
 
     /// The extra EVMLA metadata.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -52,11 +58,13 @@ impl EVM {
             deploy_bytecode,
             deploy_unlinked_libraries,
             deploy_object_format,
+            None,
         ));
         self.deployed_bytecode = Some(Bytecode::new(
             runtime_bytecode,
             runtime_unlinked_libraries,
             runtime_object_format,
+            None,
         ));
     }
 
@@ -68,6 +76,7 @@ impl EVM {
             && self.deployed_bytecode.is_none()
             && self.legacy_assembly.is_null()
             && self.method_identifiers.is_empty()
+            && self.gas_estimates.is_none()
             && self.extra_metadata.is_none()
     }
 }
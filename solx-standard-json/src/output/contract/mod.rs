@@ -36,6 +36,9 @@ pub struct Contract {
     /// The EVM data of the contract.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub evm: Option<EVM>,
+    /// The optimization mode, if overridden for this contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimization_mode: Option<char>,
 }
 
 impl Contract {
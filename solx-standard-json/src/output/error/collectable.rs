@@ -4,6 +4,8 @@
 
 use std::io::Write;
 
+use crate::output::error::color::Color;
+use crate::output::error::exit_reason::ExitReason;
 use crate::output::error::Error;
 
 ///
@@ -21,6 +23,8 @@ pub trait Collectable {
     ///
     /// Extracts warnings from the list of messages.
     ///
+    /// Implementors supporting warning suppression do not return the suppressed warnings.
+    ///
     fn take_warnings(&mut self) -> Vec<Error>;
 
     ///
@@ -51,7 +55,9 @@ pub trait Collectable {
     ///
     /// Checks for errors, exiting the application if there is at least one error.
     ///
-    fn exit_on_error(&self) {
+    /// The exit code depends on the class of the errors. See [`ExitReason`] for details.
+    ///
+    fn exit_on_error(&self, color: Color) {
         if !self.has_errors() {
             return;
         }
@@ -60,19 +66,20 @@ pub trait Collectable {
             .write_all(
                 self.errors()
                     .iter()
-                    .map(|error| error.to_string())
+                    .map(|error| error.to_terminal_string(color))
                     .collect::<Vec<String>>()
                     .join("\n")
                     .as_bytes(),
             )
             .expect("Stderr writing error");
-        std::process::exit(era_compiler_common::EXIT_CODE_FAILURE);
+        let exit_reason = ExitReason::from_errors(self.errors()).unwrap_or(ExitReason::Failure);
+        std::process::exit(exit_reason.code());
     }
 
     ///
     /// Removes warnings from the list of messages and prints them to stderr.
     ///
-    fn take_and_write_warnings(&mut self) {
+    fn take_and_write_warnings(&mut self, color: Color) {
         let warnings = self.take_warnings();
         if warnings.is_empty() {
             return;
@@ -82,7 +89,7 @@ pub trait Collectable {
             "{}",
             warnings
                 .into_iter()
-                .map(|error| error.to_string())
+                .map(|error| error.to_terminal_string(color))
                 .collect::<Vec<String>>()
                 .join("\n")
         )
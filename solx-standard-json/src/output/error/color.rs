@@ -0,0 +1,89 @@
+//!
+//! The terminal color mode.
+//!
+
+use std::io::IsTerminal;
+
+///
+/// The terminal color mode.
+///
+/// Only affects the human-readable output. JSON output is never colored.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Colors are enabled if the output stream is a terminal.
+    #[default]
+    Auto,
+    /// Colors are always enabled.
+    Always,
+    /// Colors are always disabled.
+    Never,
+}
+
+impl Color {
+    /// The ANSI sequence of errors.
+    pub const ANSI_RED: &'static str = "\x1b[31m";
+
+    /// The ANSI sequence of warnings.
+    pub const ANSI_YELLOW: &'static str = "\x1b[33m";
+
+    /// The ANSI sequence of success messages.
+    pub const ANSI_GREEN: &'static str = "\x1b[32m";
+
+    /// The ANSI sequence resetting the style.
+    pub const ANSI_RESET: &'static str = "\x1b[0m";
+
+    ///
+    /// Checks whether colors are enabled for `stream`.
+    ///
+    pub fn is_enabled(self, stream: &impl IsTerminal) -> bool {
+        match self {
+            Self::Auto => stream.is_terminal(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+
+    ///
+    /// Wraps `text` into the ANSI `sequence` if colors are enabled for `stream`.
+    ///
+    pub fn paint(self, text: &str, sequence: &str, stream: &impl IsTerminal) -> String {
+        if self.is_enabled(stream) {
+            format!("{sequence}{text}{}", Self::ANSI_RESET)
+        } else {
+            text.to_owned()
+        }
+    }
+
+    ///
+    /// Formats a success message for `stream`.
+    ///
+    pub fn success(self, text: &str, stream: &impl IsTerminal) -> String {
+        self.paint(text, Self::ANSI_GREEN, stream)
+    }
+}
+
+impl std::str::FromStr for Color {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            string => anyhow::bail!(
+                "Invalid color mode `{string}`. Available modes: `auto`, `always`, `never`."
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Always => write!(f, "always"),
+            Self::Never => write!(f, "never"),
+        }
+    }
+}
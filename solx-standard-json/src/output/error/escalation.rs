@@ -0,0 +1,52 @@
+//!
+//! The escalated warnings.
+//!
+
+use std::collections::BTreeSet;
+
+use crate::output::error::Error;
+
+///
+/// The escalated warnings.
+///
+/// Warnings with escalated codes are promoted to errors when they are collected, so specific
+/// semantic checks, e.g. the use of `block.timestamp` as a source of randomness, can be enforced
+/// without turning every warning into an error.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Escalation {
+    /// The escalated warning codes.
+    codes: BTreeSet<String>,
+}
+
+impl Escalation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(codes: BTreeSet<String>) -> Self {
+        Self { codes }
+    }
+
+    ///
+    /// Checks whether `message` is a warning with an escalated code.
+    ///
+    pub fn is_escalated(&self, message: &Error) -> bool {
+        message.severity == "warning"
+            && message
+                .error_code
+                .as_ref()
+                .map(|code| self.codes.contains(code))
+                .unwrap_or_default()
+    }
+
+    ///
+    /// Promotes the warnings with escalated codes in `messages` to errors.
+    ///
+    pub fn apply(&self, messages: &mut [Error]) {
+        for message in messages.iter_mut() {
+            if self.is_escalated(message) {
+                message.promote_to_error();
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//!
+//! The process exit reason.
+//!
+
+use crate::output::error::Error;
+
+///
+/// The process exit reason.
+///
+/// Each reason is mapped to a distinct exit code, so that scripts can branch on the failure cause.
+/// The codes are a part of the public interface and must stay stable across releases.
+///
+/// The variants are ordered by the compilation stage, so the earliest failed stage is reported
+/// if there are errors of several classes.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExitReason {
+    /// Reading the input or writing the output has failed.
+    IO,
+    /// The source code could not be parsed.
+    Syntax,
+    /// The bytecode could not be linked.
+    Linker,
+    /// The compilation has exceeded the time limit.
+    Timeout,
+    /// Any other failure.
+    Failure,
+}
+
+impl ExitReason {
+    /// The exit code of IO errors.
+    pub const EXIT_CODE_IO: i32 = 2;
+
+    /// The exit code of syntax errors.
+    pub const EXIT_CODE_SYNTAX: i32 = 3;
+
+    /// The exit code of linker errors.
+    pub const EXIT_CODE_LINKER: i32 = 4;
+
+    /// The exit code of timeout errors.
+    pub const EXIT_CODE_TIMEOUT: i32 = 5;
+
+    ///
+    /// Returns the exit reason of the most relevant error in the list.
+    ///
+    /// Returns `None` if there are no errors.
+    ///
+    pub fn from_errors<'a>(errors: impl IntoIterator<Item = &'a Error>) -> Option<Self> {
+        errors
+            .into_iter()
+            .filter(|error| error.severity == "error")
+            .map(Error::exit_reason)
+            .min()
+    }
+
+    ///
+    /// Returns the process exit code.
+    ///
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::IO => Self::EXIT_CODE_IO,
+            Self::Syntax => Self::EXIT_CODE_SYNTAX,
+            Self::Linker => Self::EXIT_CODE_LINKER,
+            Self::Timeout => Self::EXIT_CODE_TIMEOUT,
+            Self::Failure => era_compiler_common::EXIT_CODE_FAILURE,
+        }
+    }
+}
@@ -3,13 +3,19 @@
 //!
 
 pub mod collectable;
+pub mod color;
+pub mod escalation;
+pub mod exit_reason;
 pub mod mapped_location;
 pub mod source_location;
+pub mod suppression;
 
 use std::collections::BTreeMap;
 
 use crate::input::source::Source as InputSource;
 
+use self::color::Color;
+use self::exit_reason::ExitReason;
 use self::mapped_location::MappedLocation;
 use self::source_location::SourceLocation;
 
@@ -41,6 +47,22 @@ impl Error {
     /// The list of ignored `solc` warnings that are strictly EVM-related.
     pub const IGNORED_WARNING_CODES: [&'static str; 5] = ["1699", "3860", "5159", "5574", "6417"];
 
+    /// The component of messages emitted by the linker.
+    pub const COMPONENT_LINKER: &'static str = "linker";
+
+    /// The error type reported for source code that cannot be parsed.
+    pub const TYPE_PARSER: &'static str = "ParserError";
+
+    /// The error types reported for syntactically invalid source code.
+    pub const TYPES_SYNTAX: [&'static str; 3] =
+        ["DocstringParsingError", Self::TYPE_PARSER, "SyntaxError"];
+
+    /// The error type reported for input and output failures.
+    pub const TYPE_IO: &'static str = "IOError";
+
+    /// The error type reported for compilations exceeding the time limit.
+    pub const TYPE_TIMEOUT: &'static str = "TimeoutError";
+
     ///
     /// A shortcut constructor.
     ///
@@ -115,6 +137,85 @@ impl Error {
     {
         Self::new("Warning", error_code, message, source_location, sources)
     }
+
+    ///
+    /// Sets the component that has emitted the message.
+    ///
+    pub fn with_component(mut self, component: &str) -> Self {
+        self.component = component.to_owned();
+        self
+    }
+
+    ///
+    /// Sets the error type, keeping the severity unchanged.
+    ///
+    pub fn with_type(mut self, r#type: &str) -> Self {
+        if let Some(formatted_message) = self
+            .formatted_message
+            .strip_prefix(self.r#type.as_str())
+            .map(|message| format!("{type}{message}"))
+        {
+            self.formatted_message = formatted_message;
+        }
+        self.r#type = r#type.to_owned();
+        self
+    }
+
+    ///
+    /// Returns the process exit reason of the message.
+    ///
+    pub fn exit_reason(&self) -> ExitReason {
+        if self.component == Self::COMPONENT_LINKER {
+            ExitReason::Linker
+        } else if Self::TYPES_SYNTAX.contains(&self.r#type.as_str()) {
+            ExitReason::Syntax
+        } else if self.r#type == Self::TYPE_IO {
+            ExitReason::IO
+        } else if self.r#type == Self::TYPE_TIMEOUT {
+            ExitReason::Timeout
+        } else {
+            ExitReason::Failure
+        }
+    }
+
+    ///
+    /// Formats the message for stderr, coloring the severity marker if enabled by `color`.
+    ///
+    pub fn to_terminal_string(&self, color: Color) -> String {
+        let sequence = match self.severity.as_str() {
+            "error" => Color::ANSI_RED,
+            "warning" => Color::ANSI_YELLOW,
+            _ => return self.formatted_message.to_owned(),
+        };
+        match self.formatted_message.strip_prefix(self.r#type.as_str()) {
+            Some(message) => format!(
+                "{}{message}",
+                color.paint(self.r#type.as_str(), sequence, &std::io::stderr())
+            ),
+            None => self.formatted_message.to_owned(),
+        }
+    }
+
+    ///
+    /// Promotes the warning to an error.
+    ///
+    /// Messages of other severities are left unchanged.
+    ///
+    pub fn promote_to_error(&mut self) {
+        if self.severity != "warning" {
+            return;
+        }
+
+        if let Some(formatted_message) = self
+            .formatted_message
+            .strip_prefix(self.r#type.as_str())
+            .map(|message| format!("Error{message}"))
+        {
+            self.formatted_message = formatted_message;
+        }
+        self.r#type = "Error".to_owned();
+        self.severity = "error".to_owned();
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -0,0 +1,56 @@
+//!
+//! The suppressed warnings.
+//!
+
+use std::collections::BTreeSet;
+
+use crate::output::error::Error;
+
+///
+/// The suppressed warnings.
+///
+/// Warnings with suppressed codes are removed from both the terminal and standard JSON output,
+/// but returned separately, so they can still be counted and revealed upon request.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Suppression {
+    /// The suppressed warning codes.
+    codes: BTreeSet<String>,
+}
+
+impl Suppression {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(codes: BTreeSet<String>) -> Self {
+        Self { codes }
+    }
+
+    ///
+    /// Checks whether `message` is a warning with a suppressed code.
+    ///
+    pub fn is_suppressed(&self, message: &Error) -> bool {
+        message.severity == "warning"
+            && message
+                .error_code
+                .as_ref()
+                .map(|code| self.codes.contains(code))
+                .unwrap_or_default()
+    }
+
+    ///
+    /// Splits `messages` into the retained and suppressed ones.
+    ///
+    /// Only messages with the warning severity are checked, so errors are never dropped.
+    ///
+    pub fn filter(&self, messages: Vec<Error>) -> (Vec<Error>, Vec<Error>) {
+        if self.codes.is_empty() {
+            return (messages, vec![]);
+        }
+
+        let (suppressed, messages): (Vec<Error>, Vec<Error>) = messages
+            .into_iter()
+            .partition(|message| self.is_suppressed(message));
+        (messages, suppressed)
+    }
+}
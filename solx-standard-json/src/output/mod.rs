@@ -5,8 +5,11 @@
 pub mod contract;
 pub mod error;
 pub mod source;
+pub mod timing;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io::Write;
 
 use crate::input::settings::selection::selector::Selector as InputSettingsSelector;
 use crate::input::settings::selection::Selection as InputSettingsSelection;
@@ -14,9 +17,12 @@ use crate::input::source::Source as InputSource;
 
 use self::contract::Contract;
 use self::error::collectable::Collectable as CollectableError;
+use self::error::escalation::Escalation;
 use self::error::source_location::SourceLocation as JsonOutputErrorSourceLocation;
+use self::error::suppression::Suppression;
 use self::error::Error as JsonOutputError;
 use self::source::Source;
+use self::timing::Timing;
 
 ///
 /// The `solc --standard-json` output.
@@ -24,6 +30,7 @@ use self::source::Source;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Output {
     /// The file-contract hashmap.
+    /// Contracts are ordered lexicographically by path, and then by name.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub contracts: BTreeMap<String, BTreeMap<String, Contract>>,
     /// The source code mapping data.
@@ -32,9 +39,32 @@ pub struct Output {
     /// The compilation errors and warnings.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<JsonOutputError>,
+    /// The per-contract code generation timings.
+    /// Only present if requested with `settings.outputTimings`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<BTreeMap<String, Timing>>,
+    /// The output schema version.
+    /// See [`Self::VERSION`] for details.
+    #[serde(rename = "solxOutputVersion", default = "Output::version")]
+    pub version: u32,
+    /// The warnings promoted to errors by code.
+    #[serde(skip)]
+    escalation: Escalation,
+    /// The warnings removed from the output by code.
+    #[serde(skip)]
+    suppression: Suppression,
+    /// The warnings suppressed so far, which are not written to the output.
+    #[serde(skip)]
+    suppressed: Vec<JsonOutputError>,
 }
 
 impl Output {
+    /// The schema version of all `solx` JSON outputs, including the JSON Lines records.
+    /// Incremented each time the schema changes incompatibly, e.g. a field is removed or
+    /// changes its meaning, so consumers can reject the versions they do not support.
+    /// Adding fields is not considered an incompatible change.
+    pub const VERSION: u32 = 1;
+
     ///
     /// Initializes a standard JSON output.
     ///
@@ -54,6 +84,11 @@ impl Output {
             contracts: BTreeMap::new(),
             sources,
             errors: std::mem::take(messages),
+            timings: None,
+            version: Self::VERSION,
+            escalation: Escalation::default(),
+            suppression: Suppression::default(),
+            suppressed: Vec::new(),
         }
     }
 
@@ -67,13 +102,93 @@ impl Output {
             contracts: BTreeMap::new(),
             sources: BTreeMap::new(),
             errors: messages,
+            timings: None,
+            version: Self::VERSION,
+            escalation: Escalation::default(),
+            suppression: Suppression::default(),
+            suppressed: Vec::new(),
         }
     }
 
+    ///
+    /// Sets the warning codes escalated to errors and suppressed in this output.
+    ///
+    pub fn set_warning_codes(&mut self, escalation: Escalation, suppression: Suppression) {
+        self.escalation = escalation;
+        self.suppression = suppression;
+    }
+
+    ///
+    /// Records the warnings suppressed outside of this output, e.g. by the build.
+    ///
+    pub fn extend_suppressed(&mut self, suppressed: impl IntoIterator<Item = JsonOutputError>) {
+        self.suppressed.extend(suppressed);
+    }
+
+    ///
+    /// Takes the warnings suppressed so far.
+    ///
+    pub fn take_suppressed(&mut self) -> Vec<JsonOutputError> {
+        std::mem::take(&mut self.suppressed)
+    }
+
     ///
     /// Prunes the output JSON and prints it to stdout.
     ///
-    pub fn write_and_exit(mut self, output_selection: &InputSettingsSelection) -> ! {
+    /// Suppressed warnings are removed from the messages.
+    ///
+    pub fn write_and_exit(mut self, output_selection: &InputSettingsSelection, pretty: bool) -> ! {
+        self.prune(output_selection);
+        self.write_pruned_and_exit(pretty);
+    }
+
+    ///
+    /// Prints the already pruned output JSON to stdout.
+    ///
+    /// Suppressed warnings are removed from the messages.
+    ///
+    pub fn write_pruned_and_exit(self, pretty: bool) -> ! {
+        std::io::stdout()
+            .write_all(self.into_pruned_json(pretty).as_bytes())
+            .expect("Stdout writing error");
+        std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
+    }
+
+    ///
+    /// Serializes the already pruned output JSON.
+    ///
+    /// The output is a single line, unless `pretty` is set, in which case it is indented with 2 spaces.
+    /// Escalated warnings are promoted to errors, and suppressed warnings are removed from the messages.
+    ///
+    pub fn into_pruned_json(mut self, pretty: bool) -> String {
+        self.escalation.apply(self.errors.as_mut_slice());
+        let (errors, _suppressed) = self.suppression.filter(std::mem::take(&mut self.errors));
+        self.errors = errors;
+        if pretty {
+            serde_json::to_string_pretty(&self).expect("Always valid")
+        } else {
+            serde_json::to_string(&self).expect("Always valid")
+        }
+    }
+
+    ///
+    /// Returns the current schema version for the deserialized outputs, e.g. the ones produced by `solc`.
+    ///
+    fn version() -> u32 {
+        Self::VERSION
+    }
+
+    ///
+    /// Removes the output not requested by `output_selection`.
+    ///
+    /// The `solc` AST is retained only for the sources it is requested for with `ast`.
+    ///
+    pub fn prune(&mut self, output_selection: &InputSettingsSelection) {
+        for (path, source) in self.sources.iter_mut() {
+            if !output_selection.check_selection(path.as_str(), None, InputSettingsSelector::AST) {
+                source.ast = None;
+            }
+        }
         for (path, file) in self.contracts.iter_mut() {
             for (name, contract) in file.iter_mut() {
                 if !output_selection.check_selection(
@@ -98,6 +213,20 @@ impl Output {
                     ) {
                         evm.legacy_assembly = serde_json::Value::Null;
                     }
+                    if !output_selection.check_selection(
+                        path.as_str(),
+                        Some(name.as_str()),
+                        InputSettingsSelector::MethodIdentifiers,
+                    ) {
+                        evm.method_identifiers.clear();
+                    }
+                    if !output_selection.check_selection(
+                        path.as_str(),
+                        Some(name.as_str()),
+                        InputSettingsSelector::GasEstimates,
+                    ) {
+                        evm.gas_estimates = None;
+                    }
                 }
                 if contract
                     .evm
@@ -114,9 +243,65 @@ impl Output {
             contracts.retain(|_, contract| !contract.is_empty());
             !contracts.is_empty()
         });
+    }
 
-        serde_json::to_writer(std::io::stdout(), &self).expect("Stdout writing error");
-        std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
+    ///
+    /// Merges the output of another standard JSON input into this one.
+    ///
+    /// Contracts defined in both outputs are reported as errors, and the ones from `self` are kept.
+    /// Source IDs are not renumbered, as they are only meaningful within their own input.
+    ///
+    pub fn merge(&mut self, other: Self) {
+        for (path, contracts) in other.contracts.into_iter() {
+            let file = self.contracts.entry(path.clone()).or_default();
+            for (name, contract) in contracts.into_iter() {
+                if file.contains_key(name.as_str()) {
+                    self.errors.push(JsonOutputError::new_error(
+                        None,
+                        format!(
+                            "Contract `{path}:{name}` is defined in multiple standard JSON inputs."
+                        ),
+                        Some(JsonOutputErrorSourceLocation::new(path.clone())),
+                        None,
+                    ));
+                    continue;
+                }
+                file.insert(name, contract);
+            }
+        }
+        for (path, source) in other.sources.into_iter() {
+            self.sources.entry(path).or_insert(source);
+        }
+        self.errors.extend(other.errors);
+        if let Some(timings) = other.timings {
+            self.timings
+                .get_or_insert_with(BTreeMap::new)
+                .extend(timings);
+        }
+    }
+
+    ///
+    /// Warns about the `remappings` that have not been applied to any import.
+    ///
+    /// A remapping is considered applied if any source unit name starts with its target,
+    /// as `solc` replaces the matching import prefix with the target.
+    ///
+    pub fn check_unused_remappings(&mut self, remappings: &BTreeSet<String>) {
+        for remapping in remappings.iter() {
+            let target = match remapping.split_once('=') {
+                Some((_prefix, target)) => target,
+                None => continue,
+            };
+            if self.sources.keys().any(|path| path.starts_with(target)) {
+                continue;
+            }
+            self.errors.push(JsonOutputError::new_warning(
+                None,
+                format!("Remapping `{remapping}` is not applied to any import."),
+                None,
+                None,
+            ));
+        }
     }
 
     ///
@@ -138,11 +323,12 @@ impl CollectableError for Output {
     fn errors(&self) -> Vec<&JsonOutputError> {
         self.errors
             .iter()
-            .filter(|error| error.severity == "error")
+            .filter(|error| error.severity == "error" || self.escalation.is_escalated(error))
             .collect()
     }
 
     fn take_warnings(&mut self) -> Vec<JsonOutputError> {
+        self.escalation.apply(self.errors.as_mut_slice());
         let warnings = self
             .errors
             .iter()
@@ -150,6 +336,8 @@ impl CollectableError for Output {
             .cloned()
             .collect();
         self.errors.retain(|message| message.severity != "warning");
+        let (warnings, suppressed) = self.suppression.filter(warnings);
+        self.suppressed.extend(suppressed);
         warnings
     }
 }
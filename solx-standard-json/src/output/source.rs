@@ -13,6 +13,9 @@ pub struct Source {
     /// The source code AST.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ast: Option<serde_json::Value>,
+    /// The `keccak256` hash of the source code, in the `solc` metadata format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keccak256: Option<String>,
 }
 
 impl Source {
@@ -22,7 +25,31 @@ impl Source {
     /// Is used for projects compiled without `solc`.
     ///
     pub fn new(id: usize) -> Self {
-        Self { id, ast: None }
+        Self {
+            id,
+            ast: None,
+            keccak256: None,
+        }
+    }
+
+    ///
+    /// Returns the source location of the definition of contract `name` in the `solc` format `start:length:file`.
+    ///
+    /// Returns `None` if the AST is not available, or the contract is not defined in the source.
+    ///
+    pub fn contract_location(&self, name: &str) -> Option<String> {
+        self.ast
+            .as_ref()?
+            .get("nodes")?
+            .as_array()?
+            .iter()
+            .find(|node| {
+                node.get("nodeType").and_then(|node| node.as_str()) == Some("ContractDefinition")
+                    && node.get("name").and_then(|name| name.as_str()) == Some(name)
+            })?
+            .get("src")?
+            .as_str()
+            .map(|location| location.to_owned())
     }
 
     ///
@@ -0,0 +1,31 @@
+//!
+//! The `solc --standard-json` output contract timing.
+//!
+
+use std::time::Duration;
+
+///
+/// The `solc --standard-json` output contract timing.
+///
+/// The durations are in milliseconds.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timing {
+    /// The deploy code generation duration.
+    pub deploy: f64,
+    /// The runtime code generation duration.
+    pub runtime: f64,
+}
+
+impl Timing {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(deploy: Duration, runtime: Duration) -> Self {
+        Self {
+            deploy: deploy.as_secs_f64() * 1000.0,
+            runtime: runtime.as_secs_f64() * 1000.0,
+        }
+    }
+}
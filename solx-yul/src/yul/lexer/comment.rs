@@ -0,0 +1,27 @@
+//!
+//! The preserved source code comment.
+//!
+
+use crate::yul::lexer::token::location::Location;
+
+///
+/// The preserved source code comment.
+///
+/// Is only collected by the lexer if comment preservation is enabled.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
+pub struct Comment {
+    /// The location.
+    pub location: Location,
+    /// The comment text, including the comment delimiters.
+    pub text: String,
+}
+
+impl Comment {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, text: String) -> Self {
+        Self { location, text }
+    }
+}
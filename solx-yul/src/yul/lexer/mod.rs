@@ -2,12 +2,14 @@
 //! The compiler lexer.
 //!
 
+pub mod comment;
 pub mod error;
 pub mod token;
 
 #[cfg(test)]
 mod tests;
 
+use self::comment::Comment as PreservedComment;
 use self::error::Error;
 use self::token::lexeme::comment::Comment;
 use self::token::lexeme::identifier::Identifier;
@@ -30,9 +32,21 @@ pub struct Lexer {
     location: Location,
     /// The peeked lexeme, waiting to be fetched.
     peeked: Option<Token>,
+    /// Whether to collect comments instead of skipping them.
+    preserve_comments: bool,
+    /// The collected comments, waiting to be taken by the parser.
+    comments: Vec<PreservedComment>,
+    /// The current nesting depth of blocks and function calls being parsed.
+    nesting_depth: usize,
+    /// The maximum nesting depth allowed by the parser.
+    max_nesting_depth: usize,
 }
 
 impl Lexer {
+    /// The default maximum nesting depth of blocks and function calls.
+    /// Keeps the recursive descent parser and code generator well within the thread stack size.
+    pub const DEFAULT_MAX_NESTING_DEPTH: usize = 256;
+
     ///
     /// A shortcut constructor.
     ///
@@ -44,9 +58,65 @@ impl Lexer {
             offset: 0,
             location: Location::default(),
             peeked: None,
+            preserve_comments: false,
+            comments: Vec::new(),
+            nesting_depth: 0,
+            max_nesting_depth: Self::DEFAULT_MAX_NESTING_DEPTH,
         }
     }
 
+    ///
+    /// Sets whether to collect comments instead of skipping them.
+    ///
+    pub fn set_preserve_comments(&mut self, preserve_comments: bool) {
+        self.preserve_comments = preserve_comments;
+    }
+
+    ///
+    /// Whether comments are collected instead of being skipped.
+    ///
+    pub fn preserves_comments(&self) -> bool {
+        self.preserve_comments
+    }
+
+    ///
+    /// Sets the maximum nesting depth of blocks and function calls allowed by the parser.
+    ///
+    pub fn set_max_nesting_depth(&mut self, max_nesting_depth: usize) {
+        self.max_nesting_depth = max_nesting_depth;
+    }
+
+    ///
+    /// Returns the maximum nesting depth of blocks and function calls allowed by the parser.
+    ///
+    pub fn max_nesting_depth(&self) -> usize {
+        self.max_nesting_depth
+    }
+
+    ///
+    /// Is called by the parser when entering a block or function call.
+    ///
+    /// Returns `false` if the maximum nesting depth is exceeded.
+    ///
+    pub fn enter_nesting(&mut self) -> bool {
+        self.nesting_depth += 1;
+        self.nesting_depth <= self.max_nesting_depth
+    }
+
+    ///
+    /// Is called by the parser when leaving a block or function call.
+    ///
+    pub fn leave_nesting(&mut self) {
+        self.nesting_depth = self.nesting_depth.saturating_sub(1);
+    }
+
+    ///
+    /// Takes the comments collected since the last call.
+    ///
+    pub fn take_comments(&mut self) -> Vec<PreservedComment> {
+        std::mem::take(&mut self.comments)
+    }
+
     ///
     /// Advances the lexer, returning the next lexeme.
     ///
@@ -70,6 +140,14 @@ impl Lexer {
             }
 
             if let Some(token) = Comment::parse(input) {
+                if self.preserve_comments {
+                    let text = &input[..token.length.min(input.len())];
+                    self.comments.push(PreservedComment::new(
+                        self.location,
+                        text.trim_end().to_owned(),
+                    ));
+                }
+
                 self.offset += token.length;
                 self.location
                     .shift_down(token.location.line, token.location.column);
@@ -89,3 +89,55 @@ object "Test" {
         }
     }
 }
+
+#[test]
+fn comments_preserved() {
+    let input = r#"
+// The single-line comment.
+mstore(64, 128)
+/*
+    The multi-line comment.
+*/
+"#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    lexer.set_preserve_comments(true);
+    loop {
+        match lexer.next() {
+            Ok(token) if token.lexeme == Lexeme::EndOfFile => break,
+            Ok(_) => continue,
+            Err(error) => panic!("{error:?}"),
+        }
+    }
+
+    let comments = lexer.take_comments();
+    assert_eq!(
+        comments
+            .iter()
+            .map(|comment| (comment.location, comment.text.as_str()))
+            .collect::<Vec<(Location, &str)>>(),
+        vec![
+            (Location::new(2, 1), "// The single-line comment."),
+            (Location::new(4, 1), "/*\n    The multi-line comment.\n*/"),
+        ]
+    );
+    assert!(
+        lexer.take_comments().is_empty(),
+        "Comments must be taken only once"
+    );
+}
+
+#[test]
+fn comments_skipped() {
+    let input = r#"
+// The single-line comment.
+mstore(64, 128)
+"#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    while lexer.next().expect("Test failure").lexeme != Lexeme::EndOfFile {}
+    assert!(
+        lexer.take_comments().is_empty(),
+        "Comments must not be collected by default"
+    );
+}
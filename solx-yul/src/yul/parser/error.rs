@@ -41,6 +41,16 @@ pub enum Error {
         /// The actual number of arguments.
         found: usize,
     },
+    /// Invalid `verbatim` instruction name.
+    #[error(
+        "{location} The `verbatim` instruction must be named as `verbatim_<N>i_<M>o`, found `{identifier}`"
+    )]
+    InvalidVerbatimName {
+        /// The invalid instruction location.
+        location: Location,
+        /// The invalid instruction name.
+        identifier: String,
+    },
     /// Invalid object name.
     #[error(
         "{location} Objects must be named as '<name>' (deploy) and '<name>_deployed' (runtime)"
@@ -53,6 +63,18 @@ pub enum Error {
         /// The invalid identifier.
         found: String,
     },
+    /// A `dataoffset` or `datasize` reference to an object missing from the hierarchy.
+    #[error(
+        "{location} Object `{identifier}` references the object `{found}`, which is not found in its hierarchy"
+    )]
+    UnknownObjectReference {
+        /// The referencing object code location.
+        location: Location,
+        /// The referencing object identifier.
+        identifier: String,
+        /// The unknown object name.
+        found: String,
+    },
     /// Invalid attributes.
     #[error("{location} Found invalid LLVM attributes: {values:?}")]
     InvalidAttributes {
@@ -61,4 +83,22 @@ pub enum Error {
         /// The list of invalid attributes.
         values: BTreeSet<String>,
     },
+    /// Attributes are not supported by the dialect.
+    #[error("{location} Function `{identifier}` has LLVM attributes, which are not supported in the `{dialect}` dialect")]
+    UnsupportedAttributes {
+        /// The invalid token location.
+        location: Location,
+        /// The function name.
+        identifier: String,
+        /// The dialect name.
+        dialect: String,
+    },
+    /// The nesting of blocks or function calls is too deep.
+    #[error("{location} The nesting depth exceeds the limit of {limit}")]
+    NestingTooDeep {
+        /// The location of the block or function call exceeding the limit.
+        location: Location,
+        /// The maximum nesting depth.
+        limit: usize,
+    },
 }
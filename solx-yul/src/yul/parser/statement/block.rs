@@ -2,10 +2,12 @@
 //! The source code block.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::comment::Comment;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -30,6 +32,11 @@ where
     pub location: Location,
     /// The block statements.
     pub statements: Vec<Statement<P>>,
+    /// The comments preceding each statement, keyed by the statement index.
+    /// The comments before the closing bracket are keyed by the number of statements.
+    /// Only collected if the lexer preserves comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<BTreeMap<usize, Vec<Comment>>>,
 }
 
 impl<P> Block<P>
@@ -59,11 +66,25 @@ where
                 .into());
             }
         };
+        if !lexer.enter_nesting() {
+            return Err(ParserError::NestingTooDeep {
+                location,
+                limit: lexer.max_nesting_depth(),
+            }
+            .into());
+        }
 
+        let mut comments = BTreeMap::new();
         let mut remaining = None;
 
         loop {
-            match crate::yul::parser::take_or_next(remaining.take(), lexer)? {
+            let token = crate::yul::parser::take_or_next(remaining.take(), lexer)?;
+            let leading_comments = lexer.take_comments();
+            if !leading_comments.is_empty() {
+                comments.insert(statements.len(), leading_comments);
+            }
+
+            match token {
                 token @ Token {
                     lexeme: Lexeme::Keyword(_),
                     ..
@@ -112,7 +133,10 @@ where
                 Token {
                     lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
                     ..
-                } => break,
+                } => {
+                    lexer.leave_nesting();
+                    break;
+                }
                 token => {
                     return Err(ParserError::InvalidToken {
                         location: token.location,
@@ -127,6 +151,11 @@ where
         Ok(Self {
             location,
             statements,
+            comments: if lexer.preserves_comments() {
+                Some(comments)
+            } else {
+                None
+            },
         })
     }
 
@@ -225,4 +254,75 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn error_nesting_too_deep() {
+        let depth = Lexer::DEFAULT_MAX_NESTING_DEPTH;
+        let input = format!(
+            r#"
+object "Test" {{
+    code {{
+        {}{}
+    }}
+}}
+    "#,
+            "{".repeat(depth),
+            "}".repeat(depth),
+        );
+
+        let mut lexer = Lexer::new(input);
+        let result = Object::<DefaultDialect>::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::NestingTooDeep {
+                location: Location::new(4, 8 + depth),
+                limit: depth,
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_nesting_too_deep_function_calls() {
+        let input = r#"
+object "Test" {
+    code {
+        pop(add(add(add(1, 2), 3), 4))
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        lexer.set_max_nesting_depth(3);
+        let result = Object::<DefaultDialect>::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::NestingTooDeep {
+                location: Location::new(4, 17),
+                limit: 3,
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn ok_recursive_function() {
+        let input = r#"
+object "Test" {
+    code {
+        function f(x) -> y {
+            switch x
+            case 0 { y := 0 }
+            default { y := f(sub(x, 1)) }
+        }
+        pop(f(10))
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        lexer.set_max_nesting_depth(8);
+        let result = Object::<DefaultDialect>::parse(&mut lexer, None);
+        assert!(result.is_ok());
+    }
 }
@@ -56,6 +56,24 @@ impl FunctionCall {
             }
         };
 
+        if let Name::UserDefined(ref identifier) = name {
+            if identifier.starts_with(Name::VERBATIM_PREFIX) {
+                return Err(ParserError::InvalidVerbatimName {
+                    location,
+                    identifier: identifier.to_owned(),
+                }
+                .into());
+            }
+        }
+
+        if !lexer.enter_nesting() {
+            return Err(ParserError::NestingTooDeep {
+                location,
+                limit: lexer.max_nesting_depth(),
+            }
+            .into());
+        }
+
         let mut arguments = Vec::new();
         loop {
             let argument = match lexer.next()? {
@@ -86,6 +104,20 @@ impl FunctionCall {
                 _ => break,
             }
         }
+        lexer.leave_nesting();
+
+        if let Name::Verbatim { input_size, .. } = name {
+            let expected = input_size + 1;
+            if arguments.len() != expected {
+                return Err(ParserError::InvalidNumberOfArguments {
+                    location,
+                    identifier: crate::yul::printer::name_identifier(&name),
+                    expected,
+                    found: arguments.len(),
+                }
+                .into());
+            }
+        }
 
         Ok(Self {
             location,
@@ -145,3 +177,64 @@ impl FunctionCall {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::expression::function_call::name::Name;
+    use crate::yul::parser::statement::expression::Expression;
+
+    #[test]
+    fn verbatim() {
+        let input = r#"verbatim_2i_1o(hex"600202", x, y)"#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Expression::parse(&mut lexer, None);
+        match result {
+            Ok(Expression::FunctionCall(call)) => assert_eq!(
+                call.name,
+                Name::Verbatim {
+                    input_size: 2,
+                    output_size: 1,
+                }
+            ),
+            result => panic!("Expected a function call, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn error_invalid_verbatim_name() {
+        let input = r#"verbatim_2x(hex"00", x, y)"#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Expression::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidVerbatimName {
+                location: Location::new(1, 1),
+                identifier: "verbatim_2x".to_owned(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_invalid_verbatim_number_of_arguments() {
+        let input = r#"verbatim_2i_0o(hex"00", x)"#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Expression::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidNumberOfArguments {
+                location: Location::new(1, 1),
+                identifier: "verbatim_2i_0o".to_owned(),
+                expected: 3,
+                found: 2,
+            }
+            .into())
+        );
+    }
+}
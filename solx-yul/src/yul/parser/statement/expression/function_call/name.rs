@@ -336,7 +336,39 @@ impl From<&str> for Name {
             "extcodecopy" => Self::ExtCodeCopy,
             "selfdestruct" => Self::SelfDestruct,
 
-            input => Self::UserDefined(input.to_owned()),
+            input => match Self::parse_verbatim(input) {
+                Some((input_size, output_size)) => Self::Verbatim {
+                    input_size,
+                    output_size,
+                },
+                None => Self::UserDefined(input.to_owned()),
+            },
         }
     }
 }
+
+impl Name {
+    /// The prefix of the `verbatim_<N>i_<M>o` instructions.
+    pub const VERBATIM_PREFIX: &'static str = "verbatim";
+
+    ///
+    /// Parses the numbers of inputs and outputs from the `verbatim_<N>i_<M>o` instruction name.
+    ///
+    /// Returns `None` if the name is not a well-formed `verbatim` instruction.
+    ///
+    pub fn parse_verbatim(input: &str) -> Option<(usize, usize)> {
+        let arity = input
+            .strip_prefix(Self::VERBATIM_PREFIX)?
+            .strip_prefix('_')?
+            .strip_suffix('o')?;
+        let (input_size, output_size) = arity.split_once("i_")?;
+        if [input_size, output_size].iter().any(|size| {
+            size.is_empty()
+                || !size.chars().all(|character| character.is_ascii_digit())
+                || (size.len() > 1 && size.starts_with('0'))
+        }) {
+            return None;
+        }
+        Some((input_size.parse().ok()?, output_size.parse().ok()?))
+    }
+}
@@ -101,6 +101,7 @@ where
         let code = Code::parse(lexer, None)?;
         let mut inner_object = None;
         let mut factory_dependencies = HashSet::new();
+        let mut descendants = BTreeSet::new();
 
         if !is_runtime_code {
             inner_object = match lexer.peek()? {
@@ -119,6 +120,7 @@ where
                         .into());
                     }
 
+                    descendants.insert(object.identifier.clone());
                     factory_dependencies.extend(object.factory_dependencies.drain());
                     Some(Box::new(object))
                 }
@@ -149,6 +151,7 @@ where
                     ..
                 } => {
                     let dependency = Self::parse(lexer, Some(token))?;
+                    descendants.extend(dependency.factory_dependencies);
                     factory_dependencies.insert(dependency.identifier);
                 }
                 Token {
@@ -169,6 +172,22 @@ where
             }
         }
 
+        descendants.extend(factory_dependencies.iter().cloned());
+        let mut references = Dependencies::new(identifier.as_str());
+        code.accumulate_evm_dependencies(&mut references);
+        if let Some(reference) = references
+            .inner
+            .into_iter()
+            .find(|reference| !descendants.contains(reference))
+        {
+            return Err(ParserError::UnknownObjectReference {
+                location: code.location,
+                identifier,
+                found: reference,
+            }
+            .into());
+        }
+
         Ok(Self {
             location,
             identifier,
@@ -216,6 +235,89 @@ mod tests {
     use crate::yul::parser::error::Error;
     use crate::yul::parser::statement::object::Object;
 
+    #[test]
+    fn nested_object_references() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            codecopy(0, dataoffset("Test_deployed"), datasize("Test_deployed"))
+            return(0, datasize("Test_deployed"))
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let size := datasize("Callable")
+                codecopy(0, dataoffset("Test_deployed.Callable"), size)
+                pop(create(0, 0, size))
+            }
+        }
+        object "Callable" {
+            code {
+                {
+                    return(0, 0)
+                }
+            }
+            object "Callable_deployed" {
+                code {
+                    {
+                        return(0, 0)
+                    }
+                }
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Test failure");
+        let inner_object = object.inner_object.as_ref().expect("Test failure");
+        assert_eq!(
+            object.get_evm_dependencies(Some(inner_object)).inner,
+            vec!["Test_deployed".to_owned()]
+        );
+        assert_eq!(
+            inner_object.get_evm_dependencies(None).inner,
+            vec!["Callable".to_owned()]
+        );
+        assert!(object.factory_dependencies.contains("Callable"));
+    }
+
+    #[test]
+    fn error_unknown_object_reference() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            codecopy(0, dataoffset("Test_deployed"), datasize("Test_deployed"))
+            return(0, datasize("Test_deployed"))
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                pop(create(0, dataoffset("Missing"), datasize("Missing")))
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::<DefaultDialect>::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::UnknownObjectReference {
+                location: Location::new(10, 9),
+                identifier: "Test_deployed".to_owned(),
+                found: "Missing".to_owned(),
+            }
+            .into())
+        );
+    }
+
     #[test]
     fn error_invalid_token_object() {
         let input = r#"
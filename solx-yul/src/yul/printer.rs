@@ -169,6 +169,27 @@ where
     }
 
     fn visit_block(&mut self, block: &Block<P>) {
+        if let Some(comments) = block
+            .comments
+            .as_ref()
+            .filter(|comments| !comments.is_empty())
+        {
+            self.println(" {").unwrap();
+            self.increase_indent().unwrap();
+            for index in 0..=block.statements.len() {
+                for comment in comments.get(&index).into_iter().flatten() {
+                    self.println(comment.text.as_str()).unwrap();
+                }
+                if let Some(statement) = block.statements.get(index) {
+                    self.visit_statement(statement);
+                    self.println("").unwrap();
+                }
+            }
+            self.println(" }").unwrap();
+            self.decrease_indent().unwrap();
+            return;
+        }
+
         if block.statements.is_empty() {
             self.print(" { }").unwrap();
             return;
@@ -0,0 +1,253 @@
+//!
+//! The Yul user-defined function call arity checker.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::visitor::Visitor;
+
+///
+/// The user-defined function call with a wrong number of arguments.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArityMismatch {
+    /// The called function name.
+    pub identifier: String,
+    /// The call location.
+    pub location: Location,
+    /// The number of the function arguments.
+    pub expected: usize,
+    /// The number of the provided arguments.
+    pub found: usize,
+}
+
+///
+/// The Yul user-defined function call arity checker.
+///
+/// Walks the syntax tree, tracking the functions visible in each scope, and reports calls
+/// whose number of arguments differs from the one of the called function definition.
+/// As Yul functions are hoisted, all functions of a block are visible from its very beginning.
+///
+#[derive(Debug, Default)]
+pub struct ArityChecker {
+    /// The stack of scopes, mapping function names to their numbers of arguments.
+    scopes: Vec<BTreeMap<String, usize>>,
+    /// The detected mismatches.
+    mismatches: Vec<ArityMismatch>,
+}
+
+impl ArityChecker {
+    ///
+    /// Checks the object and its inner objects, returning the detected mismatches.
+    ///
+    pub fn check<P>(object: &Object<P>) -> Vec<ArityMismatch>
+    where
+        P: Dialect,
+    {
+        let mut checker = Self::default();
+        checker.visit_object(object);
+        checker.mismatches
+    }
+
+    ///
+    /// Checks the calls in the expression and its subexpressions.
+    ///
+    fn check_expression(&mut self, expression: &Expression) {
+        let call = match expression {
+            Expression::FunctionCall(call) => call,
+            Expression::Identifier(_) | Expression::Literal(_) => return,
+        };
+
+        if let Name::UserDefined(ref identifier) = call.name {
+            if let Some(expected) = self
+                .scopes
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(identifier.as_str()))
+            {
+                if *expected != call.arguments.len() {
+                    self.mismatches.push(ArityMismatch {
+                        identifier: identifier.to_owned(),
+                        location: call.location,
+                        expected: *expected,
+                        found: call.arguments.len(),
+                    });
+                }
+            }
+        }
+
+        for argument in call.arguments.iter() {
+            self.check_expression(argument);
+        }
+    }
+}
+
+impl<P> Visitor<P> for ArityChecker
+where
+    P: Dialect,
+{
+    fn visit_switch(&mut self, switch: &Switch<P>) {
+        self.check_expression(&switch.expression);
+        for case in switch.cases.iter() {
+            self.visit_block(&case.block);
+        }
+        if let Some(block) = &switch.default {
+            self.visit_block(block);
+        }
+    }
+
+    fn visit_object(&mut self, object: &Object<P>) {
+        let scopes = std::mem::take(&mut self.scopes);
+        self.visit_code(&object.code);
+        self.scopes = scopes;
+        if let Some(inner) = &object.inner_object {
+            self.visit_object(inner);
+        }
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &ForLoop<P>) {
+        self.scopes.push(BTreeMap::new());
+        for statement in for_loop.initializer.statements.iter() {
+            self.visit_statement(statement);
+        }
+        self.check_expression(&for_loop.condition);
+        self.visit_block(&for_loop.finalizer);
+        self.visit_block(&for_loop.body);
+        self.scopes.pop();
+    }
+
+    fn visit_variable_declaration(&mut self, variable_declaration: &VariableDeclaration) {
+        if let Some(expression) = &variable_declaration.expression {
+            self.check_expression(expression);
+        }
+    }
+
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition<P>) {
+        self.visit_block(&function_definition.body);
+    }
+
+    fn visit_name(&mut self, _name: &Name) {}
+
+    fn visit_function_call(&mut self, _call: &FunctionCall) {}
+
+    fn visit_if_conditional(&mut self, if_conditional: &IfConditional<P>) {
+        self.check_expression(&if_conditional.condition);
+        self.visit_block(&if_conditional.block);
+    }
+
+    fn visit_literal(&mut self, _literal: &Literal) {}
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        self.check_expression(expression);
+    }
+
+    fn visit_assignment(&mut self, assignment: &Assignment) {
+        self.check_expression(&assignment.initializer);
+    }
+
+    fn visit_statement(&mut self, statement: &Statement<P>) {
+        match statement {
+            Statement::Object(inner) => self.visit_object(inner),
+            Statement::Code(inner) => self.visit_code(inner),
+            Statement::Block(inner) => self.visit_block(inner),
+            Statement::Expression(inner) => <Self as Visitor<P>>::visit_expression(self, inner),
+            Statement::FunctionDefinition(inner) => self.visit_function_definition(inner),
+            Statement::VariableDeclaration(inner) => {
+                <Self as Visitor<P>>::visit_variable_declaration(self, inner)
+            }
+            Statement::Assignment(inner) => <Self as Visitor<P>>::visit_assignment(self, inner),
+            Statement::IfConditional(inner) => self.visit_if_conditional(inner),
+            Statement::Switch(inner) => self.visit_switch(inner),
+            Statement::ForLoop(inner) => self.visit_for_loop(inner),
+            Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+        }
+    }
+
+    fn visit_block(&mut self, block: &Block<P>) {
+        let functions = block
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::FunctionDefinition(function_definition) => Some((
+                    function_definition.identifier.to_owned(),
+                    function_definition.arguments.len(),
+                )),
+                _ => None,
+            })
+            .collect::<BTreeMap<String, usize>>();
+        self.scopes.push(functions);
+        for statement in block.statements.iter() {
+            self.visit_statement(statement);
+        }
+        self.scopes.pop();
+    }
+
+    fn visit_code(&mut self, code: &Code<P>) {
+        self.visit_block(&code.block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::dialect::DefaultDialect;
+    use crate::yul::parser::statement::object::Object;
+    use crate::yul::visitor::arity::ArityChecker;
+    use crate::yul::visitor::arity::ArityMismatch;
+
+    #[test]
+    fn mismatch() {
+        let input = r#"
+object "Test" {
+    code {
+        let x := f(1)
+        function f(a, b) -> c {
+            c := g(a, b)
+        }
+        {
+            function h(a) -> b { }
+            pop(h(1))
+        }
+        function g(a, b) -> c {
+            c := add(f(a, b), 1)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            function f(a) { }
+            f(1)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Test failure");
+        assert_eq!(
+            ArityChecker::check(&object),
+            vec![ArityMismatch {
+                identifier: "f".to_owned(),
+                location: Location::new(4, 18),
+                expected: 2,
+                found: 1,
+            }]
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//!
+//! The Yul dead function checker.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::visitor::Visitor;
+
+///
+/// The user-defined function that is never called.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadFunction {
+    /// The function name.
+    pub identifier: String,
+    /// The function definition location.
+    pub location: Location,
+}
+
+///
+/// The Yul dead function checker.
+///
+/// Builds the call graph of each object code, and reports the functions unreachable from its
+/// top-level statements. Functions only called by other dead functions are reported as well.
+/// Yul has no indirect function references, so following the direct calls is enough.
+///
+#[derive(Debug, Default)]
+pub struct DeadFunctionChecker {
+    /// The stack of scopes, mapping function names to their indexes in `functions`.
+    scopes: Vec<BTreeMap<String, usize>>,
+    /// The function definitions of the current object code.
+    functions: Vec<DeadFunction>,
+    /// The indexes of the functions called by each function of the current object code.
+    calls: Vec<BTreeSet<usize>>,
+    /// The indexes of the functions called by the top-level statements of the current object code.
+    entry_calls: BTreeSet<usize>,
+    /// The index of the function being visited, or `None` for the top-level statements.
+    current_function: Option<usize>,
+    /// The detected dead functions.
+    dead_functions: Vec<DeadFunction>,
+}
+
+impl DeadFunctionChecker {
+    ///
+    /// Checks the object and its inner objects, returning the detected dead functions.
+    ///
+    pub fn check<P>(object: &Object<P>) -> Vec<DeadFunction>
+    where
+        P: Dialect,
+    {
+        let mut checker = Self::default();
+        checker.visit_object(object);
+        checker.dead_functions
+    }
+
+    ///
+    /// Records the calls in the expression and its subexpressions.
+    ///
+    fn check_expression(&mut self, expression: &Expression) {
+        let call = match expression {
+            Expression::FunctionCall(call) => call,
+            Expression::Identifier(_) | Expression::Literal(_) => return,
+        };
+
+        if let Name::UserDefined(ref identifier) = call.name {
+            if let Some(callee) = self
+                .scopes
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(identifier.as_str()))
+                .copied()
+            {
+                match self.current_function {
+                    Some(caller) => self.calls[caller].insert(callee),
+                    None => self.entry_calls.insert(callee),
+                };
+            }
+        }
+
+        for argument in call.arguments.iter() {
+            self.check_expression(argument);
+        }
+    }
+
+    ///
+    /// Reports the functions of the current object code unreachable from its top-level statements.
+    ///
+    fn collect_dead_functions(&mut self) {
+        let mut reachable = BTreeSet::new();
+        let mut queue = self.entry_calls.iter().copied().collect::<Vec<usize>>();
+        while let Some(function) = queue.pop() {
+            if reachable.insert(function) {
+                queue.extend(self.calls[function].iter().copied());
+            }
+        }
+
+        for (index, function) in std::mem::take(&mut self.functions).into_iter().enumerate() {
+            if !reachable.contains(&index) {
+                self.dead_functions.push(function);
+            }
+        }
+        self.calls.clear();
+        self.entry_calls.clear();
+    }
+}
+
+impl<P> Visitor<P> for DeadFunctionChecker
+where
+    P: Dialect,
+{
+    fn visit_switch(&mut self, switch: &Switch<P>) {
+        self.check_expression(&switch.expression);
+        for case in switch.cases.iter() {
+            self.visit_block(&case.block);
+        }
+        if let Some(block) = &switch.default {
+            self.visit_block(block);
+        }
+    }
+
+    fn visit_object(&mut self, object: &Object<P>) {
+        let scopes = std::mem::take(&mut self.scopes);
+        self.visit_code(&object.code);
+        self.collect_dead_functions();
+        self.scopes = scopes;
+        if let Some(inner) = &object.inner_object {
+            self.visit_object(inner);
+        }
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &ForLoop<P>) {
+        self.scopes.push(BTreeMap::new());
+        for statement in for_loop.initializer.statements.iter() {
+            self.visit_statement(statement);
+        }
+        self.check_expression(&for_loop.condition);
+        self.visit_block(&for_loop.finalizer);
+        self.visit_block(&for_loop.body);
+        self.scopes.pop();
+    }
+
+    fn visit_variable_declaration(&mut self, variable_declaration: &VariableDeclaration) {
+        if let Some(expression) = &variable_declaration.expression {
+            self.check_expression(expression);
+        }
+    }
+
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition<P>) {
+        let function = self
+            .scopes
+            .last()
+            .and_then(|scope| scope.get(function_definition.identifier.as_str()))
+            .copied();
+        let caller = std::mem::replace(&mut self.current_function, function);
+        self.visit_block(&function_definition.body);
+        self.current_function = caller;
+    }
+
+    fn visit_name(&mut self, _name: &Name) {}
+
+    fn visit_function_call(&mut self, _call: &FunctionCall) {}
+
+    fn visit_if_conditional(&mut self, if_conditional: &IfConditional<P>) {
+        self.check_expression(&if_conditional.condition);
+        self.visit_block(&if_conditional.block);
+    }
+
+    fn visit_literal(&mut self, _literal: &Literal) {}
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        self.check_expression(expression);
+    }
+
+    fn visit_assignment(&mut self, assignment: &Assignment) {
+        self.check_expression(&assignment.initializer);
+    }
+
+    fn visit_statement(&mut self, statement: &Statement<P>) {
+        match statement {
+            Statement::Object(inner) => self.visit_object(inner),
+            Statement::Code(inner) => self.visit_code(inner),
+            Statement::Block(inner) => self.visit_block(inner),
+            Statement::Expression(inner) => <Self as Visitor<P>>::visit_expression(self, inner),
+            Statement::FunctionDefinition(inner) => self.visit_function_definition(inner),
+            Statement::VariableDeclaration(inner) => {
+                <Self as Visitor<P>>::visit_variable_declaration(self, inner)
+            }
+            Statement::Assignment(inner) => <Self as Visitor<P>>::visit_assignment(self, inner),
+            Statement::IfConditional(inner) => self.visit_if_conditional(inner),
+            Statement::Switch(inner) => self.visit_switch(inner),
+            Statement::ForLoop(inner) => self.visit_for_loop(inner),
+            Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+        }
+    }
+
+    fn visit_block(&mut self, block: &Block<P>) {
+        let mut functions = BTreeMap::new();
+        for statement in block.statements.iter() {
+            if let Statement::FunctionDefinition(function_definition) = statement {
+                functions.insert(
+                    function_definition.identifier.to_owned(),
+                    self.functions.len(),
+                );
+                self.functions.push(DeadFunction {
+                    identifier: function_definition.identifier.to_owned(),
+                    location: function_definition.location,
+                });
+                self.calls.push(BTreeSet::new());
+            }
+        }
+        self.scopes.push(functions);
+        for statement in block.statements.iter() {
+            self.visit_statement(statement);
+        }
+        self.scopes.pop();
+    }
+
+    fn visit_code(&mut self, code: &Code<P>) {
+        self.visit_block(&code.block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::dialect::DefaultDialect;
+    use crate::yul::parser::statement::object::Object;
+    use crate::yul::visitor::dead_function::DeadFunctionChecker;
+
+    #[test]
+    fn dead() {
+        let input = r#"
+object "Test" {
+    code {
+        function f() -> a {
+            a := g(1)
+        }
+        function g(x) -> b {
+            b := x
+        }
+        function h() {
+            i()
+        }
+        function i() {
+            h()
+        }
+        sstore(0, f())
+    }
+    object "Test_deployed" {
+        code {
+            function f() { }
+            {
+                function j() { }
+                j()
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Test failure");
+        let dead_functions = DeadFunctionChecker::check(&object)
+            .into_iter()
+            .map(|function| (function.identifier, function.location.line))
+            .collect::<Vec<(String, usize)>>();
+        assert_eq!(
+            dead_functions,
+            vec![
+                ("h".to_owned(), 10),
+                ("i".to_owned(), 13),
+                ("f".to_owned(), 20),
+            ]
+        );
+    }
+
+    #[test]
+    fn recursive() {
+        let input = r#"
+object "Test" {
+    code {
+        function f(x) -> y {
+            y := x
+            if x {
+                y := f(sub(x, 1))
+            }
+        }
+        sstore(0, f(10))
+    }
+    object "Test_deployed" {
+        code { }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Test failure");
+        assert!(DeadFunctionChecker::check(&object).is_empty());
+    }
+}
@@ -2,6 +2,11 @@
 //! Implementation of a visitor pattern for Yul syntax tree.
 //!
 
+pub mod arity;
+pub mod dead_function;
+pub mod shadowing;
+pub mod switch_default;
+
 use std::collections::BTreeSet;
 
 use crate::yul::parser::statement::assignment::Assignment;
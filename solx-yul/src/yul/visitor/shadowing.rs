@@ -0,0 +1,259 @@
+//!
+//! The Yul variable shadowing checker.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::visitor::Visitor;
+
+///
+/// The variable shadowing an enclosing-scope variable of the same name.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shadowing {
+    /// The variable name.
+    pub identifier: String,
+    /// The shadowing declaration location.
+    pub location: Location,
+    /// The shadowed declaration location.
+    pub shadowed_location: Location,
+}
+
+///
+/// The Yul variable shadowing checker.
+///
+/// Walks the syntax tree, tracking the variables declared in each scope, including
+/// function arguments and results, and reports declarations that reuse a name still visible
+/// from an enclosing scope.
+///
+#[derive(Debug, Default)]
+pub struct ShadowingChecker {
+    /// The stack of scopes, mapping variable names to their declaration locations.
+    scopes: Vec<BTreeMap<String, Location>>,
+    /// The detected shadowings.
+    shadowings: Vec<Shadowing>,
+}
+
+impl ShadowingChecker {
+    ///
+    /// Checks the object and its inner objects, returning the detected shadowings.
+    ///
+    pub fn check<P>(object: &Object<P>) -> Vec<Shadowing>
+    where
+        P: Dialect,
+    {
+        let mut checker = Self::default();
+        checker.visit_object(object);
+        checker.shadowings
+    }
+
+    ///
+    /// Declares the variable in the innermost scope, recording the shadowing if any.
+    ///
+    fn declare(&mut self, identifier: &Identifier) {
+        if let Some(shadowed_location) = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(identifier.inner.as_str()))
+        {
+            self.shadowings.push(Shadowing {
+                identifier: identifier.inner.to_owned(),
+                location: identifier.location,
+                shadowed_location: *shadowed_location,
+            });
+        }
+        self.scopes
+            .last_mut()
+            .expect("Always exists")
+            .insert(identifier.inner.to_owned(), identifier.location);
+    }
+}
+
+impl<P> Visitor<P> for ShadowingChecker
+where
+    P: Dialect,
+{
+    fn visit_switch(&mut self, switch: &Switch<P>) {
+        for case in switch.cases.iter() {
+            self.visit_block(&case.block);
+        }
+        if let Some(block) = &switch.default {
+            self.visit_block(block);
+        }
+    }
+
+    fn visit_object(&mut self, object: &Object<P>) {
+        self.visit_code(&object.code);
+        if let Some(inner) = &object.inner_object {
+            self.visit_object(inner);
+        }
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &ForLoop<P>) {
+        self.scopes.push(BTreeMap::new());
+        for statement in for_loop.initializer.statements.iter() {
+            self.visit_statement(statement);
+        }
+        self.visit_block(&for_loop.finalizer);
+        self.visit_block(&for_loop.body);
+        self.scopes.pop();
+    }
+
+    fn visit_variable_declaration(&mut self, variable_declaration: &VariableDeclaration) {
+        for binding in variable_declaration.bindings.iter() {
+            self.declare(binding);
+        }
+    }
+
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition<P>) {
+        self.scopes.push(BTreeMap::new());
+        for identifier in function_definition
+            .arguments
+            .iter()
+            .chain(function_definition.result.iter())
+        {
+            self.declare(identifier);
+        }
+        self.visit_block(&function_definition.body);
+        self.scopes.pop();
+    }
+
+    fn visit_name(&mut self, _name: &Name) {}
+
+    fn visit_function_call(&mut self, _call: &FunctionCall) {}
+
+    fn visit_if_conditional(&mut self, if_conditional: &IfConditional<P>) {
+        self.visit_block(&if_conditional.block);
+    }
+
+    fn visit_literal(&mut self, _literal: &Literal) {}
+
+    fn visit_expression(&mut self, _expression: &Expression) {}
+
+    fn visit_assignment(&mut self, _assignment: &Assignment) {}
+
+    fn visit_statement(&mut self, statement: &Statement<P>) {
+        match statement {
+            Statement::Object(inner) => self.visit_object(inner),
+            Statement::Code(inner) => self.visit_code(inner),
+            Statement::Block(inner) => self.visit_block(inner),
+            Statement::FunctionDefinition(inner) => self.visit_function_definition(inner),
+            Statement::VariableDeclaration(inner) => {
+                <Self as Visitor<P>>::visit_variable_declaration(self, inner)
+            }
+            Statement::IfConditional(inner) => self.visit_if_conditional(inner),
+            Statement::Switch(inner) => self.visit_switch(inner),
+            Statement::ForLoop(inner) => self.visit_for_loop(inner),
+            Statement::Expression(_)
+            | Statement::Assignment(_)
+            | Statement::Continue(_)
+            | Statement::Break(_)
+            | Statement::Leave(_) => {}
+        }
+    }
+
+    fn visit_block(&mut self, block: &Block<P>) {
+        self.scopes.push(BTreeMap::new());
+        for statement in block.statements.iter() {
+            self.visit_statement(statement);
+        }
+        self.scopes.pop();
+    }
+
+    fn visit_code(&mut self, code: &Code<P>) {
+        self.visit_block(&code.block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::dialect::DefaultDialect;
+    use crate::yul::parser::statement::object::Object;
+    use crate::yul::visitor::shadowing::Shadowing;
+    use crate::yul::visitor::shadowing::ShadowingChecker;
+
+    #[test]
+    fn shadowing() {
+        let input = r#"
+object "Test" {
+    code {
+        let x := 1
+        {
+            let x := 2
+        }
+        function f(a) -> b {
+            let a := 3
+        }
+    }
+    object "Test_deployed" {
+        code {
+            let y := 0
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Test failure");
+        assert_eq!(
+            ShadowingChecker::check(&object),
+            vec![
+                Shadowing {
+                    identifier: "x".to_owned(),
+                    location: Location::new(6, 17),
+                    shadowed_location: Location::new(4, 13),
+                },
+                Shadowing {
+                    identifier: "a".to_owned(),
+                    location: Location::new(9, 17),
+                    shadowed_location: Location::new(8, 20),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn sibling_scopes() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            let x := 1
+        }
+        {
+            let x := 2
+        }
+    }
+    object "Test_deployed" {
+        code {
+            let x := 0
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Test failure");
+        assert!(ShadowingChecker::check(&object).is_empty());
+    }
+}
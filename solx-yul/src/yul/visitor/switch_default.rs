@@ -0,0 +1,244 @@
+//!
+//! The Yul `switch` default case checker.
+//!
+
+use crate::yul::lexer::token::lexeme::literal::boolean::Boolean as BooleanLiteral;
+use crate::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::visitor::Visitor;
+
+///
+/// The Yul `switch` default case checker.
+///
+/// Reports `switch` statements without a `default` case, where unmatched values silently fall through.
+/// Switches over boolean expressions, such as comparisons, are not reported if both `0` and `1` are matched.
+///
+#[derive(Debug, Default)]
+pub struct SwitchDefaultChecker {
+    /// The locations of the switches without a default case.
+    locations: Vec<Location>,
+}
+
+impl SwitchDefaultChecker {
+    ///
+    /// Checks the object and its inner objects, returning the locations of the non-exhaustive switches.
+    ///
+    pub fn check<P>(object: &Object<P>) -> Vec<Location>
+    where
+        P: Dialect,
+    {
+        let mut checker = Self::default();
+        checker.visit_object(object);
+        checker.locations
+    }
+
+    ///
+    /// Checks whether the expression can only evaluate to `0` or `1`.
+    ///
+    fn is_boolean(expression: &Expression) -> bool {
+        match expression {
+            Expression::FunctionCall(call) => matches!(
+                call.name,
+                Name::Lt | Name::Gt | Name::Slt | Name::Sgt | Name::Eq | Name::IsZero
+            ),
+            Expression::Literal(literal) => {
+                matches!(literal.inner, LexicalLiteral::Boolean(_))
+            }
+            Expression::Identifier(_) => false,
+        }
+    }
+
+    ///
+    /// Returns the boolean value of the literal, if it is `0` or `1`.
+    ///
+    fn boolean_value(literal: &Literal) -> Option<bool> {
+        match literal.inner {
+            LexicalLiteral::Boolean(BooleanLiteral::False) => Some(false),
+            LexicalLiteral::Boolean(BooleanLiteral::True) => Some(true),
+            LexicalLiteral::Integer(IntegerLiteral::Decimal { ref inner }) => {
+                match inner.as_str() {
+                    "0" => Some(false),
+                    "1" => Some(true),
+                    _ => None,
+                }
+            }
+            LexicalLiteral::Integer(IntegerLiteral::Hexadecimal { ref inner }) => {
+                match inner.trim_start_matches("0x").trim_start_matches('0') {
+                    "" => Some(false),
+                    "1" => Some(true),
+                    _ => None,
+                }
+            }
+            LexicalLiteral::String(_) => None,
+        }
+    }
+}
+
+impl<P> Visitor<P> for SwitchDefaultChecker
+where
+    P: Dialect,
+{
+    fn visit_switch(&mut self, switch: &Switch<P>) {
+        if switch.default.is_none() {
+            let values = switch
+                .cases
+                .iter()
+                .filter_map(|case| Self::boolean_value(&case.literal))
+                .collect::<Vec<bool>>();
+            let is_exhaustive = Self::is_boolean(&switch.expression)
+                && values.contains(&false)
+                && values.contains(&true);
+            if !is_exhaustive {
+                self.locations.push(switch.location);
+            }
+        }
+
+        for case in switch.cases.iter() {
+            self.visit_block(&case.block);
+        }
+        if let Some(block) = &switch.default {
+            self.visit_block(block);
+        }
+    }
+
+    fn visit_object(&mut self, object: &Object<P>) {
+        self.visit_code(&object.code);
+        if let Some(inner) = &object.inner_object {
+            self.visit_object(inner);
+        }
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &ForLoop<P>) {
+        self.visit_block(&for_loop.initializer);
+        self.visit_block(&for_loop.finalizer);
+        self.visit_block(&for_loop.body);
+    }
+
+    fn visit_variable_declaration(&mut self, _variable_declaration: &VariableDeclaration) {}
+
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition<P>) {
+        self.visit_block(&function_definition.body);
+    }
+
+    fn visit_name(&mut self, _name: &Name) {}
+
+    fn visit_function_call(&mut self, _call: &FunctionCall) {}
+
+    fn visit_if_conditional(&mut self, if_conditional: &IfConditional<P>) {
+        self.visit_block(&if_conditional.block);
+    }
+
+    fn visit_literal(&mut self, _literal: &Literal) {}
+
+    fn visit_expression(&mut self, _expression: &Expression) {}
+
+    fn visit_assignment(&mut self, _assignment: &Assignment) {}
+
+    fn visit_statement(&mut self, statement: &Statement<P>) {
+        match statement {
+            Statement::Object(inner) => self.visit_object(inner),
+            Statement::Code(inner) => self.visit_code(inner),
+            Statement::Block(inner) => self.visit_block(inner),
+            Statement::FunctionDefinition(inner) => self.visit_function_definition(inner),
+            Statement::IfConditional(inner) => self.visit_if_conditional(inner),
+            Statement::Switch(inner) => self.visit_switch(inner),
+            Statement::ForLoop(inner) => self.visit_for_loop(inner),
+            Statement::VariableDeclaration(_)
+            | Statement::Expression(_)
+            | Statement::Assignment(_)
+            | Statement::Continue(_)
+            | Statement::Break(_)
+            | Statement::Leave(_) => {}
+        }
+    }
+
+    fn visit_block(&mut self, block: &Block<P>) {
+        for statement in block.statements.iter() {
+            self.visit_statement(statement);
+        }
+    }
+
+    fn visit_code(&mut self, code: &Code<P>) {
+        self.visit_block(&code.block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::dialect::DefaultDialect;
+    use crate::yul::parser::statement::object::Object;
+    use crate::yul::visitor::switch_default::SwitchDefaultChecker;
+
+    #[test]
+    fn missing_default() {
+        let input = r#"
+object "Test" {
+    code {
+        switch calldataload(0)
+        case 0 { }
+        case 1 { }
+        function f(a) {
+            switch a
+            case 42 { }
+            default { }
+        }
+    }
+    object "Test_deployed" {
+        code {
+            switch lt(1, 2)
+            case 1 { }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Test failure");
+        assert_eq!(
+            SwitchDefaultChecker::check(&object),
+            vec![Location::new(4, 16), Location::new(15, 20)]
+        );
+    }
+
+    #[test]
+    fn boolean_exhaustive() {
+        let input = r#"
+object "Test" {
+    code {
+        switch iszero(calldatasize())
+        case 0 { }
+        case 0x01 { }
+    }
+    object "Test_deployed" {
+        code {
+            switch eq(1, 2)
+            case false { }
+            case true { }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Test failure");
+        assert!(SwitchDefaultChecker::check(&object).is_empty());
+    }
+}
@@ -194,3 +194,51 @@ object "ecadd" {
         );
     }
 }
+
+mod comments {
+    use solx_yul::util::printer::write_printer::WritePrinter;
+    use solx_yul::yul::lexer::Lexer;
+    use solx_yul::yul::parser::dialect::DefaultDialect;
+    use solx_yul::yul::parser::statement::Statement;
+    use solx_yul::yul::visitor::Visitor;
+
+    #[test]
+    fn block_comments_round_trip() {
+        let input = r#"
+code {
+    // Initializes the free memory pointer.
+    mstore(64, 128)
+    /* Reverts unconditionally. */
+    revert(0, 0)
+    // The trailing comment.
+}
+"#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        lexer.set_preserve_comments(true);
+        let statement = Statement::<DefaultDialect>::parse(&mut lexer, None)
+            .unwrap()
+            .0;
+        let mut result = String::new();
+        let mut writer = WritePrinter::<&mut String>::new(&mut result);
+        Visitor::<DefaultDialect>::visit_statement(&mut writer, &statement);
+
+        let lines = result
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            lines,
+            vec![
+                "code  {",
+                "// Initializes the free memory pointer.",
+                "mstore(64, 128)",
+                "/* Reverts unconditionally. */",
+                "revert(0, 0)",
+                "// The trailing comment.",
+                "}",
+            ]
+        );
+    }
+}
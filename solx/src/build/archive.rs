@@ -0,0 +1,222 @@
+//!
+//! The build artifacts archive.
+//!
+
+use std::io::Write;
+
+///
+/// The build artifacts archive format.
+///
+/// Entries are written without compression, and with zeroed timestamps and ownership,
+/// so the same entries always produce the same archive.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The POSIX `ustar` archive.
+    Tar,
+    /// The ZIP archive with stored entries.
+    Zip,
+}
+
+impl Format {
+    /// The size of a `tar` block.
+    const TAR_BLOCK_SIZE: usize = 512;
+
+    /// The maximum length of the `tar` entry name field.
+    const TAR_NAME_LENGTH: usize = 100;
+
+    /// The maximum length of the `tar` entry name prefix field.
+    const TAR_PREFIX_LENGTH: usize = 155;
+
+    /// The ZIP local file header signature.
+    const ZIP_LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x04034B50;
+
+    /// The ZIP central directory file header signature.
+    const ZIP_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x02014B50;
+
+    /// The ZIP end of central directory record signature.
+    const ZIP_END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054B50;
+
+    /// The ZIP version needed to extract stored entries.
+    const ZIP_VERSION: u16 = 20;
+
+    /// The ZIP general purpose flag of UTF-8 entry names.
+    const ZIP_FLAG_UTF8: u16 = 1 << 11;
+
+    /// The ZIP date of 1980-01-01, which is the earliest date representable in the format.
+    const ZIP_DATE: u16 = (1 << 5) | 1;
+
+    /// The maximum number of ZIP entries, as ZIP64 is not supported.
+    pub const ZIP_MAX_ENTRIES: usize = u16::MAX as usize;
+
+    ///
+    /// Writes the `entries` of path and content pairs into `writer`.
+    ///
+    /// The entries are written in the given order.
+    ///
+    pub fn write(
+        self,
+        writer: &mut impl Write,
+        entries: &[(String, Vec<u8>)],
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Tar => Self::write_tar(writer, entries),
+            Self::Zip => Self::write_zip(writer, entries),
+        }
+    }
+
+    ///
+    /// Writes the `ustar` archive.
+    ///
+    fn write_tar(writer: &mut impl Write, entries: &[(String, Vec<u8>)]) -> anyhow::Result<()> {
+        for (path, content) in entries.iter() {
+            let mut header = [0u8; Self::TAR_BLOCK_SIZE];
+
+            let (prefix, name) = if path.len() <= Self::TAR_NAME_LENGTH {
+                ("", path.as_str())
+            } else {
+                path.char_indices()
+                    .filter(|(index, character)| {
+                        *character == '/'
+                            && *index <= Self::TAR_PREFIX_LENGTH
+                            && path.len() - index - 1 <= Self::TAR_NAME_LENGTH
+                    })
+                    .map(|(index, _)| (&path[..index], &path[index + 1..]))
+                    .next()
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Archive entry path `{path}` is too long for tar.")
+                    })?
+            };
+            header[..name.len()].copy_from_slice(name.as_bytes());
+            header[100..107].copy_from_slice(b"0000644");
+            header[108..115].copy_from_slice(b"0000000");
+            header[116..123].copy_from_slice(b"0000000");
+            header[124..135].copy_from_slice(format!("{:011o}", content.len()).as_bytes());
+            header[136..147].copy_from_slice(b"00000000000");
+            header[148..156].copy_from_slice(b"        ");
+            header[156] = b'0';
+            header[257..263].copy_from_slice(b"ustar\0");
+            header[263..265].copy_from_slice(b"00");
+            header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+
+            let checksum: u32 = header.iter().map(|byte| *byte as u32).sum();
+            header[148..156].copy_from_slice(format!("{checksum:06o}\0 ").as_bytes());
+
+            writer.write_all(&header)?;
+            writer.write_all(content.as_slice())?;
+            let padding = (Self::TAR_BLOCK_SIZE - content.len() % Self::TAR_BLOCK_SIZE)
+                % Self::TAR_BLOCK_SIZE;
+            writer.write_all(vec![0u8; padding].as_slice())?;
+        }
+        writer.write_all(&[0u8; Self::TAR_BLOCK_SIZE * 2])?;
+        Ok(())
+    }
+
+    ///
+    /// Writes the ZIP archive.
+    ///
+    fn write_zip(writer: &mut impl Write, entries: &[(String, Vec<u8>)]) -> anyhow::Result<()> {
+        if entries.len() > Self::ZIP_MAX_ENTRIES {
+            anyhow::bail!(
+                "Too many archive entries for ZIP: {}. The maximum is {}, as ZIP64 is not supported.",
+                entries.len(),
+                Self::ZIP_MAX_ENTRIES,
+            );
+        }
+        let entries_count = entries.len() as u16;
+
+        let mut offset: u32 = 0;
+        let mut central_directory = Vec::new();
+        for (path, content) in entries.iter() {
+            let name_length = u16::try_from(path.len())
+                .map_err(|_| anyhow::anyhow!("Archive entry path `{path}` is too long for ZIP."))?;
+            let size = u32::try_from(content.len())
+                .map_err(|_| anyhow::anyhow!("Archive entry `{path}` is too large for ZIP."))?;
+            let crc = Self::crc32(content.as_slice());
+
+            let mut header = Vec::with_capacity(30 + path.len());
+            header.extend(Self::ZIP_LOCAL_FILE_HEADER_SIGNATURE.to_le_bytes());
+            header.extend(Self::ZIP_VERSION.to_le_bytes());
+            header.extend(Self::ZIP_FLAG_UTF8.to_le_bytes());
+            header.extend(0u16.to_le_bytes());
+            header.extend(0u16.to_le_bytes());
+            header.extend(Self::ZIP_DATE.to_le_bytes());
+            header.extend(crc.to_le_bytes());
+            header.extend(size.to_le_bytes());
+            header.extend(size.to_le_bytes());
+            header.extend(name_length.to_le_bytes());
+            header.extend(0u16.to_le_bytes());
+            header.extend(path.as_bytes());
+
+            central_directory.extend(Self::ZIP_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+            central_directory.extend(Self::ZIP_VERSION.to_le_bytes());
+            central_directory.extend(header[4..30].iter());
+            central_directory.extend(0u16.to_le_bytes());
+            central_directory.extend(0u16.to_le_bytes());
+            central_directory.extend(0u16.to_le_bytes());
+            central_directory.extend(0u32.to_le_bytes());
+            central_directory.extend(offset.to_le_bytes());
+            central_directory.extend(path.as_bytes());
+
+            writer.write_all(header.as_slice())?;
+            writer.write_all(content.as_slice())?;
+            offset = offset
+                .checked_add(header.len() as u32)
+                .and_then(|offset| offset.checked_add(size))
+                .ok_or_else(|| anyhow::anyhow!("Archive is too large for ZIP."))?;
+        }
+        let central_directory_size = u32::try_from(central_directory.len())
+            .map_err(|_| anyhow::anyhow!("Archive is too large for ZIP."))?;
+        writer.write_all(central_directory.as_slice())?;
+
+        let mut end = Vec::with_capacity(22);
+        end.extend(Self::ZIP_END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        end.extend(0u16.to_le_bytes());
+        end.extend(0u16.to_le_bytes());
+        end.extend(entries_count.to_le_bytes());
+        end.extend(entries_count.to_le_bytes());
+        end.extend(central_directory_size.to_le_bytes());
+        end.extend(offset.to_le_bytes());
+        end.extend(0u16.to_le_bytes());
+        writer.write_all(end.as_slice())?;
+        Ok(())
+    }
+
+    ///
+    /// Computes the CRC-32 checksum of `data`, as required by ZIP.
+    ///
+    fn crc32(data: &[u8]) -> u32 {
+        let mut crc = u32::MAX;
+        for byte in data.iter() {
+            crc ^= *byte as u32;
+            for _ in 0..8 {
+                let mask = (crc & 1).wrapping_neg();
+                crc = (crc >> 1) ^ (0xEDB88320 & mask);
+            }
+        }
+        !crc
+    }
+}
+
+impl std::str::FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "tar" => Ok(Self::Tar),
+            "zip" => Ok(Self::Zip),
+            string => {
+                anyhow::bail!("Invalid archive format `{string}`. Available formats: `tar`, `zip`.")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tar => write!(f, "tar"),
+            Self::Zip => write!(f, "zip"),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//!
+//! The contract bytecode analysis.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The contract bytecode analysis.
+///
+/// Helps finding the reasons of unexpectedly large bytecode, such as constants
+/// that are pushed many times instead of being deduplicated.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Analysis {
+    /// The number of occurrences of each opcode.
+    pub opcodes: BTreeMap<u8, usize>,
+    /// The number of `PUSH` instructions by their immediate size in bytes.
+    pub push_sizes: BTreeMap<usize, usize>,
+    /// The number of occurrences of each `PUSH` immediate pushed more than once, as a hexadecimal string.
+    pub repeated_constants: BTreeMap<String, usize>,
+}
+
+impl Analysis {
+    /// The `PUSH0` opcode.
+    pub const OPCODE_PUSH0: u8 = 0x5F;
+
+    /// The `PUSH1` opcode.
+    pub const OPCODE_PUSH1: u8 = 0x60;
+
+    /// The `PUSH32` opcode.
+    pub const OPCODE_PUSH32: u8 = 0x7F;
+
+    /// The EVM versions in the order of their activation, named as in `solc`.
+    pub const EVM_VERSIONS: [&'static str; 13] = [
+        "homestead",
+        "tangerineWhistle",
+        "spuriousDragon",
+        "byzantium",
+        "constantinople",
+        "petersburg",
+        "istanbul",
+        "berlin",
+        "london",
+        "paris",
+        "shanghai",
+        "cancun",
+        "prague",
+    ];
+
+    /// The opcodes introduced after Homestead, along with the EVM versions introducing them.
+    pub const OPCODES_INTRODUCED: [(u8, &'static str); 18] = [
+        (0x3D, "byzantium"),      // RETURNDATASIZE
+        (0x3E, "byzantium"),      // RETURNDATACOPY
+        (0xFA, "byzantium"),      // STATICCALL
+        (0xFD, "byzantium"),      // REVERT
+        (0x1B, "constantinople"), // SHL
+        (0x1C, "constantinople"), // SHR
+        (0x1D, "constantinople"), // SAR
+        (0x3F, "constantinople"), // EXTCODEHASH
+        (0xF5, "constantinople"), // CREATE2
+        (0x46, "istanbul"),       // CHAINID
+        (0x47, "istanbul"),       // SELFBALANCE
+        (0x48, "london"),         // BASEFEE
+        (0x5F, "shanghai"),       // PUSH0
+        (0x49, "cancun"),         // BLOBHASH
+        (0x4A, "cancun"),         // BLOBBASEFEE
+        (0x5C, "cancun"),         // TLOAD
+        (0x5D, "cancun"),         // TSTORE
+        (0x5E, "cancun"),         // MCOPY
+    ];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Decodes `bytecode` opcode by opcode, so `PUSH` immediates are never counted as opcodes.
+    /// An immediate truncated by the end of bytecode is taken as is.
+    ///
+    pub fn new(bytecode: &[u8]) -> Self {
+        let mut opcodes = BTreeMap::new();
+        let mut push_sizes = BTreeMap::new();
+        let mut constants: BTreeMap<&[u8], usize> = BTreeMap::new();
+
+        let mut offset = 0;
+        while offset < bytecode.len() {
+            let opcode = bytecode[offset];
+            *opcodes.entry(opcode).or_default() += 1;
+            offset += 1;
+
+            if (Self::OPCODE_PUSH0..=Self::OPCODE_PUSH32).contains(&opcode) {
+                let size = (opcode - Self::OPCODE_PUSH0) as usize;
+                *push_sizes.entry(size).or_default() += 1;
+                if size > 0 {
+                    let end = (offset + size).min(bytecode.len());
+                    *constants.entry(&bytecode[offset..end]).or_default() += 1;
+                    offset = end;
+                }
+            }
+        }
+
+        let repeated_constants = constants
+            .into_iter()
+            .filter(|(_constant, count)| *count > 1)
+            .map(|(constant, count)| (hex::encode(constant), count))
+            .collect();
+
+        Self {
+            opcodes,
+            push_sizes,
+            repeated_constants,
+        }
+    }
+
+    ///
+    /// Returns the total number of decoded instructions.
+    ///
+    pub fn instructions_count(&self) -> usize {
+        self.opcodes.values().sum()
+    }
+
+    ///
+    /// Returns the largest constant pushed more than once, along with its number of occurrences.
+    ///
+    /// Among the constants of the same size, the most frequent one is returned.
+    ///
+    pub fn largest_repeated_constant(&self) -> Option<(&str, usize)> {
+        self.repeated_constants
+            .iter()
+            .max_by(|(constant_1, count_1), (constant_2, count_2)| {
+                constant_1
+                    .len()
+                    .cmp(&constant_2.len())
+                    .then(count_1.cmp(count_2))
+                    .then(constant_2.cmp(constant_1))
+            })
+            .map(|(constant, count)| (constant.as_str(), *count))
+    }
+
+    ///
+    /// Returns the opcodes unavailable in `evm_version`, named as in `solc`.
+    ///
+    /// Returns `None` if the EVM version is unknown.
+    ///
+    pub fn unsupported_opcodes(&self, evm_version: &str) -> Option<Vec<u8>> {
+        let version_index = Self::EVM_VERSIONS
+            .iter()
+            .position(|version| *version == evm_version)?;
+        Some(
+            Self::OPCODES_INTRODUCED
+                .iter()
+                .filter(|(opcode, introduced)| {
+                    self.opcodes.contains_key(opcode)
+                        && Self::EVM_VERSIONS
+                            .iter()
+                            .position(|version| version == introduced)
+                            .expect("Always exists")
+                            > version_index
+                })
+                .map(|(opcode, _introduced)| *opcode)
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,55 @@
+//!
+//! The contract JSON artifact.
+//!
+
+///
+/// The contract JSON artifact.
+///
+/// Follows the layout of the Hardhat artifacts, so it can be consumed by `ethers.js`, `web3.js`,
+/// and other JavaScript tooling as is. The fields are serialized in the order of declaration,
+/// and the ABI object keys are sorted, so the artifacts are reproducible.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Artifact {
+    /// The contract name.
+    pub contract_name: String,
+    /// The source file path.
+    pub source_name: String,
+    /// The contract ABI. Empty for the contracts without one, e.g. Yul objects.
+    pub abi: serde_json::Value,
+    /// The creation bytecode, prefixed with `0x`.
+    pub bytecode: String,
+    /// The runtime bytecode, prefixed with `0x`.
+    pub deployed_bytecode: String,
+    /// The contract metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+}
+
+impl Artifact {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        contract_name: String,
+        source_name: String,
+        abi: Option<serde_json::Value>,
+        deploy_bytecode: &[u8],
+        runtime_bytecode: &[u8],
+        metadata: Option<String>,
+    ) -> Self {
+        Self {
+            contract_name,
+            source_name,
+            abi: abi.unwrap_or_else(|| serde_json::Value::Array(vec![])),
+            bytecode: format!(
+                "0x{}{}",
+                hex::encode(deploy_bytecode),
+                hex::encode(runtime_bytecode)
+            ),
+            deployed_bytecode: format!("0x{}", hex::encode(runtime_bytecode)),
+            metadata,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//!
+//! The contract coverage map.
+//!
+
+use crate::build::contract::assembly::Assembly;
+use crate::build::diff::ObjectDiff;
+
+///
+/// The contract coverage map.
+///
+/// Splits the runtime bytecode into basic blocks, explicitly marking the branch points,
+/// so coverage tools can report which branches have been executed.
+///
+/// The blocks are not mapped to source spans, as the EVM backend does not emit
+/// debug locations yet, so tools have to rely on the source maps for that.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CoverageMap {
+    /// The basic blocks in the bytecode order.
+    pub blocks: Vec<BasicBlock>,
+}
+
+///
+/// The bytecode basic block.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BasicBlock {
+    /// The offset of the first instruction.
+    pub start: usize,
+    /// The offset right after the last instruction.
+    pub end: usize,
+    /// Whether the block starts with `JUMPDEST`, so it can be jumped to.
+    pub is_jump_target: bool,
+    /// Whether the block ends with `JUMPI`, so the execution can continue in two blocks.
+    pub is_branch_point: bool,
+}
+
+impl CoverageMap {
+    /// The `JUMPDEST` opcode.
+    pub const OPCODE_JUMPDEST: u8 = 0x5B;
+
+    /// The `JUMPI` opcode.
+    pub const OPCODE_JUMPI: u8 = 0x57;
+
+    /// The opcodes that end a basic block, except for `JUMPI`.
+    pub const OPCODES_TERMINATING: [u8; 6] = [
+        0x00, // STOP
+        0x56, // JUMP
+        0xF3, // RETURN
+        0xFD, // REVERT
+        0xFE, // INVALID
+        0xFF, // SELFDESTRUCT
+    ];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The trailing CBOR metadata is stripped, as it is not executable.
+    ///
+    pub fn new(bytecode: &[u8]) -> Self {
+        let assembly = Assembly::new(ObjectDiff::strip_metadata(bytecode));
+
+        let mut blocks: Vec<BasicBlock> = Vec::new();
+        let mut is_block_open = false;
+        for instruction in assembly.instructions.iter() {
+            let end = instruction.offset
+                + 1
+                + instruction
+                    .operand
+                    .as_ref()
+                    .map(|operand| operand.len())
+                    .unwrap_or_default();
+
+            if instruction.opcode == Self::OPCODE_JUMPDEST || !is_block_open {
+                blocks.push(BasicBlock {
+                    start: instruction.offset,
+                    end,
+                    is_jump_target: instruction.opcode == Self::OPCODE_JUMPDEST,
+                    is_branch_point: false,
+                });
+                is_block_open = true;
+            }
+            let block = blocks.last_mut().expect("Always exists");
+            block.end = end;
+
+            if instruction.opcode == Self::OPCODE_JUMPI {
+                block.is_branch_point = true;
+                is_block_open = false;
+            } else if Self::OPCODES_TERMINATING.contains(&instruction.opcode) {
+                is_block_open = false;
+            }
+        }
+
+        Self { blocks }
+    }
+
+    ///
+    /// Returns the basic blocks ending with a branch.
+    ///
+    pub fn branch_points(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.iter().filter(|block| block.is_branch_point)
+    }
+
+    ///
+    /// Returns the basic block containing the bytecode `offset`, if any.
+    ///
+    pub fn block_at(&self, offset: usize) -> Option<&BasicBlock> {
+        let index = self.blocks.partition_point(|block| block.end <= offset);
+        self.blocks.get(index).filter(|block| block.start <= offset)
+    }
+}
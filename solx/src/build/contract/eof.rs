@@ -0,0 +1,467 @@
+//!
+//! The EVM Object Format (EOF) container.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::build::contract::assembly::Instruction as BytecodeAssemblyInstruction;
+
+///
+/// The EVM Object Format (EOF) container.
+///
+/// Only a single code section and an optional data section are supported.
+/// The code is rejected if it contains instructions deprecated in EOF, but the rest of the EOF
+/// code validation rules, e.g. the stack validation, are not checked.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Container {
+    /// The maximum stack height of the code section.
+    pub max_stack_height: u16,
+    /// The code section.
+    pub code: Vec<u8>,
+    /// The data section.
+    pub data: Vec<u8>,
+}
+
+impl Container {
+    /// The EOF magic bytes.
+    pub const MAGIC: [u8; 2] = [0xEF, 0x00];
+
+    /// The supported EOF version.
+    pub const VERSION: u8 = 0x01;
+
+    /// The type section kind.
+    pub const KIND_TYPE: u8 = 0x01;
+
+    /// The code section kind.
+    pub const KIND_CODE: u8 = 0x02;
+
+    /// The data section kind.
+    pub const KIND_DATA: u8 = 0xFF;
+
+    /// The header terminator.
+    pub const TERMINATOR: u8 = 0x00;
+
+    /// The size of a single type section entry.
+    pub const TYPE_ENTRY_SIZE: u16 = 4;
+
+    /// The number of outputs of non-returning code sections.
+    pub const NON_RETURNING_OUTPUTS: u8 = 0x80;
+
+    /// The maximum stack height allowed by EOF.
+    pub const MAX_STACK_HEIGHT: u16 = 1023;
+
+    /// The size of the header with a single code section.
+    pub const HEADER_SIZE: usize = 15;
+
+    /// The instructions deprecated in EOF, which are rejected in the code section.
+    pub const DEPRECATED_OPCODES: [u8; 16] = [
+        0x38, // CODESIZE
+        0x39, // CODECOPY
+        0x3B, // EXTCODESIZE
+        0x3C, // EXTCODECOPY
+        0x3F, // EXTCODEHASH
+        0x56, // JUMP
+        0x57, // JUMPI
+        0x58, // PC
+        0x5A, // GAS
+        0xF0, // CREATE
+        0xF1, // CALL
+        0xF2, // CALLCODE
+        0xF4, // DELEGATECALL
+        0xF5, // CREATE2
+        0xFA, // STATICCALL
+        0xFF, // SELFDESTRUCT
+    ];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Computes the maximum stack height of `code`.
+    ///
+    /// # Errors
+    /// If the code contains instructions deprecated in EOF, or the sections exceed the size limits.
+    ///
+    pub fn new(code: Vec<u8>, data: Vec<u8>) -> anyhow::Result<Self> {
+        if code.is_empty() {
+            anyhow::bail!("EOF code section cannot be empty.");
+        }
+        Self::check_deprecated_opcodes(code.as_slice())?;
+        if code.len() > u16::MAX as usize {
+            anyhow::bail!(
+                "EOF code section size {} exceeds the limit of {} bytes.",
+                code.len(),
+                u16::MAX
+            );
+        }
+        if data.len() > u16::MAX as usize {
+            anyhow::bail!(
+                "EOF data section size {} exceeds the limit of {} bytes.",
+                data.len(),
+                u16::MAX
+            );
+        }
+
+        let max_stack_height = Self::compute_max_stack_height(code.as_slice())?;
+        Ok(Self {
+            max_stack_height,
+            code,
+            data,
+        })
+    }
+
+    ///
+    /// Serializes the container into bytes.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(Self::HEADER_SIZE + 4 + self.code.len() + self.data.len());
+
+        bytes.extend(Self::MAGIC);
+        bytes.push(Self::VERSION);
+        bytes.push(Self::KIND_TYPE);
+        bytes.extend(Self::TYPE_ENTRY_SIZE.to_be_bytes());
+        bytes.push(Self::KIND_CODE);
+        bytes.extend(1u16.to_be_bytes());
+        bytes.extend((self.code.len() as u16).to_be_bytes());
+        bytes.push(Self::KIND_DATA);
+        bytes.extend((self.data.len() as u16).to_be_bytes());
+        bytes.push(Self::TERMINATOR);
+
+        bytes.push(0);
+        bytes.push(Self::NON_RETURNING_OUTPUTS);
+        bytes.extend(self.max_stack_height.to_be_bytes());
+
+        bytes.extend(self.code.as_slice());
+        bytes.extend(self.data.as_slice());
+        bytes
+    }
+
+    ///
+    /// Replaces the legacy runtime code embedded in `deploy_code` with the container.
+    ///
+    /// The size of the runtime code pushed by the deploy code before the runtime code copy
+    /// is updated to the container size, so the deploy code returns the container instead.
+    ///
+    /// # Errors
+    /// If `runtime_code` is not found in `deploy_code`, its size is not pushed by the deploy code,
+    /// or the container size does not fit into the pushed value.
+    ///
+    pub fn replace_embedded(
+        &self,
+        deploy_code: &[u8],
+        runtime_code: &[u8],
+    ) -> anyhow::Result<Vec<u8>> {
+        if runtime_code.is_empty() {
+            anyhow::bail!("EOF cannot replace the empty runtime code.");
+        }
+        let runtime_offset = deploy_code
+            .windows(runtime_code.len())
+            .position(|window| window == runtime_code)
+            .ok_or_else(|| anyhow::anyhow!("Runtime code is not found in the deploy code."))?;
+        let container = self.to_bytes();
+
+        let mut deploy_code_prefix = deploy_code[..runtime_offset].to_vec();
+        let mut size_references = 0;
+        let mut offset = 0;
+        while offset < deploy_code_prefix.len() {
+            let opcode = deploy_code_prefix[offset];
+            offset += 1;
+            if !(0x60..=0x7F).contains(&opcode) {
+                continue;
+            }
+
+            let operand_size = (opcode - 0x5F) as usize;
+            let operand_end = (offset + operand_size).min(deploy_code_prefix.len());
+            let operand = &mut deploy_code_prefix[offset..operand_end];
+            offset = operand_end;
+            if operand.len() > std::mem::size_of::<usize>()
+                || operand
+                    .iter()
+                    .fold(0usize, |value, byte| (value << 8) | *byte as usize)
+                    != runtime_code.len()
+            {
+                continue;
+            }
+
+            let container_size = container.len().to_be_bytes();
+            let (overflow, container_size) =
+                container_size.split_at(container_size.len() - operand.len());
+            if overflow.iter().any(|byte| *byte != 0) {
+                anyhow::bail!(
+                    "EOF container size {} does not fit into the {}-byte runtime code size pushed by the deploy code.",
+                    container.len(),
+                    operand.len(),
+                );
+            }
+            operand.copy_from_slice(container_size);
+            size_references += 1;
+        }
+        if size_references == 0 {
+            anyhow::bail!("Runtime code size is not pushed by the deploy code.");
+        }
+
+        let mut bytes = deploy_code_prefix;
+        bytes.extend(container);
+        bytes.extend(&deploy_code[runtime_offset + runtime_code.len()..]);
+        Ok(bytes)
+    }
+
+    ///
+    /// Parses and validates the container header, and extracts the sections.
+    ///
+    pub fn try_from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = Reader::new(bytes);
+
+        if reader.take(2)? != Self::MAGIC {
+            anyhow::bail!("EOF magic is missing.");
+        }
+        let version = reader.take_u8()?;
+        if version != Self::VERSION {
+            anyhow::bail!("EOF version {version} is not supported.");
+        }
+
+        reader.expect_kind(Self::KIND_TYPE)?;
+        let type_size = reader.take_u16()?;
+        if type_size != Self::TYPE_ENTRY_SIZE {
+            anyhow::bail!("EOF type section size {type_size} is invalid, as only one code section is supported.");
+        }
+
+        reader.expect_kind(Self::KIND_CODE)?;
+        let code_sections = reader.take_u16()?;
+        if code_sections != 1 {
+            anyhow::bail!("EOF code section number {code_sections} is not supported.");
+        }
+        let code_size = reader.take_u16()? as usize;
+        if code_size == 0 {
+            anyhow::bail!("EOF code section cannot be empty.");
+        }
+
+        reader.expect_kind(Self::KIND_DATA)?;
+        let data_size = reader.take_u16()? as usize;
+        reader.expect_kind(Self::TERMINATOR)?;
+
+        let inputs = reader.take_u8()?;
+        let outputs = reader.take_u8()?;
+        if inputs != 0 || outputs != Self::NON_RETURNING_OUTPUTS {
+            anyhow::bail!("EOF first code section must have no inputs and be non-returning.");
+        }
+        let max_stack_height = reader.take_u16()?;
+        if max_stack_height > Self::MAX_STACK_HEIGHT {
+            anyhow::bail!(
+                "EOF maximum stack height {max_stack_height} exceeds the limit of {}.",
+                Self::MAX_STACK_HEIGHT
+            );
+        }
+
+        let code = reader.take(code_size)?.to_vec();
+        let data = reader.take(data_size)?.to_vec();
+        if !reader.is_empty() {
+            anyhow::bail!("EOF container has trailing bytes.");
+        }
+
+        Ok(Self {
+            max_stack_height,
+            code,
+            data,
+        })
+    }
+
+    ///
+    /// Checks that `code` does not contain instructions deprecated in EOF.
+    ///
+    /// The errors list each deprecated instruction along with the offset of its first occurrence.
+    ///
+    fn check_deprecated_opcodes(code: &[u8]) -> anyhow::Result<()> {
+        let mut deprecated = BTreeMap::new();
+
+        let mut offset = 0;
+        while offset < code.len() {
+            let opcode = code[offset];
+            if Self::DEPRECATED_OPCODES.contains(&opcode) {
+                deprecated.entry(opcode).or_insert(offset);
+            }
+
+            offset += 1;
+            if (0x60..=0x7F).contains(&opcode) {
+                offset += (opcode - 0x5F) as usize;
+            }
+        }
+        if deprecated.is_empty() {
+            return Ok(());
+        }
+
+        let mut deprecated = deprecated.into_iter().collect::<Vec<(u8, usize)>>();
+        deprecated.sort_by_key(|(_opcode, offset)| *offset);
+        anyhow::bail!(
+            "EOF code section contains instructions deprecated in EOF: {}.",
+            deprecated
+                .into_iter()
+                .map(|(opcode, offset)| format!(
+                    "`{}` at offset {offset}",
+                    BytecodeAssemblyInstruction {
+                        offset,
+                        opcode,
+                        operand: None,
+                    }
+                    .mnemonic()
+                    .expect("Always exists")
+                ))
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+    }
+
+    ///
+    /// Computes the maximum stack height of `code` over its basic blocks.
+    ///
+    /// As the control flow is not followed, the items a block consumes from its predecessors
+    /// are counted as a part of its own stack height.
+    ///
+    fn compute_max_stack_height(code: &[u8]) -> anyhow::Result<u16> {
+        let mut max_stack_height: isize = 0;
+
+        let mut height: isize = 0;
+        let mut min_height: isize = 0;
+        let mut max_height: isize = 0;
+
+        let mut offset = 0;
+        while offset < code.len() {
+            let opcode = code[offset];
+            if opcode == 0x5B {
+                height = 0;
+                min_height = 0;
+                max_height = 0;
+            }
+
+            let (inputs, outputs) = Self::stack_effect(opcode);
+            min_height = min_height.min(height - inputs);
+            height = height - inputs + outputs;
+            max_height = max_height.max(height);
+            max_stack_height = max_stack_height.max(max_height - min_height);
+
+            if let 0x00 | 0x56 | 0xF3 | 0xFD | 0xFE | 0xFF = opcode {
+                height = 0;
+                min_height = 0;
+                max_height = 0;
+            }
+
+            offset += 1;
+            if (0x60..=0x7F).contains(&opcode) {
+                offset += (opcode - 0x5F) as usize;
+            }
+        }
+
+        if max_stack_height > Self::MAX_STACK_HEIGHT as isize {
+            anyhow::bail!(
+                "EOF maximum stack height {max_stack_height} exceeds the limit of {}.",
+                Self::MAX_STACK_HEIGHT
+            );
+        }
+        Ok(max_stack_height as u16)
+    }
+
+    ///
+    /// Returns the number of stack inputs and outputs of `opcode`.
+    ///
+    /// Unknown opcodes are assumed to have no effect on the stack.
+    ///
+    fn stack_effect(opcode: u8) -> (isize, isize) {
+        match opcode {
+            0x00 | 0x5B | 0xFE => (0, 0),
+            0x01..=0x07 | 0x0A | 0x0B => (2, 1),
+            0x08 | 0x09 => (3, 1),
+            0x10..=0x14 | 0x16..=0x18 | 0x1A..=0x1D | 0x20 => (2, 1),
+            0x15 | 0x19 => (1, 1),
+            0x31 | 0x35 | 0x3B | 0x3F | 0x40 | 0x49 | 0x51 | 0x54 | 0x5C => (1, 1),
+            0x30 | 0x32..=0x34 | 0x36 | 0x38 | 0x3A | 0x3D | 0x41..=0x48 | 0x4A => (0, 1),
+            0x58..=0x5A | 0x5F..=0x7F => (0, 1),
+            0x37 | 0x39 | 0x3E | 0x5E => (3, 0),
+            0x3C => (4, 0),
+            0x50 | 0x56 | 0xFF => (1, 0),
+            0x52 | 0x53 | 0x55 | 0x57 | 0x5D | 0xF3 | 0xFD => (2, 0),
+            0x80..=0x8F => {
+                let depth = (opcode - 0x7F) as isize;
+                (depth, depth + 1)
+            }
+            0x90..=0x9F => {
+                let depth = (opcode - 0x8E) as isize;
+                (depth, depth)
+            }
+            0xA0..=0xA4 => ((opcode - 0xA0) as isize + 2, 0),
+            0xF0 => (3, 1),
+            0xF1 | 0xF2 => (7, 1),
+            0xF4 | 0xFA => (6, 1),
+            0xF5 => (4, 1),
+            _ => (0, 0),
+        }
+    }
+}
+
+///
+/// The EOF container byte reader.
+///
+struct Reader<'a> {
+    /// The container bytes.
+    bytes: &'a [u8],
+    /// The current offset.
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    ///
+    /// Takes the next `size` bytes.
+    ///
+    fn take(&mut self, size: usize) -> anyhow::Result<&'a [u8]> {
+        let end = self.offset + size;
+        if end > self.bytes.len() {
+            anyhow::bail!("EOF container is truncated at offset {}.", self.offset);
+        }
+        let slice = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    ///
+    /// Takes the next byte.
+    ///
+    fn take_u8(&mut self) -> anyhow::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    ///
+    /// Takes the next big-endian 16-bit integer.
+    ///
+    fn take_u16(&mut self) -> anyhow::Result<u16> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    ///
+    /// Takes the next byte and checks that it is the expected section kind.
+    ///
+    fn expect_kind(&mut self, kind: u8) -> anyhow::Result<()> {
+        let offset = self.offset;
+        let found = self.take_u8()?;
+        if found != kind {
+            anyhow::bail!(
+                "EOF header expected kind 0x{kind:02x} at offset {offset}, found 0x{found:02x}."
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks whether all bytes have been read.
+    ///
+    fn is_empty(&self) -> bool {
+        self.offset == self.bytes.len()
+    }
+}
@@ -0,0 +1,172 @@
+//!
+//! The output file name template.
+//!
+
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+///
+/// The output file name template.
+///
+/// Defines the artifact paths relative to the contract output path, so the output directory
+/// can follow the naming conventions of the existing tooling. The available placeholders are:
+///
+/// - `{path}`: the name of the source file the contract is defined in;
+/// - `{name}`: the contract name, followed by the artifact suffix, e.g. `_meta` or `-runtime`;
+/// - `{ext}`: the artifact extension, e.g. `bin`, `abi`, or `json`.
+///
+/// Literal braces are written as `{{` and `}}`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilenameTemplate {
+    /// The template string.
+    template: String,
+}
+
+impl FilenameTemplate {
+    /// The default template, where the artifacts are grouped by source file.
+    pub const DEFAULT: &'static str = "{path}/{name}.{ext}";
+
+    /// The source file name placeholder.
+    pub const PLACEHOLDER_PATH: &'static str = "path";
+
+    /// The contract name placeholder.
+    pub const PLACEHOLDER_NAME: &'static str = "name";
+
+    /// The artifact extension placeholder.
+    pub const PLACEHOLDER_EXTENSION: &'static str = "ext";
+
+    /// The placeholders that must be present, as the artifacts would collide otherwise.
+    pub const PLACEHOLDERS_REQUIRED: [&'static str; 2] =
+        [Self::PLACEHOLDER_NAME, Self::PLACEHOLDER_EXTENSION];
+
+    ///
+    /// Renders the artifact path inside `output_path`.
+    ///
+    pub fn render(&self, output_path: &Path, path: &str, name: &str, extension: &str) -> PathBuf {
+        let mut result = String::with_capacity(self.template.len());
+        let mut characters = self.template.chars().peekable();
+        while let Some(character) = characters.next() {
+            match character {
+                '{' if characters.peek() == Some(&'{') => {
+                    characters.next();
+                    result.push('{');
+                }
+                '}' if characters.peek() == Some(&'}') => {
+                    characters.next();
+                    result.push('}');
+                }
+                '{' => {
+                    let placeholder = characters
+                        .by_ref()
+                        .take_while(|character| *character != '}')
+                        .collect::<String>();
+                    result.push_str(match placeholder.as_str() {
+                        Self::PLACEHOLDER_PATH => path,
+                        Self::PLACEHOLDER_NAME => name,
+                        Self::PLACEHOLDER_EXTENSION => extension,
+                        _ => unreachable!("Validated on construction"),
+                    });
+                }
+                character => result.push(character),
+            }
+        }
+        output_path.join(result)
+    }
+
+    ///
+    /// Returns the placeholders used in `template`.
+    ///
+    fn placeholders(template: &str) -> anyhow::Result<Vec<String>> {
+        let mut placeholders = Vec::new();
+        let mut characters = template.chars().peekable();
+        while let Some(character) = characters.next() {
+            match character {
+                '{' if characters.peek() == Some(&'{') => {
+                    characters.next();
+                }
+                '}' if characters.peek() == Some(&'}') => {
+                    characters.next();
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match characters.next() {
+                            Some('}') => break,
+                            Some(character) => placeholder.push(character),
+                            None => anyhow::bail!(
+                                "Output file name template `{template}` has an unclosed placeholder."
+                            ),
+                        }
+                    }
+                    placeholders.push(placeholder);
+                }
+                '}' => anyhow::bail!(
+                    "Output file name template `{template}` has an unmatched `}}`. Use `}}}}` for a literal brace."
+                ),
+                _ => {}
+            }
+        }
+        Ok(placeholders)
+    }
+}
+
+impl Default for FilenameTemplate {
+    fn default() -> Self {
+        Self {
+            template: Self::DEFAULT.to_owned(),
+        }
+    }
+}
+
+impl std::str::FromStr for FilenameTemplate {
+    type Err = anyhow::Error;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        let placeholders = Self::placeholders(template)?;
+        for placeholder in placeholders.iter() {
+            if ![
+                Self::PLACEHOLDER_PATH,
+                Self::PLACEHOLDER_NAME,
+                Self::PLACEHOLDER_EXTENSION,
+            ]
+            .contains(&placeholder.as_str())
+            {
+                anyhow::bail!(
+                    "Output file name template `{template}` has an unknown placeholder `{{{placeholder}}}`. Available placeholders: `{{path}}`, `{{name}}`, `{{ext}}`."
+                );
+            }
+        }
+        for required in Self::PLACEHOLDERS_REQUIRED.into_iter() {
+            if !placeholders
+                .iter()
+                .any(|placeholder| placeholder == required)
+            {
+                anyhow::bail!(
+                    "Output file name template `{template}` must contain the `{{{required}}}` placeholder."
+                );
+            }
+        }
+
+        let result = Self {
+            template: template.to_owned(),
+        };
+        let sample = result.render(Path::new(""), "Source.sol", "Contract", "bin");
+        if sample
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+        {
+            anyhow::bail!(
+                "Output file name template `{template}` must produce paths inside the output directory."
+            );
+        }
+        Ok(result)
+    }
+}
+
+impl std::fmt::Display for FilenameTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.template)
+    }
+}
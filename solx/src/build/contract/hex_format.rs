@@ -0,0 +1,41 @@
+//!
+//! The terminal bytecode hexadecimal format.
+//!
+
+///
+/// The terminal bytecode hexadecimal format.
+///
+/// Defaults to the bare lowercase hexadecimal, following `solc`.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HexFormat {
+    /// Whether the bytecode is prefixed with `0x`.
+    pub prefix: bool,
+    /// Whether the uppercase hexadecimal digits are used.
+    pub uppercase: bool,
+}
+
+impl HexFormat {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(prefix: bool, uppercase: bool) -> Self {
+        Self { prefix, uppercase }
+    }
+
+    ///
+    /// Encodes `bytes` into the hexadecimal string.
+    ///
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        let encoded = if self.uppercase {
+            hex::encode_upper(bytes)
+        } else {
+            hex::encode(bytes)
+        };
+        if self.prefix {
+            format!("0x{encoded}")
+        } else {
+            encoded
+        }
+    }
+}
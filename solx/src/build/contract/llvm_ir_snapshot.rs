@@ -0,0 +1,67 @@
+//!
+//! The LLVM IR snapshot taken after an optimization pass.
+//!
+
+///
+/// The LLVM IR snapshot taken after an optimization pass.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Snapshot {
+    /// The name of the pass the snapshot was taken after.
+    pub pass: String,
+    /// The code segment of the module.
+    pub code_segment: era_compiler_common::CodeSegment,
+    /// The textual LLVM IR of the whole module.
+    pub ir: String,
+}
+
+impl Snapshot {
+    /// The header LLVM prints before each IR dump.
+    pub const DUMP_HEADER: &'static str = "; *** IR Dump After ";
+
+    /// The module identifier line prefix.
+    pub const MODULE_ID_PREFIX: &'static str = "; ModuleID = '";
+
+    ///
+    /// Returns the LLVM options printing the module after each of the `passes`.
+    ///
+    pub fn llvm_options(passes: &[String]) -> Vec<String> {
+        vec![
+            format!("-print-after={}", passes.join(",")),
+            "-print-module-scope".to_owned(),
+        ]
+    }
+
+    ///
+    /// Parses the IR dumps printed by LLVM with the options from [`Self::llvm_options`].
+    ///
+    /// The text not belonging to any dump, e.g. other diagnostics, is ignored.
+    /// The module code segment is inferred from the module identifier, as the runtime
+    /// module names are suffixed with the code segment.
+    ///
+    pub fn parse_dump(text: &str) -> Vec<Self> {
+        let runtime_suffix = format!(".{}'", era_compiler_common::CodeSegment::Runtime);
+
+        text.split(Self::DUMP_HEADER)
+            .skip(1)
+            .filter_map(|dump| {
+                let (header, ir) = dump.split_once('\n')?;
+                let pass = header.split(' ').next()?.to_owned();
+                let code_segment = match ir
+                    .lines()
+                    .find(|line| line.starts_with(Self::MODULE_ID_PREFIX))
+                {
+                    Some(line) if line.ends_with(runtime_suffix.as_str()) => {
+                        era_compiler_common::CodeSegment::Runtime
+                    }
+                    _ => era_compiler_common::CodeSegment::Deploy,
+                };
+                Some(Self {
+                    pass,
+                    code_segment,
+                    ir: ir.trim_end().to_owned(),
+                })
+            })
+            .collect()
+    }
+}
@@ -2,13 +2,37 @@
 //! The Solidity contract build.
 //!
 
+pub mod analysis;
+pub mod artifact;
+pub mod assembly;
+pub mod coverage;
+pub mod eof;
+pub mod filename_template;
+pub mod hex_format;
+pub mod llvm_ir_snapshot;
 pub mod object;
+pub mod optimization;
+pub mod verification;
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 
+use self::analysis::Analysis as BytecodeAnalysis;
+use self::artifact::Artifact;
+use self::assembly::Assembly as BytecodeAssembly;
+use self::assembly::Instruction as BytecodeAssemblyInstruction;
+use self::coverage::CoverageMap;
+use self::eof::Container as EOFContainer;
+use self::filename_template::FilenameTemplate;
+use self::hex_format::HexFormat;
+use self::llvm_ir_snapshot::Snapshot as LLVMIRSnapshot;
 use self::object::Object;
+use self::optimization::Optimization;
+use self::verification::VerificationResult;
 
 ///
 /// The Solidity contract build.
@@ -23,6 +47,27 @@ pub struct Contract {
     pub runtime_object: Option<Object>,
     /// The combined `solc` and `solx` metadata.
     pub metadata: Option<String>,
+    /// The wall-clock durations of the deploy and runtime code generation.
+    #[serde(default)]
+    pub timings: (Duration, Duration),
+    /// The optimization mode, if overridden for this contract.
+    #[serde(default)]
+    pub optimization_mode: Option<char>,
+    /// The immutable identifiers mapped to their offsets in the runtime bytecode.
+    /// Empty if the contract has no immutables or the input language does not support them.
+    #[serde(default)]
+    pub immutable_references: BTreeMap<String, BTreeSet<u64>>,
+    /// The `solc` ABI JSON.
+    /// Only available for Solidity contracts.
+    #[serde(default)]
+    pub abi: Option<serde_json::Value>,
+    /// The LLVM optimizer settings the contract was compiled with.
+    /// Not set if the bytecode was not requested.
+    #[serde(default)]
+    pub optimization: Option<Optimization>,
+    /// The LLVM IR snapshots taken after the requested optimization passes, in the pipeline order.
+    #[serde(default)]
+    pub llvm_ir_snapshots: Vec<LLVMIRSnapshot>,
 }
 
 impl Contract {
@@ -34,29 +79,209 @@ impl Contract {
         deploy_object: Option<Object>,
         runtime_object: Option<Object>,
         metadata: Option<String>,
+        timings: (Duration, Duration),
     ) -> Self {
         Self {
             name,
             deploy_object,
             runtime_object,
             metadata,
+            timings,
+            optimization_mode: None,
+            immutable_references: BTreeMap::new(),
+            abi: None,
+            optimization: None,
+            llvm_ir_snapshots: Vec::new(),
         }
     }
 
+    ///
+    /// Analyzes the runtime bytecode.
+    ///
+    /// Returns an empty analysis if there is no runtime code.
+    ///
+    pub fn bytecode_analysis(&self) -> BytecodeAnalysis {
+        self.runtime_object
+            .as_ref()
+            .map(|object| BytecodeAnalysis::new(object.bytecode.as_slice()))
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Disassembles the runtime bytecode.
+    ///
+    /// Returns `None` if there is no runtime code.
+    ///
+    pub fn assembly(&self) -> Option<BytecodeAssembly> {
+        self.runtime_object
+            .as_ref()
+            .map(|object| BytecodeAssembly::new(object.bytecode.as_slice()))
+    }
+
+    ///
+    /// Decodes the runtime bytecode instruction starting at `offset`.
+    ///
+    /// Returns `None` if there is no runtime code, or `offset` is not a valid instruction start,
+    /// e.g. it points inside a `PUSH` immediate.
+    ///
+    pub fn decode_at(&self, offset: usize) -> Option<BytecodeAssemblyInstruction> {
+        self.assembly()?.instruction_at(offset).cloned()
+    }
+
+    ///
+    /// Disassembles the runtime bytecode into the instructions, skipping over the `PUSH` immediates.
+    ///
+    /// Returns an empty list if there is no runtime code.
+    ///
+    pub fn disassemble(&self) -> Vec<BytecodeAssemblyInstruction> {
+        self.assembly()
+            .map(|assembly| assembly.instructions)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the basic blocks and branch points of the runtime bytecode.
+    ///
+    /// Returns `None` if there is no runtime code.
+    ///
+    pub fn coverage_map(&self) -> Option<CoverageMap> {
+        self.runtime_object
+            .as_ref()
+            .map(|object| CoverageMap::new(object.bytecode.as_slice()))
+    }
+
+    ///
+    /// Compares the runtime bytecode with the `deployed` one, e.g. fetched from a chain.
+    ///
+    /// See [`VerificationResult::new`] for the normalization details.
+    ///
+    pub fn verify_against(&self, deployed: &[u8]) -> VerificationResult {
+        let compiled = self
+            .runtime_object
+            .as_ref()
+            .map(|object| object.bytecode.as_slice())
+            .unwrap_or_default();
+        VerificationResult::new(compiled, deployed, &self.immutable_references)
+    }
+
+    ///
+    /// Wraps the runtime bytecode into an EOF container.
+    ///
+    /// The runtime code copy embedded in the deploy code is replaced with the container as well,
+    /// so the deploy code returns the wrapped runtime code.
+    ///
+    /// # Errors
+    /// If the runtime code is not linked yet, or cannot be represented as an EOF code section.
+    /// If the deploy code sets immutables, as their offsets in the returned code are shifted.
+    ///
+    pub fn wrap_runtime_eof(&mut self) -> anyhow::Result<()> {
+        let object = match self.runtime_object.as_mut() {
+            Some(object) => object,
+            None => return Ok(()),
+        };
+        if object.format == era_compiler_common::ObjectFormat::ELF {
+            anyhow::bail!(
+                "Contract `{}` runtime code cannot be wrapped into EOF, as it is not linked yet.",
+                self.name.full_path
+            );
+        }
+
+        let container = EOFContainer::new(object.bytecode.clone(), vec![])
+            .map_err(|error| anyhow::anyhow!("Contract `{}`: {error}", self.name.full_path))?;
+        if let Some(deploy_object) = self.deploy_object.as_mut() {
+            if !self.immutable_references.is_empty() {
+                anyhow::bail!(
+                    "Contract `{}` runtime code cannot be wrapped into EOF, as its deploy code sets immutables.",
+                    self.name.full_path
+                );
+            }
+            deploy_object.bytecode = container
+                .replace_embedded(
+                    deploy_object.bytecode.as_slice(),
+                    object.bytecode.as_slice(),
+                )
+                .map_err(|error| {
+                    anyhow::anyhow!("Contract `{}` deploy code: {error}", self.name.full_path)
+                })?;
+        }
+        object.bytecode = container.to_bytes();
+        object.is_eof = true;
+
+        let code_offset =
+            (EOFContainer::HEADER_SIZE + EOFContainer::TYPE_ENTRY_SIZE as usize) as u64;
+        for offsets in self.immutable_references.values_mut() {
+            *offsets = offsets.iter().map(|offset| offset + code_offset).collect();
+        }
+        Ok(())
+    }
+
     ///
     /// Writes the contract text assembly and bytecode to terminal.
     ///
-    pub fn write_to_terminal(self, path: String, output_metadata: bool) -> anyhow::Result<()> {
+    /// The bytecode is encoded according to `hex_format`.
+    ///
+    pub fn write_to_terminal(
+        self,
+        path: String,
+        output_metadata: bool,
+        output_abi: bool,
+        output_bytecode: bool,
+        output_runtime_bytecode: bool,
+        hex_format: HexFormat,
+    ) -> anyhow::Result<()> {
         writeln!(std::io::stdout(), "\n======= {path} =======")?;
 
-        if self.deploy_object.is_some() || self.runtime_object.is_some() {
-            let deploy_bytecode = self.deploy_object.map(|object| object.bytecode);
-            let runtime_bytecode = self.runtime_object.map(|object| object.bytecode);
+        let deploy_bytecode = self
+            .deploy_object
+            .as_ref()
+            .map(|object| object.bytecode.as_slice());
+        let runtime_bytecode = self
+            .runtime_object
+            .as_ref()
+            .map(|object| object.bytecode.as_slice());
+        if output_bytecode && (deploy_bytecode.is_some() || runtime_bytecode.is_some()) {
             writeln!(
                 std::io::stdout(),
-                "Binary:\n{}{}",
-                hex::encode(deploy_bytecode.unwrap_or_default()),
-                hex::encode(runtime_bytecode.unwrap_or_default()),
+                "Binary:\n{}",
+                hex_format.encode(
+                    [
+                        deploy_bytecode.unwrap_or_default(),
+                        runtime_bytecode.unwrap_or_default(),
+                    ]
+                    .concat()
+                    .as_slice()
+                ),
+            )?;
+        }
+        if let Some(runtime_bytecode) = runtime_bytecode.filter(|_| output_runtime_bytecode) {
+            writeln!(
+                std::io::stdout(),
+                "Binary of the runtime part:\n{}",
+                hex_format.encode(runtime_bytecode),
+            )?;
+        }
+
+        if let Some(llvm_ir) = self
+            .deploy_object
+            .as_ref()
+            .and_then(|object| object.llvm_ir.as_deref())
+        {
+            writeln!(std::io::stdout(), "LLVM IR:\n{llvm_ir}")?;
+        }
+        if let Some(llvm_ir) = self
+            .runtime_object
+            .as_ref()
+            .and_then(|object| object.llvm_ir.as_deref())
+        {
+            writeln!(std::io::stdout(), "LLVM IR of the runtime part:\n{llvm_ir}")?;
+        }
+        for snapshot in self.llvm_ir_snapshots.iter() {
+            writeln!(
+                std::io::stdout(),
+                "LLVM IR of the {} part after `{}`:\n{}",
+                snapshot.code_segment,
+                snapshot.pass,
+                snapshot.ir
             )?;
         }
 
@@ -68,75 +293,276 @@ impl Contract {
             )?;
         }
 
+        if output_abi {
+            writeln!(
+                std::io::stdout(),
+                "Contract JSON ABI:\n{}",
+                serde_json::to_string(&self.abi.expect("Always exists")).expect("Always valid")
+            )?;
+        }
+
         Ok(())
     }
 
     ///
     /// Writes the contract text assembly and bytecode to files.
     ///
+    /// If `only_changed` is set, the files whose content is already up to date are not written,
+    /// so their modification times are preserved.
+    ///
+    /// Returns the paths of the files, each paired with the flag showing whether it has been written.
+    ///
     pub fn write_to_directory(
         self,
         output_path: &Path,
         overwrite: bool,
+        only_changed: bool,
         output_metadata: bool,
-    ) -> anyhow::Result<()> {
-        let file_path = PathBuf::from(self.name.path);
-        let file_name = file_path
-            .file_name()
-            .expect("Always exists")
-            .to_str()
-            .expect("Always valid");
-
-        let mut output_path = output_path.to_owned();
-        output_path.push(file_name);
-        std::fs::create_dir_all(output_path.as_path())?;
-
-        if self.deploy_object.is_some() || self.runtime_object.is_some() {
-            let output_name = format!(
-                "{}.{}",
-                self.name.name.as_deref().unwrap_or(file_name),
-                era_compiler_common::EXTENSION_EVM_BINARY
-            );
-            let mut output_path = output_path.clone();
-            output_path.push(output_name.as_str());
+        output_abi: bool,
+        output_bytecode: bool,
+        output_runtime_bytecode: bool,
+        output_contract_json: bool,
+        filename_template: &FilenameTemplate,
+    ) -> anyhow::Result<Vec<(PathBuf, bool)>> {
+        let files = self.directory_files(
+            output_path,
+            output_metadata,
+            output_abi,
+            output_bytecode,
+            output_runtime_bytecode,
+            output_contract_json,
+            filename_template,
+        );
+        Self::write_files(files, overwrite, only_changed)
+    }
 
+    ///
+    /// Writes the `files` returned by `directory_files`, creating their parent directories.
+    ///
+    /// If `only_changed` is set, the files whose content is already up to date are not written.
+    ///
+    /// Returns the paths of the files, each paired with the flag showing whether it has been written.
+    ///
+    pub fn write_files(
+        files: Vec<(PathBuf, String)>,
+        overwrite: bool,
+        only_changed: bool,
+    ) -> anyhow::Result<Vec<(PathBuf, bool)>> {
+        let mut written = Vec::with_capacity(files.len());
+        for (output_path, content) in files.into_iter() {
+            if let Some(parent) = output_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            if only_changed
+                && std::fs::read(output_path.as_path())
+                    .is_ok_and(|existing| existing == content.as_bytes())
+            {
+                written.push((output_path, false));
+                continue;
+            }
             if output_path.exists() && !overwrite {
                 anyhow::bail!(
                     "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
                 );
-            } else {
-                let deploy_bytecode = self.deploy_object.map(|object| object.bytecode);
-                let runtime_bytecode = self.runtime_object.map(|object| object.bytecode);
-                let bytecode = format!(
-                    "{}{}",
-                    hex::encode(deploy_bytecode.unwrap_or_default()),
-                    hex::encode(runtime_bytecode.unwrap_or_default()),
+            }
+            std::fs::write(output_path.as_path(), content)
+                .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+            written.push((output_path, true));
+        }
+
+        Ok(written)
+    }
+
+    ///
+    /// Returns the files that would be written by `write_to_directory`, without touching the file system.
+    ///
+    /// Each file path is paired with the flag showing whether the file already exists.
+    ///
+    pub fn plan_directory_writes(
+        &self,
+        output_path: &Path,
+        output_metadata: bool,
+        output_abi: bool,
+        output_bytecode: bool,
+        output_runtime_bytecode: bool,
+        output_contract_json: bool,
+        filename_template: &FilenameTemplate,
+    ) -> Vec<(PathBuf, bool)> {
+        self.directory_files(
+            output_path,
+            output_metadata,
+            output_abi,
+            output_bytecode,
+            output_runtime_bytecode,
+            output_contract_json,
+            filename_template,
+        )
+        .into_iter()
+        .map(|(output_path, _content)| {
+            let exists = output_path.exists();
+            (output_path, exists)
+        })
+        .collect()
+    }
+
+    ///
+    /// Returns the files to be written to the output directory as pairs of paths and contents.
+    ///
+    pub fn directory_files(
+        &self,
+        output_path: &Path,
+        output_metadata: bool,
+        output_abi: bool,
+        output_bytecode: bool,
+        output_runtime_bytecode: bool,
+        output_contract_json: bool,
+        filename_template: &FilenameTemplate,
+    ) -> Vec<(PathBuf, String)> {
+        let file_name = self.file_name();
+        let output_name = self.name.name.as_deref().unwrap_or(self.file_name());
+
+        let mut files = Vec::with_capacity(4);
+
+        let deploy_bytecode = self
+            .deploy_object
+            .as_ref()
+            .map(|object| object.bytecode.as_slice());
+        let runtime_bytecode = self
+            .runtime_object
+            .as_ref()
+            .map(|object| object.bytecode.as_slice());
+        if output_bytecode && (deploy_bytecode.is_some() || runtime_bytecode.is_some()) {
+            let output_path = filename_template.render(
+                output_path,
+                file_name,
+                output_name,
+                era_compiler_common::EXTENSION_EVM_BINARY,
+            );
+
+            let bytecode = format!(
+                "{}{}",
+                hex::encode(deploy_bytecode.unwrap_or_default()),
+                hex::encode(runtime_bytecode.unwrap_or_default()),
+            );
+            files.push((output_path, bytecode));
+        }
+
+        if let Some(runtime_bytecode) = runtime_bytecode.filter(|_| output_runtime_bytecode) {
+            let output_path = filename_template.render(
+                output_path,
+                file_name,
+                output_name,
+                format!("{}-runtime", era_compiler_common::EXTENSION_EVM_BINARY).as_str(),
+            );
+            files.push((output_path, hex::encode(runtime_bytecode)));
+        }
+
+        for object in [self.deploy_object.as_ref(), self.runtime_object.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            if let Some(ref llvm_ir) = object.llvm_ir {
+                let suffix = match object.code_segment {
+                    era_compiler_common::CodeSegment::Deploy => "",
+                    era_compiler_common::CodeSegment::Runtime => "-runtime",
+                };
+                let output_path = filename_template.render(
+                    output_path,
+                    file_name,
+                    format!("{output_name}{suffix}").as_str(),
+                    era_compiler_common::EXTENSION_LLVM_SOURCE,
                 );
-                std::fs::write(output_path.as_path(), bytecode)
-                    .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+                files.push((output_path, llvm_ir.to_owned()));
             }
         }
 
+        let mut snapshot_counts: BTreeMap<(&str, &str), usize> = BTreeMap::new();
+        for snapshot in self.llvm_ir_snapshots.iter() {
+            let suffix = match snapshot.code_segment {
+                era_compiler_common::CodeSegment::Deploy => "",
+                era_compiler_common::CodeSegment::Runtime => "-runtime",
+            };
+            let count = snapshot_counts
+                .entry((suffix, snapshot.pass.as_str()))
+                .or_default();
+            let output_path = filename_template.render(
+                output_path,
+                file_name,
+                format!("{output_name}{suffix}.after-{}.{count}", snapshot.pass).as_str(),
+                era_compiler_common::EXTENSION_LLVM_SOURCE,
+            );
+            files.push((output_path, snapshot.ir.to_owned()));
+            *count += 1;
+        }
+
         if output_metadata {
-            let output_name = format!(
-                "{}_meta.{}",
-                self.name.name.as_deref().unwrap_or(file_name),
+            let output_path = filename_template.render(
+                output_path,
+                file_name,
+                format!("{output_name}_meta").as_str(),
                 era_compiler_common::EXTENSION_JSON,
             );
-            let mut output_path = output_path.clone();
-            output_path.push(output_name.as_str());
+            files.push((output_path, self.metadata.clone().expect("Always exists")));
+        }
 
-            if output_path.exists() && !overwrite {
-                anyhow::bail!(
-                    "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
-                );
-            } else {
-                std::fs::write(output_path.as_path(), self.metadata.expect("Always exists"))
-                    .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
-            }
+        if output_abi {
+            let output_path = filename_template.render(
+                output_path,
+                file_name,
+                output_name,
+                era_compiler_common::EXTENSION_ABI,
+            );
+            let abi = serde_json::to_string(self.abi.as_ref().expect("Always exists"))
+                .expect("Always valid");
+            files.push((output_path, abi));
         }
 
-        Ok(())
+        if output_contract_json {
+            let output_path = filename_template.render(
+                output_path,
+                file_name,
+                output_name,
+                era_compiler_common::EXTENSION_JSON,
+            );
+            let artifact = serde_json::to_string_pretty(&self.artifact()).expect("Always valid");
+            files.push((output_path, artifact));
+        }
+
+        files
+    }
+
+    ///
+    /// Returns the contract JSON artifact in the layout expected by the JavaScript tooling.
+    ///
+    pub fn artifact(&self) -> Artifact {
+        Artifact::new(
+            self.name
+                .name
+                .clone()
+                .unwrap_or_else(|| self.file_name().to_owned()),
+            self.name.path.clone(),
+            self.abi.clone(),
+            self.deploy_object
+                .as_ref()
+                .map(|object| object.bytecode.as_slice())
+                .unwrap_or_default(),
+            self.runtime_object
+                .as_ref()
+                .map(|object| object.bytecode.as_slice())
+                .unwrap_or_default(),
+            self.metadata.clone(),
+        )
+    }
+
+    ///
+    /// Returns the name of the source file the contract is defined in.
+    ///
+    fn file_name(&self) -> &str {
+        Path::new(self.name.path.as_str())
+            .file_name()
+            .expect("Always exists")
+            .to_str()
+            .expect("Always valid")
     }
 
     ///
@@ -145,26 +571,84 @@ impl Contract {
     pub fn write_to_standard_json(
         self,
         standard_json_contract: &mut solx_standard_json::OutputContract,
+        output_selection: Option<&solx_standard_json::InputSelection>,
     ) -> anyhow::Result<()> {
-        standard_json_contract.metadata = self.metadata;
+        let is_selected = |selector: solx_standard_json::InputSelector| {
+            output_selection
+                .map(|output_selection| {
+                    output_selection.check_selection(
+                        self.name.path.as_str(),
+                        self.name.name.as_deref(),
+                        selector,
+                    )
+                })
+                .unwrap_or(true)
+        };
+        let is_metadata_selected = is_selected(solx_standard_json::InputSelector::Metadata);
+        let is_gas_estimates_selected =
+            is_selected(solx_standard_json::InputSelector::GasEstimates);
+        let is_bytecode_selected = is_selected(solx_standard_json::InputSelector::BytecodeObject);
+        let is_runtime_bytecode_selected =
+            is_selected(solx_standard_json::InputSelector::RuntimeBytecodeObject);
+
+        if is_metadata_selected {
+            standard_json_contract.metadata = self.metadata;
+        }
+        standard_json_contract.optimization_mode = self.optimization_mode;
+        if let Some(abi) = self.abi {
+            standard_json_contract.abi = abi;
+        }
 
         let evm = standard_json_contract
             .evm
             .get_or_insert_with(solx_standard_json::OutputContractEVM::default);
-        evm.bytecode = self.deploy_object.map(|object| {
-            solx_standard_json::OutputContractEVMBytecode::new(
-                hex::encode(object.bytecode),
-                object.unlinked_libraries,
-                object.format,
-            )
-        });
-        evm.deployed_bytecode = self.runtime_object.map(|object| {
-            solx_standard_json::OutputContractEVMBytecode::new(
-                hex::encode(object.bytecode),
-                object.unlinked_libraries,
-                object.format,
-            )
-        });
+        if let Some(object) = self
+            .runtime_object
+            .as_ref()
+            .filter(|_| is_gas_estimates_selected)
+        {
+            evm.gas_estimates = Some(solx_standard_json::OutputContractEVMGasEstimates::new(
+                object.bytecode.len(),
+            ));
+        }
+        evm.bytecode = self
+            .deploy_object
+            .filter(|_| is_bytecode_selected)
+            .map(|object| {
+                solx_standard_json::OutputContractEVMBytecode::new(
+                    hex::encode(object.bytecode),
+                    object.unlinked_libraries,
+                    object.format,
+                    object.source_map,
+                )
+            });
+        let immutable_references = self
+            .immutable_references
+            .into_iter()
+            .map(|(identifier, offsets)| {
+                (
+                    identifier,
+                    offsets
+                        .into_iter()
+                        .map(solx_standard_json::OutputContractEVMImmutableReference::new)
+                        .collect(),
+                )
+            })
+            .collect();
+        evm.deployed_bytecode = self
+            .runtime_object
+            .filter(|_| is_runtime_bytecode_selected)
+            .map(|object| {
+                let mut bytecode = solx_standard_json::OutputContractEVMBytecode::new(
+                    hex::encode(object.bytecode),
+                    object.unlinked_libraries,
+                    object.format,
+                    object.source_map,
+                );
+                bytecode.immutable_references = Some(immutable_references);
+                bytecode.cbor_metadata = Some(object.has_cbor_metadata);
+                bytecode
+            });
 
         Ok(())
     }
@@ -5,6 +5,8 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::build::contract::analysis::Analysis as BytecodeAnalysis;
+
 ///
 /// Bytecode object.
 ///
@@ -32,11 +34,30 @@ pub struct Object {
     pub is_assembled: bool,
     /// Binary object format.
     pub format: era_compiler_common::ObjectFormat,
+    /// Whether the bytecode is wrapped into an EOF container.
+    #[serde(default)]
+    pub is_eof: bool,
+    /// Whether the CBOR metadata has been appended to the bytecode during assembling.
+    #[serde(default)]
+    pub has_cbor_metadata: bool,
     /// Compilation warnings.
     pub warnings: Vec<era_compiler_llvm_context::EVMWarning>,
+    /// The ELF bytecode before the first linking, preserved for relinking.
+    pub elf_bytecode: Option<Vec<u8>>,
+    /// The linker symbols the object has been linked with.
+    pub linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    /// The source map of the bytecode.
+    /// Only available if the contract definition location is known, e.g. from the `solc` AST.
+    pub source_map: Option<String>,
+    /// The textual LLVM IR of the object before optimization.
+    /// Only available if requested with `--emit-llvm`.
+    pub llvm_ir: Option<String>,
 }
 
 impl Object {
+    /// The output format of the objects wrapped into EOF containers.
+    pub const FORMAT_EOF: &'static str = "eof";
+
     ///
     /// A shortcut constructor.
     ///
@@ -63,7 +84,13 @@ impl Object {
             unlinked_libraries,
             is_assembled: false,
             format,
+            is_eof: false,
+            has_cbor_metadata: false,
             warnings,
+            elf_bytecode: None,
+            linker_symbols: BTreeMap::new(),
+            source_map: None,
+            llvm_ir: None,
         }
     }
 
@@ -94,6 +121,25 @@ impl Object {
         Ok(memory_buffer)
     }
 
+    ///
+    /// Sets the source map mapping each instruction to the contract definition at `location`.
+    ///
+    /// The LLVM EVM backend does not emit debug information yet, so the source map has the granularity of
+    /// the contract definition. Unlinked ELF objects are skipped, as their instructions cannot be decoded.
+    ///
+    pub fn set_contract_source_map(&mut self, location: &str) {
+        if self.format == era_compiler_common::ObjectFormat::ELF {
+            return;
+        }
+
+        let instructions_count =
+            BytecodeAnalysis::new(self.bytecode.as_slice()).instructions_count();
+        self.source_map = Some(match instructions_count {
+            0 => String::new(),
+            count => format!("{location}:-{}", ";".repeat(count - 1)),
+        });
+    }
+
     ///
     /// Assembles the object.
     ///
@@ -143,6 +189,16 @@ impl Object {
         &mut self,
         linker_symbols: &BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
     ) -> anyhow::Result<()> {
+        if self.format == era_compiler_common::ObjectFormat::ELF && self.elf_bytecode.is_none() {
+            self.elf_bytecode = Some(self.bytecode.clone());
+        }
+        self.linker_symbols.extend(
+            linker_symbols
+                .iter()
+                .filter(|(symbol, _address)| self.unlinked_libraries.contains(symbol.as_str()))
+                .map(|(symbol, address)| (symbol.to_owned(), *address)),
+        );
+
         let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
             self.bytecode.as_slice(),
             self.identifier.as_str(),
@@ -152,11 +208,56 @@ impl Object {
         let (linked_object, object_format) =
             era_compiler_llvm_context::evm_link(memory_buffer, linker_symbols)?;
         self.format = object_format;
+        match self.format {
+            era_compiler_common::ObjectFormat::ELF => self
+                .unlinked_libraries
+                .retain(|library| !linker_symbols.contains_key(library)),
+            _ => self.unlinked_libraries.clear(),
+        }
 
         self.bytecode = linked_object.as_slice().to_owned();
         Ok(())
     }
 
+    ///
+    /// Links the object again from its preserved ELF bytecode.
+    ///
+    /// The `updated_symbols` override the symbols the object has been linked with before.
+    ///
+    pub fn relink(
+        &mut self,
+        updated_symbols: &BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> anyhow::Result<()> {
+        let elf_bytecode = self.elf_bytecode.clone().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Contract `{}` {} code cannot be relinked, as its original ELF relocation data was discarded.",
+                self.contract_name.full_path,
+                self.code_segment,
+            )
+        })?;
+
+        let mut linker_symbols = std::mem::take(&mut self.linker_symbols);
+        linker_symbols.extend(updated_symbols.clone());
+        self.unlinked_libraries
+            .extend(linker_symbols.keys().cloned());
+        self.bytecode = elf_bytecode;
+        self.format = era_compiler_common::ObjectFormat::ELF;
+        self.link(&linker_symbols)
+    }
+
+    ///
+    /// Returns the object format as reported in the output.
+    ///
+    /// The objects wrapped into EOF containers are reported as [`Self::FORMAT_EOF`],
+    /// and the rest as their binary object format.
+    ///
+    pub fn output_format(&self) -> serde_json::Value {
+        if self.is_eof {
+            return serde_json::Value::from(Self::FORMAT_EOF);
+        }
+        serde_json::to_value(self.format).expect("Always valid")
+    }
+
     ///
     /// Whether the object requires assebmling with its dependencies.
     ///
@@ -0,0 +1,68 @@
+//!
+//! The LLVM optimizer settings applied to the contract.
+//!
+
+///
+/// The LLVM optimizer settings applied to the contract.
+///
+/// Captured when the LLVM context is configured, so contracts with per-contract overrides
+/// report the settings they were actually compiled with.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Optimization {
+    /// The optimizer mode, e.g. `M3B3` or `MzB3`.
+    pub mode: String,
+    /// Whether the code size or the execution speed was prioritized.
+    pub priority: Priority,
+    /// Whether the optimizer was allowed to fall back to optimizing for size.
+    pub is_size_fallback_enabled: bool,
+}
+
+///
+/// The optimizer priority.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    /// The execution speed.
+    Speed,
+    /// The code size.
+    Size,
+}
+
+impl Optimization {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(settings: &era_compiler_llvm_context::OptimizerSettings) -> Self {
+        let mode = settings.to_string();
+        let priority = match mode.chars().nth(1) {
+            Some('s' | 'z') => Priority::Size,
+            _ => Priority::Speed,
+        };
+        Self {
+            mode,
+            priority,
+            is_size_fallback_enabled: settings.is_fallback_to_size_enabled(),
+        }
+    }
+}
+
+impl std::fmt::Display for Optimization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mode)?;
+        if self.is_size_fallback_enabled {
+            write!(f, " (size fallback)")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Speed => write!(f, "speed"),
+            Self::Size => write!(f, "size"),
+        }
+    }
+}
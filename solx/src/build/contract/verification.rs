@@ -0,0 +1,82 @@
+//!
+//! The contract bytecode verification result.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::build::diff::ObjectDiff;
+
+///
+/// The contract bytecode verification result.
+///
+/// Distinguishes metadata-only mismatches, which are expected if the sources differ in
+/// comments or formatting only, from the code mismatches.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub enum VerificationResult {
+    /// Both the code and metadata match.
+    Match,
+    /// The code matches, but the trailing CBOR metadata differs.
+    MetadataMismatch,
+    /// The code differs.
+    CodeMismatch(ObjectDiff),
+}
+
+impl VerificationResult {
+    ///
+    /// Compares the compiled runtime bytecode with the deployed one.
+    ///
+    /// The values of immutables are zeroed on both sides, as they are only known after deployment.
+    /// The trailing CBOR metadata is stripped from both sides and compared separately.
+    ///
+    pub fn new(
+        compiled: &[u8],
+        deployed: &[u8],
+        immutable_references: &BTreeMap<String, BTreeSet<u64>>,
+    ) -> Self {
+        let compiled_code = ObjectDiff::strip_metadata(compiled);
+        let deployed_code = ObjectDiff::strip_metadata(deployed);
+
+        let compiled_code = Self::zero_immutables(compiled_code, immutable_references);
+        let deployed_code = Self::zero_immutables(deployed_code, immutable_references);
+        if let Some(diff) =
+            ObjectDiff::new(compiled_code.as_slice(), deployed_code.as_slice(), false)
+        {
+            return Self::CodeMismatch(diff);
+        }
+
+        if compiled[compiled_code.len()..] == deployed[deployed_code.len()..] {
+            Self::Match
+        } else {
+            Self::MetadataMismatch
+        }
+    }
+
+    ///
+    /// Whether the code matches, regardless of the metadata.
+    ///
+    pub fn is_code_match(&self) -> bool {
+        !matches!(self, Self::CodeMismatch(_))
+    }
+
+    ///
+    /// Returns a copy of `code` with the immutable values zeroed.
+    ///
+    /// References out of the code bounds are ignored.
+    ///
+    fn zero_immutables(
+        code: &[u8],
+        immutable_references: &BTreeMap<String, BTreeSet<u64>>,
+    ) -> Vec<u8> {
+        let mut code = code.to_owned();
+        for offset in immutable_references.values().flatten() {
+            let start = *offset as usize;
+            let end = start + era_compiler_common::BYTE_LENGTH_FIELD;
+            if let Some(value) = code.get_mut(start..end) {
+                value.fill(0);
+            }
+        }
+        code
+    }
+}
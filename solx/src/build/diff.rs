@@ -0,0 +1,143 @@
+//!
+//! The Solidity project build diff.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The Solidity project build diff.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Diff {
+    /// The full paths of contracts only present in the other build.
+    pub added: Vec<String>,
+    /// The full paths of contracts only present in this build.
+    pub removed: Vec<String>,
+    /// The contracts present in both builds, but with differing bytecode.
+    pub changed: BTreeMap<String, ContractDiff>,
+}
+
+impl Diff {
+    ///
+    /// Checks whether the builds are bytecode-neutral.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+///
+/// The bytecode difference of a single contract.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ContractDiff {
+    /// The deploy bytecode difference, if any.
+    pub deploy: Option<ObjectDiff>,
+    /// The runtime bytecode difference, if any.
+    pub runtime: Option<ObjectDiff>,
+}
+
+///
+/// The difference of a single bytecode object.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ObjectDiff {
+    /// The length of the other bytecode minus the length of this one.
+    pub length_delta: isize,
+    /// The offset of the first differing byte.
+    /// If one bytecode is a prefix of the other, it is the length of the shorter one.
+    pub first_difference: usize,
+}
+
+impl ObjectDiff {
+    ///
+    /// Compares two bytecodes, returning `None` if they are equal.
+    ///
+    /// If `ignore_metadata` is set, the trailing CBOR metadata is stripped before comparison.
+    ///
+    pub fn new(this: &[u8], other: &[u8], ignore_metadata: bool) -> Option<Self> {
+        let (this, other) = if ignore_metadata {
+            (Self::strip_metadata(this), Self::strip_metadata(other))
+        } else {
+            (this, other)
+        };
+        if this == other {
+            return None;
+        }
+
+        let first_difference = this
+            .iter()
+            .zip(other.iter())
+            .position(|(this, other)| this != other)
+            .unwrap_or_else(|| this.len().min(other.len()));
+        Some(Self {
+            length_delta: other.len() as isize - this.len() as isize,
+            first_difference,
+        })
+    }
+
+    ///
+    /// Strips the trailing CBOR metadata from `bytecode`.
+    ///
+    /// The metadata is recognized by its big-endian length suffix pointing at a CBOR map header.
+    /// If there is no such suffix, the bytecode is returned as is.
+    ///
+    pub fn strip_metadata(bytecode: &[u8]) -> &[u8] {
+        if bytecode.len() < 2 {
+            return bytecode;
+        }
+        let length_offset = bytecode.len() - 2;
+        let length =
+            u16::from_be_bytes([bytecode[length_offset], bytecode[length_offset + 1]]) as usize;
+        if length == 0 || length > length_offset {
+            return bytecode;
+        }
+        let metadata_offset = length_offset - length;
+        match bytecode[metadata_offset] {
+            0xA1..=0xB7 => &bytecode[..metadata_offset],
+            _ => bytecode,
+        }
+    }
+}
+
+impl std::fmt::Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No bytecode changes.");
+        }
+
+        for path in self.added.iter() {
+            writeln!(f, "+ {path}")?;
+        }
+        for path in self.removed.iter() {
+            writeln!(f, "- {path}")?;
+        }
+        for (path, contract) in self.changed.iter() {
+            writeln!(f, "~ {path}: {contract}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for ContractDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let objects: Vec<String> = [
+            ("deploy", self.deploy.as_ref()),
+            ("runtime", self.runtime.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(kind, object)| Some(format!("{kind} {}", object?)))
+        .collect();
+        write!(f, "{}", objects.join(", "))
+    }
+}
+
+impl std::fmt::Display for ObjectDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:+} bytes, first difference at byte {}",
+            self.length_delta, self.first_difference
+        )
+    }
+}
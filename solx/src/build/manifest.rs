@@ -0,0 +1,167 @@
+//!
+//! The build manifest.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use path_slash::PathExt;
+use sha2::Digest;
+
+use crate::build::contract::optimization::Optimization;
+
+///
+/// The build manifest.
+///
+/// Lists the artifacts written to the output directory along with the settings they were
+/// produced with, so the whole output can be attested with a single signature.
+///
+/// Contains no timestamps or absolute paths, and all collections are ordered,
+/// so identical builds always produce identical manifests.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Manifest {
+    /// The output schema version.
+    /// See [`solx_standard_json::Output::VERSION`] for details.
+    #[serde(rename = "solxOutputVersion")]
+    pub version: u32,
+    /// The compiler that produced the artifacts.
+    pub compiler: Compiler,
+    /// The settings the artifacts were produced with.
+    pub settings: Settings,
+    /// The artifacts mapped by their paths relative to the output directory.
+    pub artifacts: BTreeMap<String, Artifact>,
+}
+
+///
+/// The compiler that produced the artifacts.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Compiler {
+    /// The compiler name.
+    pub name: String,
+    /// The compiler version.
+    pub version: String,
+}
+
+///
+/// The settings the artifacts were produced with.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Settings {
+    /// Whether only the runtime code was written.
+    pub runtime_only: bool,
+    /// Whether the runtime code was wrapped into EOF containers.
+    pub eof: bool,
+    /// Whether the source directory structure was mirrored in the output directory.
+    pub mirror_source_tree: bool,
+    /// The compiler version overriding the one in the metadata, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_compiler_version: Option<String>,
+    /// The LLVM optimizer settings each contract was compiled with.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub optimizations: BTreeMap<String, Optimization>,
+}
+
+///
+/// The build manifest artifact entry.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Artifact {
+    /// The file size in bytes.
+    pub size: usize,
+    /// The hexadecimal SHA-256 hash of the file contents.
+    pub sha256: String,
+}
+
+impl Manifest {
+    /// The manifest file name.
+    pub const FILE_NAME: &'static str = "manifest.json";
+
+    ///
+    /// Creates a manifest of the artifact `files` written to `output_directory`.
+    ///
+    /// The files are read back from the file system, so the manifest attests what has been
+    /// actually written.
+    ///
+    pub fn new(
+        output_directory: &Path,
+        files: &[PathBuf],
+        settings: Settings,
+    ) -> anyhow::Result<Self> {
+        let mut contents = Vec::with_capacity(files.len());
+        for path in files.iter() {
+            let content = std::fs::read(path)
+                .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+            let relative_path = path
+                .strip_prefix(output_directory)
+                .unwrap_or(path.as_path());
+            contents.push((relative_path.to_slash_lossy().to_string(), content));
+        }
+        Ok(Self::from_contents(contents, settings))
+    }
+
+    ///
+    /// Creates a manifest of the artifact `contents` mapped by their relative paths.
+    ///
+    /// Used for the outputs that are not written to the file system, e.g. archives.
+    ///
+    pub fn from_contents<C>(
+        contents: impl IntoIterator<Item = (String, C)>,
+        settings: Settings,
+    ) -> Self
+    where
+        C: AsRef<[u8]>,
+    {
+        let artifacts = contents
+            .into_iter()
+            .map(|(path, content)| {
+                let content = content.as_ref();
+                (
+                    path,
+                    Artifact {
+                        size: content.len(),
+                        sha256: hex::encode(sha2::Sha256::digest(content)),
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            version: solx_standard_json::Output::VERSION,
+            compiler: Compiler {
+                name: crate::r#const::DEFAULT_EXECUTABLE_NAME.to_owned(),
+                version: crate::r#const::version(),
+            },
+            settings,
+            artifacts,
+        }
+    }
+
+    ///
+    /// Serializes the manifest into the pretty-printed JSON written to the output.
+    ///
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Always valid")
+    }
+
+    ///
+    /// Writes the manifest to `output_directory`.
+    ///
+    pub fn write_to_directory(
+        &self,
+        output_directory: &Path,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let output_path = output_directory.join(Self::FILE_NAME);
+        if output_path.exists() && !overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+            );
+        }
+        std::fs::write(output_path.as_path(), self.to_json())
+            .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+        Ok(())
+    }
+}
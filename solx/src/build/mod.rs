@@ -2,16 +2,37 @@
 //! The Solidity project build.
 //!
 
+pub mod archive;
 pub mod contract;
+pub mod diff;
+pub mod manifest;
+pub mod options;
+pub mod statistics;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::io::Write;
+use std::path::Component;
 use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
 
+use rayon::iter::IntoParallelIterator;
+use rayon::iter::ParallelIterator;
 use solx_standard_json::CollectableError;
 
+use self::archive::Format as ArchiveFormat;
+use self::contract::analysis::Analysis as BytecodeAnalysis;
+use self::contract::assembly::Instruction as BytecodeAssemblyInstruction;
 use self::contract::object::Object as ContractObject;
 use self::contract::Contract;
+use self::diff::ContractDiff;
+use self::diff::Diff;
+use self::diff::ObjectDiff;
+use self::manifest::Manifest;
+use self::manifest::Settings as ManifestSettings;
+use self::options::Options;
+use self::statistics::Statistics;
 
 ///
 /// The Solidity project build.
@@ -22,9 +43,31 @@ pub struct Build {
     pub results: BTreeMap<String, Result<Contract, solx_standard_json::OutputError>>,
     /// The additional message to output.
     pub messages: Vec<solx_standard_json::OutputError>,
+    /// The output options.
+    pub options: Options,
+    /// The `keccak256` hashes of the input sources. See [`Self::source_hashes`] for all source files.
+    pub input_source_hashes: BTreeMap<String, String>,
+    /// The contracts compiled with `-Oz`, whose linked sizes are compared for the size advisory.
+    pub size_results: BTreeMap<String, Contract>,
+    /// The warnings suppressed in this build, which are not written to the output.
+    pub suppressed: Vec<solx_standard_json::OutputError>,
 }
 
 impl Build {
+    /// The metadata keys that cannot be overridden with the extra metadata.
+    pub const RESERVED_METADATA_KEYS: [&'static str; 7] = [
+        "compiler",
+        "language",
+        "output",
+        "settings",
+        "sources",
+        "version",
+        env!("CARGO_PKG_NAME"),
+    ];
+
+    /// The size increase over `-Oz` in percent, above which the size advisory warning is emitted.
+    pub const SIZE_ADVISORY_THRESHOLD_PERCENT: usize = 10;
+
     ///
     /// A shortcut constructor.
     ///
@@ -35,22 +78,78 @@ impl Build {
         Self {
             results,
             messages: std::mem::take(messages),
+            options: Options::default(),
+            input_source_hashes: BTreeMap::new(),
+            size_results: BTreeMap::new(),
+            suppressed: Vec::new(),
         }
     }
 
+    ///
+    /// Sets the output options.
+    ///
+    pub fn set_options(&mut self, options: Options) {
+        self.options = options;
+    }
+
+    ///
+    /// Sets the `keccak256` hashes of the input sources, computed with `hash_sources`.
+    ///
+    pub fn set_source_hashes(&mut self, source_hashes: BTreeMap<String, String>) {
+        self.input_source_hashes = source_hashes;
+    }
+
     ///
     /// Links the EVM build.
     ///
+    /// A shortcut for [`Self::link_with_resolver`] resolving the symbols from `linker_symbols`.
+    ///
     pub fn link(
-        mut self,
+        self,
         linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
         cbor_data: Option<Vec<(String, semver::Version)>>,
     ) -> Self {
-        let mut contracts: BTreeMap<String, Contract> = self
-            .results
-            .into_iter()
-            .map(|(path, result)| (path, result.expect("Cannot link a project with errors")))
-            .collect();
+        self.link_with_resolver(|symbol| linker_symbols.get(symbol).copied(), cbor_data)
+    }
+
+    ///
+    /// Links the EVM build, resolving the library addresses with `resolver`.
+    ///
+    /// The resolver is called once for each library identifier required by the build, so the
+    /// addresses can be fetched lazily, e.g. from a registry or a deployment database.
+    /// The libraries the resolver returns `None` for are reported as unresolved symbols.
+    /// They are reported as linker errors if linked contracts are required by the options.
+    ///
+    pub fn link_with_resolver<R>(
+        mut self,
+        mut resolver: R,
+        cbor_data: Option<Vec<(String, semver::Version)>>,
+    ) -> Self
+    where
+        R: FnMut(&str) -> Option<[u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    {
+        let linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]> =
+            self.required_symbols()
+                .into_iter()
+                .filter_map(|symbol| {
+                    let address = resolver(symbol.as_str())?;
+                    Some((symbol, address))
+                })
+                .collect();
+        let size_results = std::mem::take(&mut self.size_results);
+
+        let mut contracts = BTreeMap::new();
+        let mut failed_results = BTreeMap::new();
+        for (path, result) in self.results.into_iter() {
+            match result {
+                Ok(contract) => {
+                    contracts.insert(path, contract);
+                }
+                Err(error) => {
+                    failed_results.insert(path, Err(error));
+                }
+            }
+        }
 
         loop {
             let assembled_objects_data = {
@@ -88,16 +187,7 @@ impl Build {
                 let mut assembled_objects_data = Vec::with_capacity(assembleable_objects.len());
                 for object in assembleable_objects.into_iter() {
                     let assembled_object =
-                        match object.assemble(all_objects.as_slice(), cbor_data.clone()) {
-                            Ok(assembled_object) => assembled_object,
-                            Err(error) => {
-                                self.messages
-                                    .push(solx_standard_json::OutputError::new_error(
-                                        None, &error, None, None,
-                                    ));
-                                return Self::new(BTreeMap::new(), &mut self.messages);
-                            }
-                        };
+                        object.assemble(all_objects.as_slice(), cbor_data.clone());
                     assembled_objects_data.push((
                         object.contract_name.full_path.to_owned(),
                         object.code_segment,
@@ -107,7 +197,29 @@ impl Build {
                 assembled_objects_data
             };
 
+            let mut failed_paths = Vec::new();
             for (full_path, code_segment, assembled_object) in assembled_objects_data.into_iter() {
+                let assembled_object = match assembled_object {
+                    Ok(assembled_object) => assembled_object,
+                    Err(error) => {
+                        failed_results.insert(
+                            full_path.clone(),
+                            Err(solx_standard_json::OutputError::new_error(
+                                None,
+                                &error,
+                                Some(solx_standard_json::OutputErrorSourceLocation::new(
+                                    full_path.clone(),
+                                )),
+                                None,
+                            )),
+                        );
+                        failed_paths.push(full_path);
+                        continue;
+                    }
+                };
+                if failed_paths.contains(&full_path) {
+                    continue;
+                }
                 let contract = contracts
                     .get_mut(full_path.as_str())
                     .expect("Always exists");
@@ -118,244 +230,1747 @@ impl Build {
                 if let Some(object) = object {
                     object.bytecode = assembled_object.as_slice().to_owned();
                     object.is_assembled = true;
+                    object.has_cbor_metadata = code_segment
+                        == era_compiler_common::CodeSegment::Runtime
+                        && cbor_data.is_some();
                 }
             }
+            Self::fail_dependents(&mut contracts, &mut failed_results, failed_paths);
         }
 
-        for contract in contracts.values_mut() {
+        let mut failed_paths = Vec::new();
+        for (path, contract) in contracts.iter_mut() {
             for object in [&mut contract.deploy_object, &mut contract.runtime_object].into_iter() {
                 let object = match object {
                     Some(object) => object,
                     None => continue,
                 };
                 if let Err(error) = object.link(&linker_symbols) {
-                    self.messages
-                        .push(solx_standard_json::OutputError::new_error(
-                            None, &error, None, None,
-                        ));
-                    return Self::new(BTreeMap::new(), &mut self.messages);
+                    failed_results.insert(
+                        path.to_owned(),
+                        Err(solx_standard_json::OutputError::new_error(
+                            None,
+                            &error,
+                            Some(solx_standard_json::OutputErrorSourceLocation::new(
+                                path.to_owned(),
+                            )),
+                            None,
+                        )
+                        .with_component(solx_standard_json::OutputError::COMPONENT_LINKER)),
+                    );
+                    failed_paths.push(path.to_owned());
+                    break;
                 }
             }
         }
+        for path in failed_paths.into_iter() {
+            contracts.remove(path.as_str());
+        }
 
-        Self::new(
+        let mut results = failed_results;
+        results.extend(
             contracts
                 .into_iter()
-                .map(|(path, contract)| (path, Ok(contract)))
-                .collect(),
-            &mut self.messages,
-        )
+                .map(|(path, contract)| (path, Ok(contract))),
+        );
+        let mut build = Self::new(results, &mut self.messages);
+        build.set_options(self.options);
+        build.set_source_hashes(self.input_source_hashes);
+        build.suppressed = self.suppressed;
+        if !size_results.is_empty() {
+            let size_build = Self::new(
+                size_results
+                    .into_iter()
+                    .map(|(path, contract)| (path, Ok(contract)))
+                    .collect(),
+                &mut vec![],
+            )
+            .link(linker_symbols, cbor_data);
+            let size_advisories = build
+                .results
+                .iter()
+                .filter_map(|(path, result)| {
+                    let contract = result.as_ref().ok()?;
+                    let size_contract = size_build.results.get(path)?.as_ref().ok()?;
+                    Self::size_advisory(path.as_str(), contract, size_contract)
+                })
+                .collect::<Vec<solx_standard_json::OutputError>>();
+            build.messages.extend(size_advisories);
+        }
+        for (path, symbols) in build.unresolved_symbols().into_iter() {
+            let symbols = symbols
+                .into_iter()
+                .map(|symbol| format!("`{symbol}`"))
+                .collect::<Vec<String>>()
+                .join(", ");
+            let message = format!("Contract `{path}` remains unlinked, as the following libraries are not provided: {symbols}.");
+            let location = Some(solx_standard_json::OutputErrorSourceLocation::new(path));
+            let error = if build.options.require_linked {
+                solx_standard_json::OutputError::new_error(None, message, location, None)
+            } else {
+                solx_standard_json::OutputError::new_warning(None, message, location, None)
+            };
+            build
+                .messages
+                .push(error.with_component(solx_standard_json::OutputError::COMPONENT_LINKER));
+        }
+        build
     }
 
     ///
-    /// Writes all contracts to the terminal.
+    /// Links the EVM build again with the updated library addresses.
+    ///
+    /// The objects are relinked from their ELF bytecode preserved during the first linking,
+    /// so addresses of libraries that have been already linked can be changed.
     ///
-    pub fn write_to_terminal(mut self, output_metadata: bool) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+    /// # Errors
+    /// If an object has no preserved ELF bytecode, e.g. it has never been linked.
+    ///
+    pub fn relink(
+        mut self,
+        updated_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> anyhow::Result<Self> {
+        for contract in self.results.values_mut().flatten() {
+            for object in [&mut contract.deploy_object, &mut contract.runtime_object]
+                .into_iter()
+                .flatten()
+            {
+                object.relink(&updated_symbols)?;
+            }
+        }
+        Ok(self)
+    }
 
-        for (path, build) in self.results.into_iter() {
-            build
-                .expect("Always valid")
-                .write_to_terminal(path, output_metadata)?;
+    ///
+    /// Returns the successfully compiled contract matching `query`, if any.
+    ///
+    /// The query is matched against the full contract path first, e.g. `path/to/Test.sol:Test`,
+    /// and then against the simple contract name, e.g. `Test`.
+    ///
+    /// # Errors
+    /// If the simple name matches contracts in multiple source files.
+    ///
+    pub fn contract(&self, query: &str) -> anyhow::Result<Option<&Contract>> {
+        if let Some(contract) = self
+            .results
+            .get(query)
+            .and_then(|result| result.as_ref().ok())
+        {
+            return Ok(Some(contract));
         }
 
-        Ok(())
+        let mut matches = self
+            .results
+            .values()
+            .filter_map(|result| result.as_ref().ok())
+            .filter(|contract| contract.name.name.as_deref() == Some(query))
+            .collect::<Vec<&Contract>>();
+        if matches.len() > 1 {
+            anyhow::bail!(
+                "Contract name `{query}` is ambiguous, as it is defined in multiple source files: {}. Use the full path instead.",
+                matches
+                    .into_iter()
+                    .map(|contract| format!("`{}`", contract.name.full_path))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+        }
+        Ok(matches.pop())
     }
 
     ///
-    /// Writes all contracts to the specified directory.
+    /// Returns the library references that are still unresolved in ELF objects.
     ///
-    pub fn write_to_directory(
-        mut self,
-        output_directory: &Path,
-        overwrite: bool,
-        output_metadata: bool,
-    ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+    /// The mapping is from the contract full path to the list of missing library identifiers.
+    ///
+    pub fn unresolved_symbols(&self) -> BTreeMap<String, Vec<String>> {
+        self.results
+            .iter()
+            .filter_map(|(path, result)| {
+                let contract = result.as_ref().ok()?;
+                let symbols = [
+                    contract.deploy_object.as_ref(),
+                    contract.runtime_object.as_ref(),
+                ]
+                .into_iter()
+                .flatten()
+                .filter(|object| object.format == era_compiler_common::ObjectFormat::ELF)
+                .flat_map(|object| object.unlinked_libraries.iter().cloned())
+                .collect::<BTreeSet<String>>();
+                if symbols.is_empty() {
+                    return None;
+                }
+                Some((path.to_owned(), symbols.into_iter().collect()))
+            })
+            .collect()
+    }
 
-        std::fs::create_dir_all(output_directory)?;
+    ///
+    /// Returns the library identifiers that must be provided to fully link the build.
+    ///
+    /// Unlike [`Self::unresolved_symbols`], the identifiers are merged across all ELF contracts,
+    /// so the result can be compared against the keys of the linker symbols map directly.
+    ///
+    pub fn required_symbols(&self) -> BTreeSet<String> {
+        self.unresolved_symbols().into_values().flatten().collect()
+    }
 
-        for build in self.results.into_values() {
-            build.expect("Always valid").write_to_directory(
-                output_directory,
-                overwrite,
-                output_metadata,
-            )?;
+    ///
+    /// Returns the direct dependencies of each successfully compiled contract.
+    ///
+    /// The dependencies are the libraries the contract is linked or yet to be linked with,
+    /// and the contracts it deploys, mapped by their full paths.
+    /// The libraries are taken from the unresolved symbols as well, so the graph can be built before linking.
+    ///
+    pub fn dependency_graph(&self) -> BTreeMap<String, BTreeSet<String>> {
+        let contracts = self
+            .results
+            .iter()
+            .filter_map(|(path, result)| Some((path, result.as_ref().ok()?)))
+            .collect::<Vec<(&String, &Contract)>>();
+        let object_paths = contracts
+            .iter()
+            .flat_map(|(path, contract)| {
+                [
+                    contract.deploy_object.as_ref(),
+                    contract.runtime_object.as_ref(),
+                ]
+                .into_iter()
+                .flatten()
+                .map(|object| (object.identifier.as_str(), path.as_str()))
+            })
+            .collect::<BTreeMap<&str, &str>>();
+
+        contracts
+            .iter()
+            .map(|(path, contract)| {
+                let dependencies = [
+                    contract.deploy_object.as_ref(),
+                    contract.runtime_object.as_ref(),
+                ]
+                .into_iter()
+                .flatten()
+                .flat_map(|object| {
+                    object
+                        .unlinked_libraries
+                        .iter()
+                        .chain(object.linker_symbols.keys())
+                        .map(String::as_str)
+                        .chain(object.dependencies.inner.iter().filter_map(|dependency| {
+                            object_paths.get(dependency.as_str()).copied()
+                        }))
+                })
+                .filter(|dependency| *dependency != path.as_str())
+                .map(|dependency| dependency.to_owned())
+                .collect::<BTreeSet<String>>();
+                (path.to_string(), dependencies)
+            })
+            .collect()
+    }
+
+    ///
+    /// Writes the dependency graph in the Graphviz DOT format.
+    ///
+    /// See [`Self::dependency_graph`] for the graph contents.
+    ///
+    pub fn write_dependency_graph_dot(&self, writer: &mut impl Write) -> anyhow::Result<()> {
+        writeln!(writer, "digraph dependencies {{")?;
+        for (path, dependencies) in self.dependency_graph().into_iter() {
+            writeln!(writer, "    {path:?};")?;
+            for dependency in dependencies.into_iter() {
+                writeln!(writer, "    {path:?} -> {dependency:?};")?;
+            }
         }
+        writeln!(writer, "}}")?;
+        Ok(())
+    }
 
-        writeln!(
-            std::io::stderr(),
-            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
-        )?;
+    ///
+    /// Returns the current object format of each successfully compiled contract.
+    ///
+    /// A contract is reported as `ELF` if either its deploy or runtime code is still unlinked.
+    /// Contracts without bytecode are skipped.
+    ///
+    pub fn object_formats(&self) -> BTreeMap<String, era_compiler_common::ObjectFormat> {
+        self.results
+            .iter()
+            .filter_map(|(path, result)| {
+                let contract = result.as_ref().ok()?;
+                let formats = [
+                    contract.deploy_object.as_ref(),
+                    contract.runtime_object.as_ref(),
+                ]
+                .into_iter()
+                .flatten()
+                .map(|object| object.format)
+                .collect::<Vec<era_compiler_common::ObjectFormat>>();
+                let format = formats
+                    .iter()
+                    .find(|format| **format == era_compiler_common::ObjectFormat::ELF)
+                    .or_else(|| formats.first())
+                    .copied()?;
+                Some((path.to_owned(), format))
+            })
+            .collect()
+    }
+
+    ///
+    /// Checks whether all successfully compiled contracts are fully linked.
+    ///
+    pub fn is_fully_linked(&self) -> bool {
+        self.object_formats()
+            .values()
+            .all(|format| *format != era_compiler_common::ObjectFormat::ELF)
+    }
+
+    ///
+    /// Checks that all successfully compiled contracts are fully linked.
+    ///
+    /// Prevents deploying objects with placeholders instead of the library addresses.
+    /// The CLI relies on the linker errors reported by [`Self::link_with_resolver`] instead.
+    ///
+    /// # Errors
+    /// If any contract is still in the `ELF` format, listing the unlinked contracts with their missing libraries.
+    ///
+    pub fn assert_linked(&self) -> anyhow::Result<()> {
+        let unresolved_symbols = self.unresolved_symbols();
+        let unlinked = self
+            .object_formats()
+            .into_iter()
+            .filter(|(_path, format)| *format == era_compiler_common::ObjectFormat::ELF)
+            .map(
+                |(path, _format)| match unresolved_symbols.get(path.as_str()) {
+                    Some(symbols) => format!(
+                        "`{path}`: {}",
+                        symbols
+                            .iter()
+                            .map(|symbol| format!("`{symbol}`"))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                    None => format!("`{path}`: unlinked dependencies"),
+                },
+            )
+            .collect::<Vec<String>>();
+        if !unlinked.is_empty() {
+            anyhow::bail!(
+                "The following contracts remain unlinked, as their libraries are not provided:\n{}",
+                unlinked.join("\n")
+            );
+        }
         Ok(())
     }
 
     ///
-    /// Writes all contracts assembly and bytecode to the standard JSON.
+    /// Splits the build into the successfully compiled contracts and the errors of the failed ones.
     ///
-    pub fn write_to_standard_json(
+    /// Allows writing the artifacts of the successful contracts while reporting the rest.
+    /// The additional messages are discarded.
+    ///
+    pub fn split(
         self,
-        standard_json: &mut solx_standard_json::Output,
-    ) -> anyhow::Result<()> {
-        let mut errors = Vec::with_capacity(self.results.len());
-        for result in self.results.into_values() {
-            let build = match result {
+    ) -> (
+        BTreeMap<String, Contract>,
+        BTreeMap<String, solx_standard_json::OutputError>,
+    ) {
+        let mut contracts = BTreeMap::new();
+        let mut errors = BTreeMap::new();
+        for (path, result) in self.results.into_iter() {
+            match result {
                 Ok(contract) => {
-                    errors.extend(
-                        contract
-                            .deploy_object
-                            .as_ref()
-                            .map(|object| {
-                                object
-                                    .warnings
-                                    .iter()
-                                    .map(|error| {
-                                        solx_standard_json::OutputError::new_warning(
-                                            error.code(),
-                                            error.to_string(),
-                                            Some(
-                                                solx_standard_json::OutputErrorSourceLocation::new(
-                                                    contract.name.full_path.clone(),
-                                                ),
-                                            ),
-                                            None,
-                                        )
-                                    })
-                                    .collect::<Vec<solx_standard_json::OutputError>>()
-                            })
-                            .unwrap_or_default(),
-                    );
-                    errors.extend(
-                        contract
-                            .runtime_object
-                            .as_ref()
-                            .map(|object| {
-                                object
-                                    .warnings
-                                    .iter()
-                                    .map(|error| {
-                                        solx_standard_json::OutputError::new_warning(
-                                            error.code(),
-                                            error.to_string(),
-                                            Some(
-                                                solx_standard_json::OutputErrorSourceLocation::new(
-                                                    contract.name.full_path.clone(),
-                                                ),
-                                            ),
-                                            None,
-                                        )
-                                    })
-                                    .collect::<Vec<solx_standard_json::OutputError>>()
-                            })
-                            .unwrap_or_default(),
-                    );
-                    contract
+                    contracts.insert(path, contract);
                 }
                 Err(error) => {
-                    errors.push(error);
-                    continue;
+                    errors.insert(path, error);
                 }
-            };
-            let name = build.name.clone();
+            }
+        }
+        (contracts, errors)
+    }
 
-            match standard_json
-                .contracts
-                .get_mut(name.path.as_str())
-                .and_then(|contracts| {
-                    contracts.get_mut(name.name.as_deref().unwrap_or(name.path.as_str()))
-                }) {
-                Some(contract) => {
-                    build.write_to_standard_json(contract)?;
-                }
+    ///
+    /// Returns the deploy and runtime bytecode sizes of each successfully compiled contract,
+    /// along with the totals and warnings about contracts exceeding the runtime code size limit.
+    ///
+    /// The optimizer settings are reported for the contracts whose bytecode was generated.
+    ///
+    pub fn statistics(&self) -> Statistics {
+        let contracts = self
+            .results
+            .iter()
+            .filter_map(|(path, result)| {
+                let contract = result.as_ref().ok()?;
+                let deploy_size = contract
+                    .deploy_object
+                    .as_ref()
+                    .map(|object| object.bytecode.len())
+                    .unwrap_or_default();
+                let runtime_size = contract
+                    .runtime_object
+                    .as_ref()
+                    .map(|object| object.bytecode.len())
+                    .unwrap_or_default();
+                Some((path.to_owned(), (deploy_size, runtime_size)))
+            })
+            .collect();
+        let mut statistics = Statistics::new(contracts);
+        statistics.optimizations = self
+            .results
+            .iter()
+            .filter_map(|(path, result)| {
+                let optimization = result.as_ref().ok()?.optimization.as_ref()?;
+                Some((path.to_owned(), optimization.to_owned()))
+            })
+            .collect();
+        statistics
+    }
+
+    ///
+    /// Compares the bytecode of successfully compiled contracts with the `other` build.
+    ///
+    /// Contracts are matched by their full paths.
+    /// If `ignore_metadata` is set, the trailing CBOR metadata is not compared.
+    ///
+    pub fn diff(&self, other: &Self, ignore_metadata: bool) -> Diff {
+        let this: BTreeMap<&String, &Contract> = self
+            .results
+            .iter()
+            .filter_map(|(path, result)| Some((path, result.as_ref().ok()?)))
+            .collect();
+        let other: BTreeMap<&String, &Contract> = other
+            .results
+            .iter()
+            .filter_map(|(path, result)| Some((path, result.as_ref().ok()?)))
+            .collect();
+
+        let mut diff = Diff::default();
+        for (path, contract) in this.iter() {
+            let other_contract = match other.get(path) {
+                Some(other_contract) => other_contract,
                 None => {
-                    let contracts = standard_json
-                        .contracts
-                        .entry(name.path.clone())
-                        .or_default();
-                    let mut contract = solx_standard_json::OutputContract::default();
-                    build.write_to_standard_json(&mut contract)?;
-                    contracts.insert(name.name.unwrap_or(name.path), contract);
+                    diff.removed.push((*path).to_owned());
+                    continue;
                 }
+            };
+
+            let object_diff = |this: Option<&ContractObject>, other: Option<&ContractObject>| {
+                ObjectDiff::new(
+                    this.map(|object| object.bytecode.as_slice())
+                        .unwrap_or_default(),
+                    other
+                        .map(|object| object.bytecode.as_slice())
+                        .unwrap_or_default(),
+                    ignore_metadata,
+                )
+            };
+            let contract_diff = ContractDiff {
+                deploy: object_diff(
+                    contract.deploy_object.as_ref(),
+                    other_contract.deploy_object.as_ref(),
+                ),
+                runtime: object_diff(
+                    contract.runtime_object.as_ref(),
+                    other_contract.runtime_object.as_ref(),
+                ),
+            };
+            if contract_diff.deploy.is_some() || contract_diff.runtime.is_some() {
+                diff.changed.insert((*path).to_owned(), contract_diff);
             }
         }
+        diff.added = other
+            .keys()
+            .filter(|path| !this.contains_key(*path))
+            .map(|path| (*path).to_owned())
+            .collect();
+        diff
+    }
 
-        standard_json.errors.extend(errors);
-        Ok(())
+    ///
+    /// Returns the deploy and runtime code generation durations of each successfully compiled contract.
+    ///
+    pub fn timings(&self) -> BTreeMap<String, (Duration, Duration)> {
+        self.results
+            .iter()
+            .filter_map(|(path, result)| {
+                let contract = result.as_ref().ok()?;
+                Some((path.to_owned(), contract.timings))
+            })
+            .collect()
     }
-}
 
-impl solx_standard_json::CollectableError for Build {
-    fn errors(&self) -> Vec<&solx_standard_json::OutputError> {
-        let mut errors: Vec<&solx_standard_json::OutputError> = self
+    ///
+    /// Computes the `keccak256` hashes of `sources`, skipping the ones without content.
+    ///
+    /// The hashes are computed over the exact content passed to `solc`, so they match the ones in
+    /// the `solc` metadata, e.g. `0x1145e8...`.
+    ///
+    pub fn hash_sources(
+        sources: &BTreeMap<String, solx_standard_json::InputSource>,
+    ) -> BTreeMap<String, String> {
+        sources
+            .iter()
+            .filter_map(|(path, source)| {
+                let hash =
+                    era_compiler_common::Keccak256Hash::from_slice(source.content()?.as_bytes());
+                Some((
+                    path.to_owned(),
+                    format!("0x{}", hex::encode(hash.as_bytes())),
+                ))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the `keccak256` hashes of all source files.
+    ///
+    /// Besides the input sources, the files loaded by `solc` via imports are included,
+    /// as their hashes are taken from the `solc` metadata of the contracts.
+    ///
+    pub fn source_hashes(&self) -> BTreeMap<String, String> {
+        let mut source_hashes = self.input_source_hashes.clone();
+        for contract in self
             .results
             .values()
-            .filter_map(|build| build.as_ref().err())
-            .collect();
-        errors.extend(
-            self.messages
-                .iter()
-                .filter(|message| message.severity == "error"),
-        );
-        errors
+            .filter_map(|result| result.as_ref().ok())
+        {
+            let Some(metadata) = contract
+                .metadata
+                .as_deref()
+                .and_then(|metadata| serde_json::from_str::<serde_json::Value>(metadata).ok())
+            else {
+                continue;
+            };
+            let Some(sources) = metadata
+                .get("sources")
+                .and_then(serde_json::Value::as_object)
+            else {
+                continue;
+            };
+            for (path, source) in sources.iter() {
+                if let Some(hash) = source.get("keccak256").and_then(serde_json::Value::as_str) {
+                    source_hashes
+                        .entry(path.to_owned())
+                        .or_insert_with(|| hash.to_owned());
+                }
+            }
+        }
+        source_hashes
     }
 
-    fn take_warnings(&mut self) -> Vec<solx_standard_json::OutputError> {
-        let mut warnings: Vec<solx_standard_json::OutputError> = self
-            .messages
-            .iter()
-            .filter(|message| message.severity == "warning")
-            .cloned()
-            .collect();
+    ///
+    /// Merges the `extra` JSON object into the metadata of each successfully compiled contract.
+    ///
+    /// The metadata hash is not affected, as it has been already computed during compilation.
+    ///
+    /// # Errors
+    /// If `extra` is not a JSON object, or any of its keys collides with a reserved or existing metadata key.
+    ///
+    pub fn inject_metadata(&mut self, extra: &serde_json::Value) -> anyhow::Result<()> {
+        let extra = extra
+            .as_object()
+            .ok_or_else(|| anyhow::anyhow!("Extra metadata must be a JSON object."))?;
+        if let Some(key) = extra
+            .keys()
+            .find(|key| Self::RESERVED_METADATA_KEYS.contains(&key.as_str()))
+        {
+            anyhow::bail!("Extra metadata key `{key}` is reserved.");
+        }
+
         for contract in self.results.values_mut().flatten() {
-            warnings.extend(
-                contract
-                    .deploy_object
-                    .as_ref()
-                    .map(|object| {
-                        object
-                            .warnings
-                            .iter()
-                            .map(|error| {
-                                solx_standard_json::OutputError::new_warning(
-                                    error.code(),
-                                    error.to_string(),
-                                    Some(solx_standard_json::OutputErrorSourceLocation::new(
-                                        contract.name.full_path.clone(),
-                                    )),
-                                    None,
-                                )
-                            })
-                            .collect::<Vec<solx_standard_json::OutputError>>()
-                    })
-                    .unwrap_or_default(),
-            );
-            warnings.extend(
-                contract
-                    .runtime_object
-                    .as_ref()
-                    .map(|object| {
-                        object
-                            .warnings
-                            .iter()
-                            .map(|error| {
-                                solx_standard_json::OutputError::new_warning(
-                                    error.code(),
-                                    error.to_string(),
-                                    Some(solx_standard_json::OutputErrorSourceLocation::new(
-                                        contract.name.full_path.clone(),
-                                    )),
-                                    None,
-                                )
-                            })
-                            .collect::<Vec<solx_standard_json::OutputError>>()
-                    })
-                    .unwrap_or_default(),
-            );
+            let metadata = match contract.metadata.as_mut() {
+                Some(metadata) if !metadata.is_empty() => metadata,
+                _ => continue,
+            };
+
+            let mut object: serde_json::Value = serde_json::from_str(metadata.as_str())?;
+            let object_map = object.as_object_mut().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Contract `{}` metadata is not a JSON object.",
+                    contract.name.full_path
+                )
+            })?;
+            for (key, value) in extra.iter() {
+                if object_map.contains_key(key) {
+                    anyhow::bail!(
+                        "Extra metadata key `{key}` collides with the metadata of contract `{}`.",
+                        contract.name.full_path
+                    );
+                }
+                object_map.insert(key.to_owned(), value.to_owned());
+            }
+            *metadata = serde_json::to_string(&object).expect("Always valid");
         }
+        Ok(())
+    }
+
+    ///
+    /// Moves the results and messages of `other` into `self`.
+    ///
+    /// If a contract full path exists in both builds, the contract of `self` is kept,
+    /// and the collision is reported as an error message.
+    ///
+    /// # Errors
+    /// If a colliding contract has different object formats in the two builds.
+    /// In this case, `self` is left unchanged.
+    ///
+    pub fn merge(&mut self, other: Build) -> anyhow::Result<()> {
+        for (path, result) in other.results.iter() {
+            let (existing, contract) = match (self.results.get(path), result) {
+                (Some(Ok(existing)), Ok(contract)) => (existing, contract),
+                _ => continue,
+            };
+            for (existing, object) in [
+                (
+                    existing.deploy_object.as_ref(),
+                    contract.deploy_object.as_ref(),
+                ),
+                (
+                    existing.runtime_object.as_ref(),
+                    contract.runtime_object.as_ref(),
+                ),
+            ] {
+                if let (Some(existing), Some(object)) = (existing, object) {
+                    if existing.format != object.format {
+                        anyhow::bail!(
+                            "Contract `{path}` is present in both builds with different object formats."
+                        );
+                    }
+                }
+            }
+        }
+
+        for (path, result) in other.results.into_iter() {
+            if self.results.contains_key(path.as_str()) {
+                self.messages
+                    .push(solx_standard_json::OutputError::new_error(
+                        None,
+                        format!(
+                            "Contract `{path}` is present in both builds. The first one is kept."
+                        ),
+                        Some(solx_standard_json::OutputErrorSourceLocation::new(
+                            path.clone(),
+                        )),
+                        None,
+                    ));
+                continue;
+            }
+            self.results.insert(path, result);
+        }
+        self.messages.extend(other.messages);
+        self.suppressed.extend(other.suppressed);
+        for (path, hash) in other.input_source_hashes.into_iter() {
+            self.input_source_hashes.entry(path).or_insert(hash);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts to the terminal.
+    ///
+    /// If `selection` is provided, only contracts whose full path matches at least one pattern are written.
+    ///
+    pub fn write_to_terminal(
+        mut self,
+        selection: Option<&[glob::Pattern]>,
+        output_metadata: bool,
+        output_abi: bool,
+    ) -> anyhow::Result<()> {
+        self.check_size_limit();
+        self.check_evm_version();
+        self.check_duplicate_names();
+        self.override_compiler_version()?;
+        self.take_and_write_warnings(self.options.color);
+        let failures = self.take_failures_or_exit();
+        self.strip_deploy_objects();
+        self.wrap_eof()?;
+
+        if !self.select(selection) {
+            writeln!(std::io::stderr(), "No contracts matched selection.")?;
+            Self::write_failures(failures.as_slice(), self.options.color)?;
+            return Ok(());
+        }
+
+        for (path, build) in self.results.into_iter() {
+            build.expect("Always valid").write_to_terminal(
+                path,
+                output_metadata,
+                output_abi,
+                self.options.output_bytecode,
+                self.options.output_runtime_bytecode,
+                self.options.hex_format,
+            )?;
+        }
+
+        Self::write_failures(failures.as_slice(), self.options.color)?;
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts to the specified directory.
+    ///
+    /// If `selection` is provided, only contracts whose full path matches at least one pattern are written.
+    ///
+    /// Contracts are written concurrently, as each of them is written to its own files.
+    /// The errors are reported together in the lexicographic order of the contract full paths.
+    ///
+    pub fn write_to_directory(
+        mut self,
+        selection: Option<&[glob::Pattern]>,
+        output_directory: &Path,
+        overwrite: bool,
+        output_metadata: bool,
+        output_abi: bool,
+    ) -> anyhow::Result<()> {
+        self.check_size_limit();
+        self.check_evm_version();
+        self.check_duplicate_names();
+        self.override_compiler_version()?;
+        self.take_and_write_warnings(self.options.color);
+        let failures = self.take_failures_or_exit();
+        self.strip_deploy_objects();
+        self.wrap_eof()?;
+
+        let planned_files =
+            match self.planned_files(selection, output_directory, output_metadata, output_abi)? {
+                Some(planned_files) => planned_files,
+                None => {
+                    writeln!(std::io::stderr(), "No contracts matched selection.")?;
+                    Self::write_failures(failures.as_slice(), self.options.color)?;
+                    return Ok(());
+                }
+            };
+
+        std::fs::create_dir_all(output_directory)?;
+
+        let manifest_settings = self.manifest_settings();
+        let only_changed = self.options.only_changed;
+        let results = planned_files
+            .into_par_iter()
+            .map(|(path, files)| {
+                let result = Contract::write_files(files, overwrite, only_changed);
+                (path, result)
+            })
+            .collect::<BTreeMap<String, anyhow::Result<Vec<(PathBuf, bool)>>>>();
+        let mut files = Vec::new();
+        let mut skipped_count = 0;
+        let mut errors = BTreeMap::new();
+        for (path, result) in results.into_iter() {
+            match result {
+                Ok(written) => {
+                    for (file, is_written) in written.into_iter() {
+                        if !is_written {
+                            skipped_count += 1;
+                        }
+                        files.push(file);
+                    }
+                }
+                Err(error) => {
+                    errors.insert(path, error);
+                }
+            }
+        }
+        if !errors.is_empty() {
+            anyhow::bail!(
+                "{}",
+                errors
+                    .into_values()
+                    .map(|error| error.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+        }
+
+        if let Some(manifest_settings) = manifest_settings {
+            Manifest::new(output_directory, files.as_slice(), manifest_settings)?
+                .write_to_directory(output_directory, overwrite)?;
+        }
+
+        if only_changed {
+            writeln!(
+                std::io::stderr(),
+                "{} artifact(s) written, {skipped_count} unchanged artifact(s) skipped.",
+                files.len() - skipped_count,
+            )?;
+        }
+
+        if !failures.is_empty() {
+            Self::write_failures(failures.as_slice(), self.options.color)?;
+            writeln!(
+                std::io::stderr(),
+                "Compiler run finished with {} error(s). Artifact(s) of the successfully compiled contracts can be found in directory {output_directory:?}.",
+                failures.len(),
+            )?;
+            return Ok(());
+        }
+
+        writeln!(
+            std::io::stderr(),
+            "{}",
+            self.options.color.success(
+                format!(
+                    "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+                )
+                .as_str(),
+                &std::io::stderr()
+            )
+        )?;
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts to an archive of the specified `format`.
+    ///
+    /// If `selection` is provided, only contracts whose full path matches at least one pattern are written.
+    ///
+    /// The archive layout, including the manifest, matches the one produced by `write_to_directory`,
+    /// and the entries are sorted by their paths, so the archive is reproducible.
+    ///
+    pub fn write_to_archive(
+        mut self,
+        selection: Option<&[glob::Pattern]>,
+        mut writer: impl Write,
+        format: ArchiveFormat,
+        output_metadata: bool,
+        output_abi: bool,
+    ) -> anyhow::Result<()> {
+        self.check_size_limit();
+        self.check_evm_version();
+        self.check_duplicate_names();
+        self.override_compiler_version()?;
+        self.take_and_write_warnings(self.options.color);
+        let failures = self.take_failures_or_exit();
+        self.strip_deploy_objects();
+        self.wrap_eof()?;
+
+        let planned_files =
+            match self.planned_files(selection, Path::new(""), output_metadata, output_abi)? {
+                Some(planned_files) => planned_files,
+                None => {
+                    writeln!(std::io::stderr(), "No contracts matched selection.")?;
+                    Self::write_failures(failures.as_slice(), self.options.color)?;
+                    return Ok(());
+                }
+            };
+
+        let mut entries = planned_files
+            .into_values()
+            .flatten()
+            .map(|(path, content)| {
+                let path = path
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                (path, content.into_bytes())
+            })
+            .collect::<Vec<(String, Vec<u8>)>>();
+        if let Some(manifest_settings) = self.manifest_settings() {
+            let manifest = Manifest::from_contents(entries.clone(), manifest_settings);
+            entries.push((
+                Manifest::FILE_NAME.to_owned(),
+                manifest.to_json().into_bytes(),
+            ));
+        }
+        entries.sort_by(|(path_1, _), (path_2, _)| path_1.cmp(path_2));
+
+        format.write(&mut writer, entries.as_slice())?;
+        writer.flush()?;
+        Self::write_failures(failures.as_slice(), self.options.color)?;
+        Ok(())
+    }
+
+    ///
+    /// Returns the files that would be written by `write_to_directory`, without touching the file system.
+    ///
+    /// Each file path is paired with the flag showing whether the file already exists,
+    /// which allows detecting collisions before deciding whether to overwrite.
+    ///
+    pub fn plan_directory_writes(
+        &self,
+        output_directory: &Path,
+        output_metadata: bool,
+        output_abi: bool,
+    ) -> Vec<(PathBuf, bool)> {
+        self.directory_files(output_directory, output_metadata, output_abi)
+            .into_values()
+            .flatten()
+            .map(|(output_path, _content)| {
+                let exists = output_path.exists();
+                (output_path, exists)
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the files of the selected contracts to be written to `output_directory`.
+    ///
+    /// Shared by the directory and archive writers, so they select the same contracts and lay out
+    /// their files identically. Retains only the contracts matching `selection`, and returns `None`
+    /// if a selection is provided, but none of the contracts match it.
+    ///
+    /// # Errors
+    /// If an output file is written by more than one contract.
+    ///
+    fn planned_files(
+        &mut self,
+        selection: Option<&[glob::Pattern]>,
+        output_directory: &Path,
+        output_metadata: bool,
+        output_abi: bool,
+    ) -> anyhow::Result<Option<BTreeMap<String, Vec<(PathBuf, String)>>>> {
+        if !self.select(selection) {
+            return Ok(None);
+        }
+
+        let files = self.directory_files(output_directory, output_metadata, output_abi);
+        Self::check_directory_collisions(&files)?;
+        Ok(Some(files))
+    }
+
+    ///
+    /// Returns the files of the successfully compiled contracts inside `output_directory`,
+    /// mapped by the contract full paths.
+    ///
+    fn directory_files(
+        &self,
+        output_directory: &Path,
+        output_metadata: bool,
+        output_abi: bool,
+    ) -> BTreeMap<String, Vec<(PathBuf, String)>> {
+        self.results
+            .iter()
+            .filter_map(|(path, result)| Some((path, result.as_ref().ok()?)))
+            .map(|(path, contract)| {
+                let output_path = Self::contract_output_path(
+                    output_directory,
+                    contract,
+                    self.options.mirror_source_tree,
+                );
+                let files = contract.directory_files(
+                    output_path.as_path(),
+                    output_metadata,
+                    output_abi,
+                    self.options.output_bytecode,
+                    self.options.output_runtime_bytecode,
+                    self.options.output_contract_json,
+                    &self.options.filename_template,
+                );
+                (path.to_owned(), files)
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the manifest settings if the manifest is requested by the options.
+    ///
+    fn manifest_settings(&self) -> Option<ManifestSettings> {
+        self.options.manifest.then(|| ManifestSettings {
+            runtime_only: self.options.runtime_only,
+            eof: self.options.eof,
+            mirror_source_tree: self.options.mirror_source_tree,
+            metadata_compiler_version: self.options.compiler_version.clone(),
+            optimizations: self.statistics().optimizations,
+        })
+    }
+
+    ///
+    /// Returns the output path of `contract` inside `output_directory`.
+    ///
+    /// If `mirror_source_tree` is enabled, the directories of the contract source path are appended.
+    /// Root, current, and parent directory components are skipped, so the artifacts never escape
+    /// the output directory.
+    ///
+    fn contract_output_path(
+        output_directory: &Path,
+        contract: &Contract,
+        mirror_source_tree: bool,
+    ) -> PathBuf {
+        let mut output_path = output_directory.to_owned();
+        if mirror_source_tree {
+            if let Some(parent) = Path::new(contract.name.path.as_str()).parent() {
+                output_path.extend(
+                    parent
+                        .components()
+                        .filter(|component| matches!(component, Component::Normal(_))),
+                );
+            }
+        }
+        output_path
+    }
+
+    ///
+    /// Checks that no output file is written by more than one contract.
+    ///
+    fn check_directory_collisions(
+        files: &BTreeMap<String, Vec<(PathBuf, String)>>,
+    ) -> anyhow::Result<()> {
+        let mut writers: BTreeMap<&Path, Vec<&str>> = BTreeMap::new();
+        for (path, files) in files.iter() {
+            for (file_path, _content) in files.iter() {
+                writers
+                    .entry(file_path.as_path())
+                    .or_default()
+                    .push(path.as_str());
+            }
+        }
+
+        let collisions = writers
+            .into_iter()
+            .filter(|(_file_path, contracts)| contracts.len() > 1)
+            .map(|(file_path, contracts)| {
+                format!(
+                    "Output file {file_path:?} is written by multiple contracts: {}. Use --mirror-source-tree to write them into separate directories.",
+                    contracts.join(", ")
+                )
+            })
+            .collect::<Vec<String>>();
+        if !collisions.is_empty() {
+            anyhow::bail!("{}", collisions.join("\n"));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts to the JSON Lines stream.
+    ///
+    /// Each line is an independent JSON object with the `solxOutputVersion` field,
+    /// discriminated by the `kind` field:
+    /// - `deploy` and `runtime` for the bytecode objects of successfully compiled contracts
+    /// - `diagnostic` for errors and messages, with the additional `severity` field
+    ///
+    pub fn write_to_jsonl(&self, writer: &mut impl Write) -> anyhow::Result<()> {
+        for (path, result) in self.results.iter() {
+            match result {
+                Ok(contract) => {
+                    for (kind, object) in [
+                        (
+                            "deploy",
+                            contract
+                                .deploy_object
+                                .as_ref()
+                                .filter(|_| !self.options.runtime_only),
+                        ),
+                        ("runtime", contract.runtime_object.as_ref()),
+                    ] {
+                        let object = match object {
+                            Some(object) => object,
+                            None => continue,
+                        };
+                        let record = serde_json::json!({
+                            "solxOutputVersion": solx_standard_json::Output::VERSION,
+                            "kind": kind,
+                            "fullPath": contract.name.full_path,
+                            "path": contract.name.path,
+                            "name": contract.name.name,
+                            "format": object.output_format(),
+                            "bytecode": hex::encode(object.bytecode.as_slice()),
+                        });
+                        serde_json::to_writer(&mut *writer, &record)?;
+                        writeln!(writer)?;
+                    }
+                }
+                Err(error) => {
+                    Self::write_jsonl_diagnostic(writer, Some(path.as_str()), error)?;
+                }
+            }
+        }
+        for message in self.messages.iter() {
+            Self::write_jsonl_diagnostic(writer, None, message)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts assembly and bytecode to the standard JSON.
+    ///
+    /// Contracts are written in the lexicographic order of their full paths, so the output
+    /// is reproducible across runs. The build and LLVM warnings with suppressed codes are
+    /// recorded as suppressed in the output instead of being written.
+    ///
+    pub fn write_to_standard_json(
+        mut self,
+        standard_json: &mut solx_standard_json::Output,
+    ) -> anyhow::Result<()> {
+        self.check_size_limit();
+        self.check_evm_version();
+        self.check_duplicate_names();
+        self.override_compiler_version()?;
+
+        for (path, hash) in self.source_hashes().into_iter() {
+            if let Some(source) = standard_json.sources.get_mut(path.as_str()) {
+                source.keccak256 = Some(hash);
+            }
+        }
+
+        let mut errors = self.object_warnings();
+        for result in self.results.into_values() {
+            let build = match result {
+                Ok(mut contract) => {
+                    if let Some(location) = standard_json
+                        .sources
+                        .get(contract.name.path.as_str())
+                        .zip(contract.name.name.as_deref())
+                        .and_then(|(source, name)| source.contract_location(name))
+                    {
+                        for object in [&mut contract.deploy_object, &mut contract.runtime_object]
+                            .into_iter()
+                            .flatten()
+                        {
+                            object.set_contract_source_map(location.as_str());
+                        }
+                    }
+                    if self.options.runtime_only {
+                        contract.deploy_object = None;
+                    }
+                    contract
+                }
+                Err(error) => {
+                    errors.push(error);
+                    continue;
+                }
+            };
+            let name = build.name.clone();
+
+            match standard_json
+                .contracts
+                .get_mut(name.path.as_str())
+                .and_then(|contracts| {
+                    contracts.get_mut(name.name.as_deref().unwrap_or(name.path.as_str()))
+                }) {
+                Some(contract) => {
+                    build
+                        .write_to_standard_json(contract, self.options.output_selection.as_ref())?;
+                }
+                None => {
+                    let contracts = standard_json
+                        .contracts
+                        .entry(name.path.clone())
+                        .or_default();
+                    let mut contract = solx_standard_json::OutputContract::default();
+                    build.write_to_standard_json(
+                        &mut contract,
+                        self.options.output_selection.as_ref(),
+                    )?;
+                    contracts.insert(name.name.unwrap_or(name.path), contract);
+                }
+            }
+        }
+
+        errors.extend(self.messages);
+        let (errors, suppressed) = self.options.suppression.filter(errors);
+        standard_json.errors.extend(errors);
+        standard_json.extend_suppressed(self.suppressed.into_iter().chain(suppressed));
+        Ok(())
+    }
+
+    ///
+    /// Converts the build into a fresh standard JSON output.
+    ///
+    /// The sources are numbered in the lexicographic order of the contract paths.
+    /// The messages are moved to the output errors.
+    ///
+    pub fn into_standard_json_output(self) -> anyhow::Result<solx_standard_json::Output> {
+        let paths: BTreeSet<String> = self
+            .results
+            .values()
+            .filter_map(|result| result.as_ref().ok())
+            .map(|contract| contract.name.path.clone())
+            .collect();
+
+        let mut standard_json = solx_standard_json::Output::new_with_messages(vec![]);
+        standard_json.sources = paths
+            .into_iter()
+            .enumerate()
+            .map(|(index, path)| (path, solx_standard_json::OutputSource::new(index)))
+            .collect();
+        self.write_to_standard_json(&mut standard_json)?;
+        Ok(standard_json)
+    }
+
+    ///
+    /// Writes all errors and warnings to `writer` as a single JSON array.
+    ///
+    /// Both the per-contract diagnostics and the top-level messages are included.
+    /// Errors go first, then warnings, each sorted by the source path and location.
+    ///
+    pub fn write_diagnostics_json(&self, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut diagnostics: Vec<solx_standard_json::OutputError> = self
+            .results
+            .values()
+            .filter_map(|result| result.as_ref().err())
+            .chain(self.messages.iter())
+            .cloned()
+            .collect();
+        diagnostics.extend(self.object_warnings());
+        diagnostics.sort_by_key(|diagnostic| {
+            (
+                diagnostic.severity != "error",
+                diagnostic.severity != "warning",
+                diagnostic
+                    .source_location
+                    .as_ref()
+                    .map(|location| (location.file.to_owned(), location.start)),
+            )
+        });
+
+        let diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                serde_json::json!({
+                    "severity": diagnostic.severity,
+                    "formattedMessage": diagnostic.formatted_message,
+                    "sourceLocation": diagnostic.source_location,
+                })
+            })
+            .collect::<Vec<serde_json::Value>>();
+        serde_json::to_writer(&mut *writer, &diagnostics)?;
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    ///
+    /// Returns the LLVM warnings of the deploy and runtime objects of all successfully compiled contracts.
+    ///
+    fn object_warnings(&self) -> Vec<solx_standard_json::OutputError> {
+        let mut warnings = Vec::new();
+        for contract in self.results.values().flatten() {
+            for object in [
+                contract.deploy_object.as_ref(),
+                contract.runtime_object.as_ref(),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                warnings.extend(object.warnings.iter().map(|error| {
+                    solx_standard_json::OutputError::new_warning(
+                        error.code(),
+                        error.to_string(),
+                        Some(solx_standard_json::OutputErrorSourceLocation::new(
+                            contract.name.full_path.clone(),
+                        )),
+                        None,
+                    )
+                }));
+            }
+        }
+        warnings
+    }
+
+    ///
+    /// Retains only the contracts whose full path matches at least one of the `selection` patterns.
+    ///
+    /// Returns `false` if a selection is provided, but none of the contracts match it.
+    ///
+    fn select(&mut self, selection: Option<&[glob::Pattern]>) -> bool {
+        let selection = match selection {
+            Some(selection) => selection,
+            None => return true,
+        };
+
+        self.results.retain(|path, _| {
+            selection
+                .iter()
+                .any(|pattern| pattern.matches(path.as_str()))
+        });
+        !self.results.is_empty()
+    }
+
+    ///
+    /// Removes the failed contracts and error messages from the build, and returns them.
+    ///
+    fn take_failures(&mut self) -> Vec<solx_standard_json::OutputError> {
+        let mut failures = Vec::new();
+        self.results.retain(|_, result| match result {
+            Ok(_) => true,
+            Err(error) => {
+                failures.push(error.to_owned());
+                false
+            }
+        });
+        let (errors, messages): (Vec<_>, Vec<_>) = std::mem::take(&mut self.messages)
+            .into_iter()
+            .partition(|message| message.severity == "error");
+        self.messages = messages;
+        failures.extend(errors);
+        failures
+    }
+
+    ///
+    /// Returns the failures to report after writing the successful contracts if the build keeps going.
+    ///
+    /// Otherwise, exits the application if there is at least one error.
+    ///
+    fn take_failures_or_exit(&mut self) -> Vec<solx_standard_json::OutputError> {
+        if self.options.keep_going {
+            return self.take_failures();
+        }
+        self.exit_on_error(self.options.color);
+        vec![]
+    }
+
+    ///
+    /// Writes the failures skipped by the output writers to stderr.
+    ///
+    fn write_failures(
+        failures: &[solx_standard_json::OutputError],
+        color: solx_standard_json::OutputErrorColor,
+    ) -> anyhow::Result<()> {
+        for failure in failures.iter() {
+            writeln!(std::io::stderr(), "{}", failure.to_terminal_string(color))?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Moves the contracts at `failed_paths` and the contracts depending on them to `failed_results`.
+    ///
+    /// The dependents cannot be assembled without the failed objects, so they are reported
+    /// with an error naming the failed dependency.
+    ///
+    fn fail_dependents(
+        contracts: &mut BTreeMap<String, Contract>,
+        failed_results: &mut BTreeMap<String, Result<Contract, solx_standard_json::OutputError>>,
+        mut failed_paths: Vec<String>,
+    ) {
+        while let Some(failed_path) = failed_paths.pop() {
+            let failed_contract = match contracts.remove(failed_path.as_str()) {
+                Some(contract) => contract,
+                None => continue,
+            };
+            let failed_identifiers = [
+                failed_contract.deploy_object,
+                failed_contract.runtime_object,
+            ]
+            .into_iter()
+            .flatten()
+            .map(|object| object.identifier)
+            .collect::<BTreeSet<String>>();
+
+            for (path, contract) in contracts.iter() {
+                if failed_paths.contains(path) {
+                    continue;
+                }
+                let is_dependent = [
+                    contract.deploy_object.as_ref(),
+                    contract.runtime_object.as_ref(),
+                ]
+                .into_iter()
+                .flatten()
+                .any(|object| {
+                    object
+                        .dependencies
+                        .inner
+                        .iter()
+                        .any(|dependency| failed_identifiers.contains(dependency.as_str()))
+                });
+                if !is_dependent {
+                    continue;
+                }
+
+                failed_results.insert(
+                    path.to_owned(),
+                    Err(solx_standard_json::OutputError::new_error(
+                        None,
+                        format!(
+                            "Contract `{path}` cannot be assembled, as its dependency `{failed_path}` has failed."
+                        ),
+                        Some(solx_standard_json::OutputErrorSourceLocation::new(
+                            path.to_owned(),
+                        )),
+                        None,
+                    )),
+                );
+                failed_paths.push(path.to_owned());
+            }
+        }
+    }
+
+    ///
+    /// Returns the warning if `contract` is notably larger than `size_contract` compiled with `-Oz`.
+    ///
+    /// The code segments are compared separately after linking, as they are subject to different
+    /// size limits. The objects that remain unlinked are skipped.
+    ///
+    fn size_advisory(
+        path: &str,
+        contract: &Contract,
+        size_contract: &Contract,
+    ) -> Option<solx_standard_json::OutputError> {
+        let deltas = [
+            (
+                era_compiler_common::CodeSegment::Deploy,
+                contract.deploy_object.as_ref(),
+                size_contract.deploy_object.as_ref(),
+            ),
+            (
+                era_compiler_common::CodeSegment::Runtime,
+                contract.runtime_object.as_ref(),
+                size_contract.runtime_object.as_ref(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(code_segment, object, size_object)| {
+            let object = object?;
+            let size_object = size_object?;
+            if object.format == era_compiler_common::ObjectFormat::ELF
+                || size_object.format == era_compiler_common::ObjectFormat::ELF
+            {
+                return None;
+            }
+            let size = object.bytecode.len();
+            let size_optimized = size_object.bytecode.len();
+            (size * 100 > size_optimized * (100 + Self::SIZE_ADVISORY_THRESHOLD_PERCENT)).then(
+                || {
+                    format!(
+                        "the {code_segment} code is {size} bytes, which is {} bytes more than {size_optimized} bytes with `-Oz`",
+                        size - size_optimized
+                    )
+                },
+            )
+        })
+        .collect::<Vec<String>>();
+        if deltas.is_empty() {
+            return None;
+        }
+
+        Some(solx_standard_json::OutputError::new_warning(
+            None,
+            format!(
+                "Contract `{path}` is notably larger than it would be if optimized for size: {}. Consider optimizing it for size if it approaches the contract size limit.",
+                deltas.join("; ")
+            ),
+            None,
+            None,
+        ))
+    }
+
+    ///
+    /// Reports the contracts whose runtime code exceeds the EIP-170 size limit.
+    ///
+    /// The reports are warnings, unless the strict size limit is enabled.
+    ///
+    fn check_size_limit(&mut self) {
+        let mut warnings = self.statistics().warnings;
+        if self.options.strict_size_limit {
+            for warning in warnings.iter_mut() {
+                warning.promote_to_error();
+            }
+        }
+        self.messages.extend(warnings);
+    }
+
+    ///
+    /// Reports the contracts using the instructions unavailable in the target EVM version.
+    ///
+    /// The EVM version is passed to the code generator, so this check is only a safety net
+    /// against the instructions unavailable in the target EVM version slipping through.
+    /// The bytecode of the dependencies embedded as data and the trailing CBOR metadata are not checked.
+    ///
+    fn check_evm_version(&mut self) {
+        let evm_version = match self.options.evm_version {
+            Some(evm_version) => evm_version.to_string(),
+            None => return,
+        };
+        if !BytecodeAnalysis::EVM_VERSIONS.contains(&evm_version.as_str()) {
+            self.messages
+                .push(solx_standard_json::OutputError::new_error(
+                    None,
+                    format!("EVM version `{evm_version}` is not supported."),
+                    None,
+                    None,
+                ));
+            return;
+        }
+
+        let objects = self
+            .results
+            .values()
+            .filter_map(|result| result.as_ref().ok())
+            .flat_map(|contract| {
+                [
+                    contract.deploy_object.as_ref(),
+                    contract.runtime_object.as_ref(),
+                ]
+            })
+            .flatten()
+            .filter(|object| object.format != era_compiler_common::ObjectFormat::ELF)
+            .map(|object| (object.identifier.as_str(), object))
+            .collect::<BTreeMap<&str, &ContractObject>>();
+
+        let mut errors = Vec::new();
+        for (path, result) in self.results.iter() {
+            let contract = match result {
+                Ok(contract) => contract,
+                Err(_) => continue,
+            };
+
+            let mut unsupported_opcodes = BTreeSet::new();
+            for object in [
+                contract.deploy_object.as_ref(),
+                contract.runtime_object.as_ref(),
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|object| object.format != era_compiler_common::ObjectFormat::ELF)
+            {
+                let bytecode = object.bytecode.as_slice();
+                let code_size = object
+                    .dependencies
+                    .inner
+                    .iter()
+                    .filter_map(|dependency| objects.get(dependency.as_str()))
+                    .filter(|dependency| !dependency.bytecode.is_empty())
+                    .filter_map(|dependency| {
+                        bytecode
+                            .windows(dependency.bytecode.len())
+                            .position(|window| window == dependency.bytecode.as_slice())
+                    })
+                    .min()
+                    .unwrap_or(bytecode.len());
+                let code = ObjectDiff::strip_metadata(&bytecode[..code_size]);
+                unsupported_opcodes.extend(
+                    BytecodeAnalysis::new(code)
+                        .unsupported_opcodes(evm_version.as_str())
+                        .expect("Always valid"),
+                );
+            }
+            if unsupported_opcodes.is_empty() {
+                continue;
+            }
+
+            errors.push(solx_standard_json::OutputError::new_error(
+                None,
+                format!(
+                    "Contract `{path}` uses instructions unavailable in EVM version `{evm_version}`: {}. The bytecode cannot be deployed to chains running this EVM version.",
+                    unsupported_opcodes
+                        .into_iter()
+                        .map(|opcode| format!(
+                            "`{}`",
+                            BytecodeAssemblyInstruction {
+                                offset: 0,
+                                opcode,
+                                operand: None,
+                            }
+                            .mnemonic()
+                            .expect("Always exists")
+                        ))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                Some(solx_standard_json::OutputErrorSourceLocation::new(
+                    contract.name.path.to_owned(),
+                )),
+                None,
+            ));
+        }
+        self.messages.extend(errors);
+    }
+
+    ///
+    /// Reports the contract names defined in more than one source file.
+    ///
+    /// Such contracts can only be told apart by their full paths, so tools keying contracts by
+    /// their simple names may pick the wrong one.
+    ///
+    fn check_duplicate_names(&mut self) {
+        let mut full_paths: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for contract in self
+            .results
+            .values()
+            .filter_map(|result| result.as_ref().ok())
+        {
+            if let Some(name) = contract.name.name.as_deref() {
+                full_paths
+                    .entry(name)
+                    .or_default()
+                    .push(contract.name.full_path.as_str());
+            }
+        }
+
+        let warnings: Vec<solx_standard_json::OutputError> = full_paths
+            .into_iter()
+            .filter(|(_, full_paths)| full_paths.len() > 1)
+            .map(|(name, full_paths)| {
+                solx_standard_json::OutputError::new_warning(
+                    None,
+                    format!(
+                        "Contract name `{name}` is defined in multiple source files: {}. Tools identifying contracts by their simple names may pick the wrong one.",
+                        full_paths
+                            .into_iter()
+                            .map(|full_path| format!("`{full_path}`"))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                    None,
+                    None,
+                )
+            })
+            .collect();
+        self.messages.extend(warnings);
+    }
+
+    ///
+    /// Replaces the metadata compiler version of all contracts if requested.
+    ///
+    /// The override is reported with a warning, as the metadata no longer reflects the actual compiler.
+    ///
+    fn override_compiler_version(&mut self) -> anyhow::Result<()> {
+        let version = match self.options.compiler_version.clone() {
+            Some(version) => version,
+            None => return Ok(()),
+        };
+
+        let mut original_versions = BTreeSet::new();
+        for contract in self.results.values_mut().flatten() {
+            let metadata = match contract.metadata.as_mut() {
+                Some(metadata) if !metadata.is_empty() => metadata,
+                _ => continue,
+            };
+
+            let mut object: serde_json::Value = serde_json::from_str(metadata.as_str())?;
+            let compiler = match object
+                .get_mut("compiler")
+                .and_then(|compiler| compiler.as_object_mut())
+            {
+                Some(compiler) => compiler,
+                None => continue,
+            };
+            if let Some(original_version) = compiler
+                .insert(
+                    "version".to_owned(),
+                    serde_json::Value::String(version.clone()),
+                )
+                .as_ref()
+                .and_then(|original_version| original_version.as_str())
+            {
+                original_versions.insert(original_version.to_owned());
+            }
+            *metadata = serde_json::to_string(&object).expect("Always valid");
+        }
+
+        self.messages
+            .push(solx_standard_json::OutputError::new_warning(
+                None,
+                format!(
+                    "The metadata compiler version is overridden with `{version}`, so it does not reflect the actual compiler version{}.",
+                    if original_versions.is_empty() {
+                        String::new()
+                    } else {
+                        format!(
+                            " {}",
+                            original_versions
+                                .into_iter()
+                                .map(|original_version| format!("`{original_version}`"))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        )
+                    }
+                ),
+                None,
+                None,
+            ));
+        Ok(())
+    }
+
+    ///
+    /// Wraps the runtime code of all contracts into EOF containers if requested.
+    ///
+    fn wrap_eof(&mut self) -> anyhow::Result<()> {
+        if !self.options.eof {
+            return Ok(());
+        }
+
+        for contract in self
+            .results
+            .values_mut()
+            .filter_map(|result| result.as_mut().ok())
+        {
+            contract.wrap_runtime_eof()?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Drops the deploy code objects if only the runtime code is requested.
+    ///
+    fn strip_deploy_objects(&mut self) {
+        if !self.options.runtime_only {
+            return;
+        }
+
+        for contract in self
+            .results
+            .values_mut()
+            .filter_map(|result| result.as_mut().ok())
+        {
+            contract.deploy_object = None;
+        }
+    }
+
+    ///
+    /// Writes a single diagnostic record to the JSON Lines stream.
+    ///
+    fn write_jsonl_diagnostic(
+        writer: &mut impl Write,
+        path: Option<&str>,
+        error: &solx_standard_json::OutputError,
+    ) -> anyhow::Result<()> {
+        let record = serde_json::json!({
+            "solxOutputVersion": solx_standard_json::Output::VERSION,
+            "kind": "diagnostic",
+            "fullPath": path,
+            "severity": error.severity,
+            "message": error.formatted_message,
+            "sourceLocation": error.source_location,
+        });
+        serde_json::to_writer(&mut *writer, &record)?;
+        writeln!(writer)?;
+        Ok(())
+    }
+}
+
+impl solx_standard_json::CollectableError for Build {
+    fn errors(&self) -> Vec<&solx_standard_json::OutputError> {
+        let mut errors: Vec<&solx_standard_json::OutputError> = self
+            .results
+            .values()
+            .filter_map(|build| build.as_ref().err())
+            .collect();
+        errors.extend(self.messages.iter().filter(|message| {
+            message.severity == "error"
+                || (self.options.warnings_as_errors && message.severity == "warning")
+                || self.options.escalation.is_escalated(message)
+        }));
+        errors
+    }
+
+    fn take_warnings(&mut self) -> Vec<solx_standard_json::OutputError> {
+        self.options.escalation.apply(self.messages.as_mut_slice());
+        let mut warnings: Vec<solx_standard_json::OutputError> = self
+            .messages
+            .iter()
+            .filter(|message| message.severity == "warning")
+            .cloned()
+            .collect();
+        warnings.extend(self.object_warnings());
+        self.options.escalation.apply(warnings.as_mut_slice());
+        let (escalated, warnings): (Vec<_>, Vec<_>) = warnings
+            .into_iter()
+            .partition(|warning| warning.severity == "error");
+        self.messages.extend(escalated);
         self.messages
             .retain(|message| message.severity != "warning");
+        let (warnings, suppressed) = self.options.suppression.filter(warnings);
+        self.suppressed.extend(suppressed);
+        if self.options.warnings_as_errors {
+            for mut warning in warnings.into_iter() {
+                warning.promote_to_error();
+                self.messages.push(warning);
+            }
+            return vec![];
+        }
         warnings
     }
 }
@@ -0,0 +1,83 @@
+//!
+//! The build output options.
+//!
+
+use crate::build::contract::filename_template::FilenameTemplate;
+use crate::build::contract::hex_format::HexFormat;
+
+///
+/// The build output options.
+///
+/// Control how the compiled contracts are checked and written by the output writers.
+/// The options are preserved when the build is linked.
+///
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Whether warnings are treated as errors.
+    pub warnings_as_errors: bool,
+    /// The warnings promoted to errors by code.
+    pub escalation: solx_standard_json::OutputErrorEscalation,
+    /// The warnings removed from the output by code.
+    pub suppression: solx_standard_json::OutputErrorSuppression,
+    /// Whether only the runtime code is written to the output.
+    pub runtime_only: bool,
+    /// Whether exceeding the EIP-170 runtime code size limit is reported as an error.
+    pub strict_size_limit: bool,
+    /// Whether the runtime code is wrapped into EOF containers.
+    pub eof: bool,
+    /// Whether the successfully compiled contracts are written despite the failed ones.
+    pub keep_going: bool,
+    /// Whether the contracts that remain unlinked are reported as linker errors.
+    pub require_linked: bool,
+    /// Whether the source directory structure is mirrored in the output directory.
+    pub mirror_source_tree: bool,
+    /// The compiler version overriding the one in the metadata `compiler` field.
+    pub compiler_version: Option<String>,
+    /// Whether the build manifest is written to the output directory.
+    pub manifest: bool,
+    /// Whether the creation bytecode is written to the output.
+    pub output_bytecode: bool,
+    /// Whether the runtime bytecode is written to the output separately.
+    pub output_runtime_bytecode: bool,
+    /// Whether the contract JSON artifacts are written to the output directory.
+    pub output_contract_json: bool,
+    /// The hexadecimal format of the bytecode written to the terminal.
+    pub hex_format: HexFormat,
+    /// The target EVM version, if specified.
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
+    /// Whether only the artifacts with changed content are written to the output directory.
+    pub only_changed: bool,
+    /// The output file name template.
+    pub filename_template: FilenameTemplate,
+    /// The standard JSON output selection, if the outputs are selected per file or contract.
+    pub output_selection: Option<solx_standard_json::InputSelection>,
+    /// The terminal color mode of diagnostics and success messages.
+    pub color: solx_standard_json::OutputErrorColor,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warnings_as_errors: false,
+            escalation: solx_standard_json::OutputErrorEscalation::default(),
+            suppression: solx_standard_json::OutputErrorSuppression::default(),
+            runtime_only: false,
+            strict_size_limit: false,
+            eof: false,
+            keep_going: false,
+            require_linked: false,
+            mirror_source_tree: false,
+            compiler_version: None,
+            manifest: false,
+            output_bytecode: true,
+            output_runtime_bytecode: false,
+            output_contract_json: false,
+            hex_format: HexFormat::default(),
+            evm_version: None,
+            only_changed: false,
+            filename_template: FilenameTemplate::default(),
+            output_selection: None,
+            color: solx_standard_json::OutputErrorColor::default(),
+        }
+    }
+}
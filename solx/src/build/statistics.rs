@@ -0,0 +1,129 @@
+//!
+//! The Solidity project build statistics.
+//!
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use crate::build::contract::optimization::Optimization;
+
+///
+/// The Solidity project build statistics.
+///
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct Statistics {
+    /// The deploy and runtime bytecode sizes of each contract.
+    pub contracts: BTreeMap<String, (usize, usize)>,
+    /// The total deploy bytecode size.
+    pub total_deploy_size: usize,
+    /// The total runtime bytecode size.
+    pub total_runtime_size: usize,
+    /// The full path of the contract with the largest runtime bytecode.
+    pub largest_contract: Option<String>,
+    /// The LLVM optimizer settings each contract was compiled with.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub optimizations: BTreeMap<String, Optimization>,
+    /// The warnings about contracts exceeding the runtime bytecode size limit.
+    #[serde(skip)]
+    pub warnings: Vec<solx_standard_json::OutputError>,
+}
+
+impl Statistics {
+    /// The runtime bytecode size limit introduced by EIP-170.
+    pub const RUNTIME_SIZE_LIMIT: usize = 24576;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Produces a warning for each contract whose runtime bytecode exceeds `RUNTIME_SIZE_LIMIT`.
+    ///
+    pub fn new(contracts: BTreeMap<String, (usize, usize)>) -> Self {
+        let total_deploy_size = contracts.values().map(|(deploy, _runtime)| deploy).sum();
+        let total_runtime_size = contracts.values().map(|(_deploy, runtime)| runtime).sum();
+        let largest_contract = contracts
+            .iter()
+            .max_by_key(|(_path, (_deploy, runtime))| *runtime)
+            .map(|(path, _sizes)| path.to_owned());
+        let warnings = contracts
+            .iter()
+            .filter(|(_path, (_deploy, runtime))| *runtime > Self::RUNTIME_SIZE_LIMIT)
+            .map(|(path, (_deploy, runtime))| {
+                solx_standard_json::OutputError::new_warning(
+                    None,
+                    format!(
+                        "Contract `{path}` runtime code size is {runtime} bytes, which exceeds the limit of {} bytes. It may not be deployable on Mainnet.",
+                        Self::RUNTIME_SIZE_LIMIT,
+                    ),
+                    Some(solx_standard_json::OutputErrorSourceLocation::new(
+                        path.to_owned(),
+                    )),
+                    None,
+                )
+            })
+            .collect();
+
+        Self {
+            contracts,
+            total_deploy_size,
+            total_runtime_size,
+            largest_contract,
+            optimizations: BTreeMap::new(),
+            warnings,
+        }
+    }
+
+    ///
+    /// Writes the statistics to `writer` as a table.
+    ///
+    pub fn write_table(&self, writer: &mut impl Write) -> anyhow::Result<()> {
+        let width = self
+            .contracts
+            .keys()
+            .map(|path| path.len())
+            .chain(std::iter::once("Total".len()))
+            .max()
+            .unwrap_or_default();
+
+        let optimizer_header = if self.optimizations.is_empty() {
+            String::new()
+        } else {
+            format!("  {:<10}", "Optimizer")
+        };
+        writeln!(
+            writer,
+            "{:<width$}  {:>10}  {:>10}{optimizer_header}",
+            "Contract", "Deploy", "Runtime"
+        )?;
+        for (path, (deploy, runtime)) in self.contracts.iter() {
+            let optimizer = if self.optimizations.is_empty() {
+                String::new()
+            } else {
+                let optimization = self
+                    .optimizations
+                    .get(path)
+                    .map(|optimization| optimization.to_string())
+                    .unwrap_or_else(|| "-".to_owned());
+                format!("  {optimization:<10}")
+            };
+            let marker = if *runtime > Self::RUNTIME_SIZE_LIMIT {
+                " (!)"
+            } else {
+                ""
+            };
+            writeln!(
+                writer,
+                "{path:<width$}  {deploy:>10}  {runtime:>10}{optimizer}{marker}"
+            )?;
+        }
+        writeln!(
+            writer,
+            "{:<width$}  {:>10}  {:>10}",
+            "Total", self.total_deploy_size, self.total_runtime_size
+        )?;
+        if let Some(ref largest_contract) = self.largest_contract {
+            writeln!(writer, "Largest contract: {largest_contract}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+//!
+//! The incremental compilation cache.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::build::contract::Contract as EVMContractBuild;
+use crate::process::input::Input as EVMProcessInput;
+
+///
+/// The incremental compilation cache.
+///
+/// Stores the contract builds in a directory, keyed by the hash of the code generation input.
+/// The input contains the contract IR and the `solc` metadata, which lists the hashes of all
+/// the sources the contract depends on, so changes in imported files invalidate the entry as well.
+/// The input also contains the optimizer settings and the other code generation options.
+///
+/// Only the code generation is skipped, as the frontend is still needed to produce the IR.
+///
+#[derive(Debug, Clone)]
+pub struct Cache {
+    /// The cache directory.
+    directory: PathBuf,
+}
+
+impl Cache {
+    /// The cache entry file extension.
+    pub const FILE_EXTENSION: &'static str = "json";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    ///
+    /// Returns the cache key of the code generation `input`.
+    ///
+    /// The compiler version is hashed as well, so entries produced by other versions are never used.
+    ///
+    pub fn key(input: &EVMProcessInput) -> String {
+        let mut preimage = crate::r#const::version().into_bytes();
+        preimage.extend(serde_json::to_vec(input).expect("Always valid"));
+        era_compiler_common::Keccak256Hash::from_slice(preimage.as_slice()).to_string()
+    }
+
+    ///
+    /// Loads the contract build stored with `key`, if any.
+    ///
+    /// Unreadable or corrupted entries are treated as missing, so they are overwritten by the next build.
+    ///
+    pub fn load(&self, key: &str) -> Option<EVMContractBuild> {
+        let content = std::fs::read(self.entry_path(key)).ok()?;
+        serde_json::from_slice(content.as_slice()).ok()
+    }
+
+    ///
+    /// Stores the contract `build` with `key`.
+    ///
+    pub fn store(&self, key: &str, build: &EVMContractBuild) -> anyhow::Result<()> {
+        std::fs::create_dir_all(self.directory.as_path()).map_err(|error| {
+            anyhow::anyhow!("Cache directory {:?} creation: {error}", self.directory)
+        })?;
+
+        let path = self.entry_path(key);
+        let content = serde_json::to_vec(build).expect("Always valid");
+        std::fs::write(path.as_path(), content)
+            .map_err(|error| anyhow::anyhow!("Cache file {path:?} writing: {error}"))?;
+        Ok(())
+    }
+
+    ///
+    /// Returns the cache directory.
+    ///
+    pub fn directory(&self) -> &Path {
+        self.directory.as_path()
+    }
+
+    ///
+    /// Returns the path of the entry stored with `key`.
+    ///
+    fn entry_path(&self, key: &str) -> PathBuf {
+        let mut path = self.directory.join(key);
+        path.set_extension(Self::FILE_EXTENSION);
+        path
+    }
+}
@@ -11,6 +11,18 @@ pub static SOLC_PRODUCTION_NAME: &str = "solc";
 /// The `solc` LLVM revision metadata tag.
 pub static SOLC_LLVM_REVISION_METADATA_TAG: &str = "llvm";
 
+/// The default virtual path of the source read from the standard input.
+pub static DEFAULT_STDIN_PATH: &str = "stdin.sol";
+
+/// The source path of the in-memory source passed to `compile_solidity`.
+pub static COMPILE_SOLIDITY_SOURCE_PATH: &str = "input.sol";
+
+/// The LLVM option skipping all optional passes, which is used to build without optimizations.
+pub static LLVM_OPTION_NO_OPTIMIZE: &str = "-opt-bisect-limit=0";
+
+/// The name of the EVM target registered in the LLVM framework.
+pub static LLVM_TARGET_EVM: &str = "evm";
+
 /// The worker thread stack size.
 pub const WORKER_THREAD_STACK_SIZE: usize = 64 * 1024 * 1024;
 
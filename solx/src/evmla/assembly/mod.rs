@@ -4,6 +4,7 @@
 
 pub mod data;
 pub mod instruction;
+pub mod text;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
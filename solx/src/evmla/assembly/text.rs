@@ -0,0 +1,620 @@
+//!
+//! The `solc --asm` textual assembly parser.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::evmla::assembly::data::Data;
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::evmla::assembly::instruction::Instruction;
+use crate::evmla::assembly::Assembly;
+
+///
+/// The textual assembly section of a single contract.
+///
+#[derive(Debug)]
+pub struct Section {
+    /// The contract source path.
+    pub path: String,
+    /// The contract name.
+    pub name: String,
+    /// The parsed deploy code assembly with the runtime code nested.
+    pub assembly: Assembly,
+}
+
+///
+/// Parses the textual assembly of one or more contracts.
+///
+/// Contracts are separated by `======= <path>:<name> =======` headers, as printed by `solc --asm`.
+/// If there are no headers, the whole `source` is a single contract named after the file stem of `path`.
+///
+pub fn parse(path: &str, source: &str) -> anyhow::Result<Vec<Section>> {
+    let mut headers = Vec::new();
+    let mut bodies: Vec<String> = Vec::new();
+    let mut prelude = String::new();
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if let Some(header) = trimmed
+            .strip_prefix("=======")
+            .and_then(|header| header.strip_suffix("======="))
+        {
+            let (path, name) = header.trim().rsplit_once(':').ok_or_else(|| {
+                anyhow::anyhow!("Invalid contract header `{trimmed}`: expected `<path>:<name>`")
+            })?;
+            headers.push((path.to_owned(), name.to_owned()));
+            bodies.push(String::new());
+            continue;
+        }
+        if trimmed == "EVM assembly:" {
+            continue;
+        }
+
+        let body = bodies.last_mut().unwrap_or(&mut prelude);
+        body.push_str(line);
+        body.push('\n');
+    }
+
+    if headers.is_empty() {
+        let name = std::path::Path::new(path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_owned());
+        headers.push((path.to_owned(), name));
+        bodies.push(prelude);
+    } else if !prelude.trim().is_empty() {
+        anyhow::bail!("Assembly found before the first contract header");
+    }
+
+    headers
+        .into_iter()
+        .zip(bodies)
+        .map(|((path, name), body)| {
+            let assembly = Parser::new(body.as_str())
+                .and_then(|mut parser| parser.parse_assembly(false))
+                .map_err(|error| anyhow::anyhow!("Contract `{path}:{name}`: {error}"))?;
+            Ok(Section {
+                path,
+                name,
+                assembly,
+            })
+        })
+        .collect()
+}
+
+///
+/// The textual assembly token.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// An instruction, tag, or sub-assembly identifier.
+    Identifier(String),
+    /// A hexadecimal number without the `0x` prefix.
+    Number(String),
+    /// A string literal without the quotes.
+    String(String),
+    /// The source location from a `/* "<path>":<begin>:<end> ... */` comment.
+    Location(isize, isize),
+    /// The `(` symbol.
+    ParenthesisLeft,
+    /// The `)` symbol.
+    ParenthesisRight,
+    /// The `,` symbol.
+    Comma,
+    /// The `:` symbol.
+    Colon,
+    /// The `{` symbol.
+    BracketCurlyLeft,
+    /// The `}` symbol.
+    BracketCurlyRight,
+}
+
+///
+/// The textual assembly parser.
+///
+struct Parser {
+    /// The tokens with their line numbers.
+    tokens: Vec<(Token, usize)>,
+    /// The index of the next token.
+    offset: usize,
+    /// The source location of the following instructions.
+    location: (isize, isize),
+}
+
+impl Parser {
+    ///
+    /// Tokenizes `source` and creates a parser.
+    ///
+    fn new(source: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            tokens: Self::tokenize(source)?,
+            offset: 0,
+            location: (-1, -1),
+        })
+    }
+
+    ///
+    /// Splits `source` into tokens, dropping comments except for source locations.
+    ///
+    fn tokenize(source: &str) -> anyhow::Result<Vec<(Token, usize)>> {
+        let chars: Vec<char> = source.chars().collect();
+        let mut tokens = Vec::new();
+        let mut line = 1;
+        let mut index = 0;
+
+        while index < chars.len() {
+            let character = chars[index];
+            match character {
+                '\n' => {
+                    line += 1;
+                    index += 1;
+                }
+                character if character.is_whitespace() => index += 1,
+                '/' if chars.get(index + 1) == Some(&'/') => {
+                    while index < chars.len() && chars[index] != '\n' {
+                        index += 1;
+                    }
+                }
+                '/' if chars.get(index + 1) == Some(&'*') => {
+                    let start = index + 2;
+                    let mut end = start;
+                    while end + 1 < chars.len() && !(chars[end] == '*' && chars[end + 1] == '/') {
+                        end += 1;
+                    }
+                    if end + 1 >= chars.len() {
+                        anyhow::bail!("line {line}: unterminated comment");
+                    }
+                    let comment: String = chars[start..end].iter().collect();
+                    if let Some(location) = Self::parse_location(comment.as_str()) {
+                        tokens.push((location, line));
+                    }
+                    line += comment.matches('\n').count();
+                    index = end + 2;
+                }
+                '"' => {
+                    let start = index + 1;
+                    let mut end = start;
+                    while end < chars.len() && chars[end] != '"' && chars[end] != '\n' {
+                        end += 1;
+                    }
+                    if chars.get(end) != Some(&'"') {
+                        anyhow::bail!("line {line}: unterminated string");
+                    }
+                    tokens.push((Token::String(chars[start..end].iter().collect()), line));
+                    index = end + 1;
+                }
+                '(' | ')' | ',' | ':' | '{' | '}' => {
+                    let token = match character {
+                        '(' => Token::ParenthesisLeft,
+                        ')' => Token::ParenthesisRight,
+                        ',' => Token::Comma,
+                        ':' => Token::Colon,
+                        '{' => Token::BracketCurlyLeft,
+                        _ => Token::BracketCurlyRight,
+                    };
+                    tokens.push((token, line));
+                    index += 1;
+                }
+                character if character.is_ascii_digit() => {
+                    let start = index;
+                    while index < chars.len() && chars[index].is_ascii_alphanumeric() {
+                        index += 1;
+                    }
+                    let literal: String = chars[start..index].iter().collect();
+                    let number = match literal.strip_prefix("0x") {
+                        Some(hexadecimal)
+                            if !hexadecimal.is_empty()
+                                && hexadecimal.chars().all(|digit| digit.is_ascii_hexdigit()) =>
+                        {
+                            hexadecimal.to_owned()
+                        }
+                        Some(_) => anyhow::bail!("line {line}: invalid number `{literal}`"),
+                        None => {
+                            let decimal: u128 = literal.parse().map_err(|_| {
+                                anyhow::anyhow!("line {line}: invalid number `{literal}`")
+                            })?;
+                            format!("{decimal:x}")
+                        }
+                    };
+                    tokens.push((Token::Number(number), line));
+                }
+                character if character.is_ascii_alphabetic() || character == '_' => {
+                    let start = index;
+                    while index < chars.len()
+                        && (chars[index].is_ascii_alphanumeric() || chars[index] == '_')
+                    {
+                        index += 1;
+                    }
+                    tokens.push((
+                        Token::Identifier(chars[start..index].iter().collect()),
+                        line,
+                    ));
+                }
+                character => anyhow::bail!("line {line}: unexpected character `{character}`"),
+            }
+        }
+
+        Ok(tokens)
+    }
+
+    ///
+    /// Parses a `"<path>":<begin>:<end> <snippet>` source location comment.
+    ///
+    fn parse_location(comment: &str) -> Option<Token> {
+        let comment = comment.trim().strip_prefix('"')?;
+        let (_path, rest) = comment.split_once('"')?;
+        let mut parts = rest
+            .strip_prefix(':')?
+            .split(|character: char| character == ':' || character.is_whitespace());
+        let begin = parts.next()?.parse().ok()?;
+        let end = parts.next()?.parse().ok()?;
+        Some(Token::Location(begin, end))
+    }
+
+    ///
+    /// Parses an assembly block until the end of input, or until `}` if `is_nested` is set.
+    ///
+    fn parse_assembly(&mut self, is_nested: bool) -> anyhow::Result<Assembly> {
+        let mut code = Vec::new();
+        let mut data = BTreeMap::new();
+        let mut auxdata = None;
+
+        loop {
+            let (token, line) = match self.next() {
+                Some(token) => token,
+                None if is_nested => anyhow::bail!("unterminated sub-assembly"),
+                None => break,
+            };
+
+            match token {
+                Token::BracketCurlyRight if is_nested => break,
+                Token::Location(begin, end) => self.location = (begin, end),
+                Token::Identifier(identifier) if self.peek() == Some(&Token::Colon) => {
+                    self.next();
+                    if let Some(tag) = identifier.strip_prefix("tag_") {
+                        let tag = Self::parse_index(tag, line)?;
+                        code.push(self.instruction(InstructionName::Tag, Some(tag.to_string())));
+                        code.push(self.instruction(InstructionName::JUMPDEST, None));
+                    } else if let Some(index) = identifier.strip_prefix("sub_") {
+                        let index = Self::parse_index(index, line)?;
+                        self.expect(Token::Identifier("assembly".to_owned()))?;
+                        self.expect(Token::BracketCurlyLeft)?;
+                        let assembly = self.parse_assembly(true)?;
+                        data.insert(format!("{index:x}"), Data::Assembly(assembly));
+                    } else if identifier == "auxdata" {
+                        auxdata = Some(self.expect_number()?);
+                    } else if let Some(key) = identifier.strip_prefix("data_") {
+                        data.insert(key.to_owned(), Data::Hash(self.expect_number()?));
+                    } else {
+                        anyhow::bail!("line {line}: unknown label `{identifier}`");
+                    }
+                }
+                token => self.parse_expression(token, line, &mut code)?,
+            }
+        }
+
+        Ok(Assembly {
+            auxdata,
+            code: Some(code),
+            data: if data.is_empty() { None } else { Some(data) },
+            full_path: None,
+            factory_dependencies: Default::default(),
+            extra_metadata: None,
+        })
+    }
+
+    ///
+    /// Parses an instruction in either plain or functional notation, and appends it to `code`.
+    ///
+    /// The arguments of `f(a, b)` are pushed in reverse order, so that `a` ends up on top of the stack.
+    ///
+    fn parse_expression(
+        &mut self,
+        token: Token,
+        line: usize,
+        code: &mut Vec<Instruction>,
+    ) -> anyhow::Result<()> {
+        let identifier = match token {
+            Token::Number(value) => {
+                code.push(self.instruction(InstructionName::PUSH, Some(value)));
+                return Ok(());
+            }
+            Token::Identifier(identifier) => identifier,
+            token => anyhow::bail!("line {line}: unexpected token `{token:?}`"),
+        };
+
+        if let Some(tag) = identifier.strip_prefix("tag_") {
+            let tag = Self::parse_index(tag, line)?;
+            code.push(self.instruction(InstructionName::PUSH_Tag, Some(tag.to_string())));
+            return Ok(());
+        }
+        if let Some(key) = identifier.strip_prefix("data_") {
+            code.push(self.instruction(InstructionName::PUSH_Data, Some(key.to_owned())));
+            return Ok(());
+        }
+        if identifier == "bytecodeSize" {
+            code.push(self.instruction(InstructionName::PUSHSIZE, None));
+            return Ok(());
+        }
+        if self.peek() != Some(&Token::ParenthesisLeft) {
+            let name = Self::parse_instruction_name(identifier.as_str(), line)?;
+            code.push(self.instruction(name, None));
+            return Ok(());
+        }
+        self.next();
+
+        let special = match identifier.as_str() {
+            "dataSize" => Some(InstructionName::PUSH_DataSize),
+            "dataOffset" => Some(InstructionName::PUSH_DataOffset),
+            "linkerSymbol" => Some(InstructionName::PUSHLIB),
+            "immutable" => Some(InstructionName::PUSHIMMUTABLE),
+            "assignImmutable" => Some(InstructionName::ASSIGNIMMUTABLE),
+            "deployTimeAddress" => Some(InstructionName::PUSHDEPLOYADDRESS),
+            _ => None,
+        };
+        if let Some(name) = special {
+            let value = match name {
+                InstructionName::PUSHDEPLOYADDRESS => None,
+                InstructionName::PUSH_DataSize | InstructionName::PUSH_DataOffset => {
+                    match self.next() {
+                        Some((Token::Identifier(identifier), line)) => {
+                            let index = identifier.strip_prefix("sub_").ok_or_else(|| {
+                                anyhow::anyhow!(
+                                    "line {line}: expected a sub-assembly, found `{identifier}`"
+                                )
+                            })?;
+                            let index = Self::parse_index(index, line)?;
+                            Some(format!(
+                                "{index:0width$x}",
+                                width = era_compiler_common::BYTE_LENGTH_FIELD * 2
+                            ))
+                        }
+                        token => {
+                            anyhow::bail!("line {line}: expected a sub-assembly, found `{token:?}`")
+                        }
+                    }
+                }
+                _ => match self.next() {
+                    Some((Token::String(value), _)) => Some(Self::parse_immutable(&name, value)?),
+                    token => anyhow::bail!("line {line}: expected a string, found `{token:?}`"),
+                },
+            };
+            self.expect(Token::ParenthesisRight)?;
+            code.push(self.instruction(name, value));
+            return Ok(());
+        }
+
+        let name = Self::parse_instruction_name(identifier.as_str(), line)?;
+        let mut arguments = Vec::new();
+        if self.peek() == Some(&Token::ParenthesisRight) {
+            self.next();
+        } else {
+            loop {
+                let (token, line) = self
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("line {line}: unterminated argument list"))?;
+                let mut argument = Vec::new();
+                self.parse_expression(token, line, &mut argument)?;
+                arguments.push(argument);
+                match self.next() {
+                    Some((Token::Comma, _)) => continue,
+                    Some((Token::ParenthesisRight, _)) => break,
+                    token => anyhow::bail!("line {line}: expected `,` or `)`, found `{token:?}`"),
+                }
+            }
+        }
+        for argument in arguments.into_iter().rev() {
+            code.extend(argument);
+        }
+        code.push(self.instruction(name, None));
+        Ok(())
+    }
+
+    ///
+    /// Converts an instruction mnemonic into its name.
+    ///
+    /// Pseudo-instructions and pushes are rejected, as they require values.
+    ///
+    fn parse_instruction_name(identifier: &str, line: usize) -> anyhow::Result<InstructionName> {
+        let uppercase = identifier.to_ascii_uppercase();
+        if uppercase.starts_with("PUSH") || matches!(uppercase.as_str(), "TAG" | "ASSIGNIMMUTABLE")
+        {
+            anyhow::bail!("line {line}: unexpected instruction `{identifier}`");
+        }
+        serde_json::from_value(serde_json::Value::String(uppercase))
+            .map_err(|_| anyhow::anyhow!("line {line}: unknown instruction `{identifier}`"))
+    }
+
+    ///
+    /// Converts an immutable or library identifier into the instruction value.
+    ///
+    /// Hexadecimal immutable identifiers are converted to decimal, as in the JSON assembly.
+    ///
+    fn parse_immutable(name: &InstructionName, value: String) -> anyhow::Result<String> {
+        match (name, value.strip_prefix("0x")) {
+            (
+                InstructionName::PUSHIMMUTABLE | InstructionName::ASSIGNIMMUTABLE,
+                Some(hexadecimal),
+            ) => {
+                let identifier =
+                    u128::from_str_radix(hexadecimal, era_compiler_common::BASE_HEXADECIMAL)
+                        .map_err(|error| {
+                            anyhow::anyhow!("invalid immutable identifier `{value}`: {error}")
+                        })?;
+                Ok(identifier.to_string())
+            }
+            _ => Ok(value),
+        }
+    }
+
+    ///
+    /// Parses a decimal tag or sub-assembly index.
+    ///
+    fn parse_index(index: &str, line: usize) -> anyhow::Result<usize> {
+        index
+            .parse()
+            .map_err(|_| anyhow::anyhow!("line {line}: invalid index `{index}`"))
+    }
+
+    ///
+    /// Creates an instruction at the current source location.
+    ///
+    fn instruction(&self, name: InstructionName, value: Option<String>) -> Instruction {
+        Instruction {
+            name,
+            value,
+            source: None,
+            begin: self.location.0,
+            end: self.location.1,
+        }
+    }
+
+    ///
+    /// Returns the next token and advances the parser.
+    ///
+    fn next(&mut self) -> Option<(Token, usize)> {
+        let token = self.tokens.get(self.offset).cloned();
+        self.offset += 1;
+        token
+    }
+
+    ///
+    /// Returns the next token without advancing the parser.
+    ///
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.offset).map(|(token, _line)| token)
+    }
+
+    ///
+    /// Takes the next token and checks that it is `expected`.
+    ///
+    fn expect(&mut self, expected: Token) -> anyhow::Result<()> {
+        match self.next() {
+            Some((token, _line)) if token == expected => Ok(()),
+            Some((token, line)) => {
+                anyhow::bail!("line {line}: expected `{expected:?}`, found `{token:?}`")
+            }
+            None => anyhow::bail!("expected `{expected:?}`, found the end of input"),
+        }
+    }
+
+    ///
+    /// Takes the next token and checks that it is a number.
+    ///
+    fn expect_number(&mut self) -> anyhow::Result<String> {
+        match self.next() {
+            Some((Token::Number(number), _line)) => Ok(number),
+            Some((token, line)) => {
+                anyhow::bail!("line {line}: expected a number, found `{token:?}`")
+            }
+            None => anyhow::bail!("expected a number, found the end of input"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::evmla::assembly::data::Data;
+    use crate::evmla::assembly::instruction::name::Name as InstructionName;
+
+    const INPUT: &str = r#"
+======= Test.sol:Test =======
+EVM assembly:
+    /* "Test.sol":0:10  contract Test {} */
+  mstore(0x40, 0x80)
+  jumpi(tag_1, iszero(callvalue))
+  revert(0x00, 0x00)
+tag_1:
+  dataSize(sub_0)
+  dup1
+  dataOffset(sub_0)
+  0x00
+  codecopy
+  0x00
+  return
+stop
+
+sub_0: assembly {
+        /* "Test.sol":0:10  contract Test {} */
+      mstore(0x40, 0x80)
+      tag_2
+      jump	// in
+    tag_2:
+      stop
+
+    auxdata: 0xa264
+}
+"#;
+
+    fn names(assembly: &super::Assembly) -> Vec<String> {
+        assembly
+            .code
+            .as_ref()
+            .expect("Always exists")
+            .iter()
+            .map(|instruction| match instruction.value {
+                Some(ref value) => format!("{} {value}", instruction.name),
+                None => instruction.name.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn default() {
+        let mut sections = super::parse("Test.asm", INPUT).expect("Test failure");
+        assert_eq!(sections.len(), 1);
+        let section = sections.remove(0);
+        assert_eq!(section.path, "Test.sol");
+        assert_eq!(section.name, "Test");
+
+        let deploy = names(&section.assembly);
+        assert_eq!(
+            &deploy[..9],
+            &[
+                "PUSH 80",
+                "PUSH 40",
+                "MSTORE",
+                "CALLVALUE",
+                "ISZERO",
+                "PUSH [tag] 1",
+                "JUMPI",
+                "PUSH 00",
+                "PUSH 00",
+            ]
+        );
+        assert!(deploy.contains(&"Tag 1".to_owned()));
+        assert!(deploy.contains(&format!("PUSH #[$] {}", "0".repeat(64))));
+        assert_eq!(
+            section.assembly.code.as_ref().expect("Always exists")[0].begin,
+            0
+        );
+
+        let runtime = match section
+            .assembly
+            .data
+            .as_ref()
+            .and_then(|data| data.get("0"))
+        {
+            Some(Data::Assembly(runtime)) => runtime,
+            _ => panic!("Test failure"),
+        };
+        assert_eq!(runtime.auxdata.as_deref(), Some("a264"));
+        assert!(names(runtime).contains(&InstructionName::JUMPDEST.to_string()));
+    }
+
+    #[test]
+    fn without_header() {
+        let sections =
+            super::parse("contracts/Simple.asm", "0x00\ndup1\nrevert\n").expect("Test failure");
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].path, "contracts/Simple.asm");
+        assert_eq!(sections[0].name, "Simple");
+    }
+
+    #[test]
+    fn unknown_instruction() {
+        let error = super::parse("Test.asm", "0x00\nfoo\n").expect_err("Test failure");
+        assert!(error
+            .to_string()
+            .contains("line 2: unknown instruction `foo`"));
+    }
+}
@@ -10,27 +10,56 @@
 #![allow(clippy::result_large_err)]
 
 pub mod build;
+pub mod cache;
 pub mod r#const;
 pub mod evmla;
 pub mod linker;
 pub mod process;
 pub mod project;
+pub mod server;
+pub mod watcher;
 pub mod yul;
 
+pub use self::build::archive::Format as ArchiveFormat;
+pub use self::build::contract::analysis::Analysis as BytecodeAnalysis;
+pub use self::build::contract::artifact::Artifact as EVMContractArtifact;
+pub use self::build::contract::assembly::Assembly as BytecodeAssembly;
+pub use self::build::contract::assembly::Instruction as BytecodeAssemblyInstruction;
+pub use self::build::contract::coverage::BasicBlock as EVMContractBasicBlock;
+pub use self::build::contract::coverage::CoverageMap as EVMContractCoverageMap;
+pub use self::build::contract::eof::Container as EOFContainer;
+pub use self::build::contract::filename_template::FilenameTemplate as EVMContractFilenameTemplate;
+pub use self::build::contract::hex_format::HexFormat as EVMContractHexFormat;
+pub use self::build::contract::llvm_ir_snapshot::Snapshot as EVMContractLLVMIRSnapshot;
+pub use self::build::contract::optimization::Optimization as EVMContractOptimization;
+pub use self::build::contract::optimization::Priority as EVMContractOptimizationPriority;
+pub use self::build::contract::verification::VerificationResult as EVMContractVerificationResult;
 pub use self::build::contract::Contract as EVMContractBuild;
+pub use self::build::diff::Diff as EVMBuildDiff;
+pub use self::build::manifest::Manifest as EVMBuildManifest;
+pub use self::build::options::Options as EVMBuildOptions;
+pub use self::build::statistics::Statistics as EVMBuildStatistics;
 pub use self::build::Build as EVMBuild;
+pub use self::cache::Cache;
 pub use self::linker::input::Input as LinkerInput;
 pub use self::linker::output::Output as LinkerOutput;
+pub use self::linker::symbols::load as load_linker_symbols;
 pub use self::linker::Linker;
 pub use self::process::input::Input as EVMProcessInput;
 pub use self::process::output::Output as EVMProcessOutput;
 pub use self::process::run as run_recursive;
 pub use self::process::EXECUTABLE;
 pub use self::project::contract::Contract as ProjectContract;
+pub use self::project::options::Options as CompileOptions;
 pub use self::project::Project;
 pub use self::r#const::*;
+pub use self::server::Server;
+pub use self::watcher::Watcher;
+pub use self::yul::parser::dialect::kind::Kind as YulDialect;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::path::Path;
 use std::path::PathBuf;
 
 use solx_standard_json::CollectableError;
@@ -38,12 +67,32 @@ use solx_standard_json::CollectableError;
 /// The default error compatible with `solc` standard JSON output.
 pub type Result<T> = std::result::Result<T, solx_standard_json::OutputError>;
 
+///
+/// Checks whether the LLVM framework `solx` is linked with is built with the EVM target.
+///
+/// Must be called once the target is initialized, and before any contract is compiled.
+/// Otherwise, a missing target is only reported deep in the code generator with an opaque error.
+///
+pub fn check_evm_target() -> anyhow::Result<()> {
+    if inkwell::targets::Target::from_name(crate::r#const::LLVM_TARGET_EVM).is_none() {
+        anyhow::bail!(
+            "The LLVM framework is built without the `{}` target. Rebuild it with the Matter Labs LLVM builder, or with `-DLLVM_TARGETS_TO_BUILD='EVM'` if built manually, and rebuild `solx` against it.",
+            crate::r#const::LLVM_TARGET_EVM,
+        );
+    }
+    Ok(())
+}
+
 ///
 /// Runs the Yul mode for the EVM target.
 ///
+/// The source read from the standard input is assigned the virtual `stdin_path`.
+///
 pub fn yul_to_evm(
     paths: &[PathBuf],
+    stdin_path: &str,
     libraries: &[String],
+    dialect: YulDialect,
     output_bytecode: bool,
     output_metadata: bool,
     messages: &mut Vec<solx_standard_json::OutputError>,
@@ -51,6 +100,8 @@ pub fn yul_to_evm(
     append_cbor: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    options: CompileOptions,
+    build_options: EVMBuildOptions,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EVMBuild> {
     let libraries = era_compiler_common::Libraries::try_from(libraries)?;
@@ -61,16 +112,43 @@ pub fn yul_to_evm(
     );
     let linker_symbols = libraries.as_linker_symbols()?;
 
+    let sources = paths
+        .iter()
+        .map(|path| {
+            if path.as_os_str() == solx_standard_json::InputSource::STDIN_PATH {
+                let source = solx_standard_json::InputSource::try_read(path.as_path())?;
+                Ok((stdin_path.to_owned(), source))
+            } else {
+                let source = solx_standard_json::InputSource::from(path.as_path());
+                Ok((path.to_string_lossy().to_string(), source))
+            }
+        })
+        .collect::<anyhow::Result<BTreeMap<String, solx_standard_json::InputSource>>>()?;
+
     let solc_compiler = solx_solc::Compiler::default();
-    solc_compiler.validate_yul_paths(paths, libraries.clone(), messages)?;
+    solc_compiler.validate_yul_sources(sources.clone(), libraries.clone(), messages)?;
 
-    let project = Project::try_from_yul_paths(
-        paths,
+    let mut project = Project::try_from_yul_sources(
+        sources,
         libraries,
         &output_selection,
+        dialect,
         None,
         debug_config.as_ref(),
     )?;
+    let arity_errors = project.yul_arity_errors();
+    if !arity_errors.is_empty() {
+        messages.extend(arity_errors);
+        let mut build = EVMBuild::new(BTreeMap::new(), messages);
+        build.set_options(build_options);
+        return Ok(build);
+    }
+    messages.extend(project.yul_shadowing_warnings());
+    messages.extend(project.yul_switch_default_warnings());
+    messages.extend(project.yul_dead_function_warnings());
+    messages.extend(project.yul_empty_object_warnings());
+    let keep_going = options.keep_going;
+    project.set_options(options)?;
 
     let mut build = project.compile_to_evm(
         messages,
@@ -80,8 +158,11 @@ pub fn yul_to_evm(
         llvm_options,
         debug_config,
     )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.set_options(build_options);
+    build.take_and_write_warnings(build.options.color);
+    if !keep_going {
+        build.check_errors()?;
+    }
 
     let cbor_data = if append_cbor {
         Some(vec![
@@ -102,9 +183,9 @@ pub fn yul_to_evm(
         None
     };
 
+    // The linker errors are left to the output writers, so they are reported with their exit code.
     let mut build = build.link(linker_symbols, cbor_data);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.take_and_write_warnings(build.options.color);
     Ok(build)
 }
 
@@ -121,6 +202,8 @@ pub fn llvm_ir_to_evm(
     append_cbor: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    options: CompileOptions,
+    build_options: EVMBuildOptions,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EVMBuild> {
     let libraries = era_compiler_common::Libraries::try_from(libraries)?;
@@ -128,7 +211,9 @@ pub fn llvm_ir_to_evm(
         solx_standard_json::InputSelection::new_compilation(output_bytecode, output_metadata, None);
     let linker_symbols = libraries.as_linker_symbols()?;
 
-    let project = Project::try_from_llvm_ir_paths(paths, libraries, &output_selection, None)?;
+    let mut project = Project::try_from_llvm_ir_paths(paths, libraries, &output_selection, None)?;
+    let keep_going = options.keep_going;
+    project.set_options(options)?;
 
     let mut build = project.compile_to_evm(
         messages,
@@ -138,8 +223,11 @@ pub fn llvm_ir_to_evm(
         llvm_options,
         debug_config,
     )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.set_options(build_options);
+    build.take_and_write_warnings(build.options.color);
+    if !keep_going {
+        build.check_errors()?;
+    }
 
     let cbor_data = if append_cbor {
         Some(vec![(
@@ -150,19 +238,78 @@ pub fn llvm_ir_to_evm(
         None
     };
 
+    // The linker errors are left to the output writers, so they are reported with their exit code.
     let mut build = build.link(linker_symbols, cbor_data);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.take_and_write_warnings(build.options.color);
+    Ok(build)
+}
+
+///
+/// Runs the textual EVM assembly mode for the EVM target.
+///
+pub fn assembly_to_evm(
+    paths: &[PathBuf],
+    libraries: &[String],
+    output_bytecode: bool,
+    output_metadata: bool,
+    messages: &mut Vec<solx_standard_json::OutputError>,
+    metadata_hash_type: era_compiler_common::EVMMetadataHashType,
+    append_cbor: bool,
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    llvm_options: Vec<String>,
+    options: CompileOptions,
+    build_options: EVMBuildOptions,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<EVMBuild> {
+    let libraries = era_compiler_common::Libraries::try_from(libraries)?;
+    let output_selection =
+        solx_standard_json::InputSelection::new_compilation(output_bytecode, output_metadata, None);
+    let linker_symbols = libraries.as_linker_symbols()?;
+
+    let mut project = Project::try_from_assembly_paths(paths, libraries, &output_selection)?;
+    let keep_going = options.keep_going;
+    project.set_options(options)?;
+
+    let mut build = project.compile_to_evm(
+        messages,
+        output_bytecode,
+        metadata_hash_type,
+        optimizer_settings,
+        llvm_options,
+        debug_config,
+    )?;
+    build.set_options(build_options);
+    build.take_and_write_warnings(build.options.color);
+    if !keep_going {
+        build.check_errors()?;
+    }
+
+    let cbor_data = if append_cbor {
+        Some(vec![(
+            crate::r#const::DEFAULT_EXECUTABLE_NAME.to_owned(),
+            crate::r#const::version().parse().expect("Always valid"),
+        )])
+    } else {
+        None
+    };
+
+    // The linker errors are left to the output writers, so they are reported with their exit code.
+    let mut build = build.link(linker_symbols, cbor_data);
+    build.take_and_write_warnings(build.options.color);
     Ok(build)
 }
 
 ///
 /// Runs the standard output mode for the EVM target.
 ///
+/// The source read from the standard input is assigned the virtual `stdin_path`.
+///
 pub fn standard_output_evm(
     paths: &[PathBuf],
+    stdin_path: &str,
     libraries: &[String],
     output_bytecode: bool,
+    output_abi: bool,
     messages: &mut Vec<solx_standard_json::OutputError>,
     evm_version: Option<era_compiler_common::EVMVersion>,
     via_ir: bool,
@@ -175,8 +322,11 @@ pub fn standard_output_evm(
     remappings: BTreeSet<String>,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    mut options: CompileOptions,
+    build_options: EVMBuildOptions,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EVMBuild> {
+    options.evm_version = evm_version;
     let mut solc_input = solx_standard_json::Input::try_from_solidity_paths(
         paths,
         libraries,
@@ -188,6 +338,18 @@ pub fn standard_output_evm(
         solx_standard_json::InputMetadata::new(metadata_literal, append_cbor, metadata_hash_type),
         llvm_options.clone(),
     )?;
+    if output_abi {
+        solc_input
+            .settings
+            .output_selection
+            .set_selector(solx_standard_json::InputSelector::ABI);
+    }
+    if let Some(source) = solc_input
+        .sources
+        .remove(solx_standard_json::InputSource::STDIN_PATH)
+    {
+        solc_input.sources.insert(stdin_path.to_owned(), source);
+    }
 
     let solc_compiler = solx_solc::Compiler::default();
 
@@ -198,19 +360,26 @@ pub fn standard_output_evm(
         include_paths,
         allow_paths,
     )?;
-    solc_output.take_and_write_warnings();
+    solc_output.set_warning_codes(
+        build_options.escalation.clone(),
+        build_options.suppression.clone(),
+    );
+    solc_output.check_unused_remappings(&solc_input.settings.remappings);
+    solc_output.take_and_write_warnings(build_options.color);
     solc_output.check_errors()?;
 
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
-    let project = Project::try_from_solc_output(
+    let mut project = Project::try_from_solc_output(
         solc_input.settings.libraries,
         via_ir,
         &mut solc_output,
         debug_config.as_ref(),
     )?;
-    solc_output.take_and_write_warnings();
+    solc_output.take_and_write_warnings(build_options.color);
     solc_output.check_errors()?;
+    let keep_going = options.keep_going;
+    project.set_options(options)?;
 
     let mut build = project.compile_to_evm(
         messages,
@@ -220,7 +389,162 @@ pub fn standard_output_evm(
         llvm_options,
         debug_config,
     )?;
-    build.take_and_write_warnings();
+    build.set_options(build_options);
+    build.suppressed.extend(solc_output.take_suppressed());
+    build.set_source_hashes(EVMBuild::hash_sources(&solc_input.sources));
+    build.take_and_write_warnings(build.options.color);
+    if !keep_going {
+        build.check_errors()?;
+    }
+
+    let cbor_data = if append_cbor {
+        Some(vec![
+            (
+                crate::r#const::DEFAULT_EXECUTABLE_NAME.to_owned(),
+                crate::r#const::version().parse().expect("Always valid"),
+            ),
+            (
+                crate::r#const::SOLC_PRODUCTION_NAME.to_owned(),
+                solc_compiler.version.default.to_owned(),
+            ),
+            (
+                crate::r#const::SOLC_LLVM_REVISION_METADATA_TAG.to_owned(),
+                solc_compiler.version.llvm_revision.to_owned(),
+            ),
+        ])
+    } else {
+        None
+    };
+
+    // The linker errors are left to the output writers, so they are reported with their exit code.
+    let mut build = build.link(linker_symbols, cbor_data);
+    build.take_and_write_warnings(build.options.color);
+    Ok(build)
+}
+
+///
+/// Compiles a single Solidity source string for the EVM target.
+///
+/// Does not touch the file system, so it is suitable for embedding the compiler as a library.
+/// Warnings are kept in the returned build instead of being written to stderr.
+/// If the output selection is empty, the bytecode and metadata are requested.
+///
+/// Contracts are compiled in subprocesses, so the `solx` executable path must be set via
+/// [`EXECUTABLE`] if the embedding binary is not `solx` itself.
+///
+pub fn compile_solidity(
+    source: &str,
+    settings: &solx_standard_json::InputSettings,
+) -> anyhow::Result<EVMBuild> {
+    compile_solidity_inner(source, settings, None)
+}
+
+///
+/// Compiles a single Solidity source string for the EVM target, resolving its imports via `callback`.
+///
+/// The callback receives the `solc` source unit name of each imported file, after the remappings
+/// are applied, and returns its contents or an error message. It mirrors the `solc` import callback,
+/// so the sources can be served from a virtual file system, e.g. in a browser or a test harness.
+///
+/// # Errors
+/// If an import cannot be resolved. The callback error is reported at the import directive.
+///
+pub fn compile_solidity_with_callback(
+    source: &str,
+    settings: &solx_standard_json::InputSettings,
+    callback: &mut solx_solc::ImportCallback,
+) -> anyhow::Result<EVMBuild> {
+    compile_solidity_inner(source, settings, Some(callback))
+}
+
+///
+/// Compiles a single Solidity source string, resolving its imports via `callback` if specified.
+///
+fn compile_solidity_inner(
+    source: &str,
+    settings: &solx_standard_json::InputSettings,
+    callback: Option<&mut solx_solc::ImportCallback>,
+) -> anyhow::Result<EVMBuild> {
+    let mut messages = Vec::new();
+
+    let via_ir = settings.via_ir;
+    let output_selection = if settings.output_selection.is_empty() {
+        solx_standard_json::InputSelection::new_compilation(true, true, Some(via_ir))
+    } else {
+        settings.output_selection.clone()
+    };
+    let output_bytecode = output_selection
+        .is_set_for_any(solx_standard_json::InputSelector::BytecodeObject)
+        || output_selection
+            .is_set_for_any(solx_standard_json::InputSelector::RuntimeBytecodeObject);
+
+    let mut optimizer_settings =
+        era_compiler_llvm_context::OptimizerSettings::try_from_cli(settings.optimizer.mode)?;
+    if settings.optimizer.size_fallback {
+        optimizer_settings.enable_fallback_to_size();
+    }
+    let metadata_hash_type = settings.metadata.bytecode_hash;
+    let append_cbor = settings.metadata.append_cbor;
+
+    let sources = BTreeMap::from([(
+        crate::r#const::COMPILE_SOLIDITY_SOURCE_PATH.to_owned(),
+        solx_standard_json::InputSource::from(source.to_owned()),
+    )]);
+    let mut solc_input = solx_standard_json::Input::try_from_solidity_sources(
+        sources,
+        settings.libraries.clone(),
+        settings.remappings.clone(),
+        solx_standard_json::InputOptimizer::new(
+            settings.optimizer.mode,
+            settings.optimizer.size_fallback,
+        ),
+        settings.evm_version,
+        via_ir,
+        output_selection,
+        solx_standard_json::InputMetadata::new(
+            settings.metadata.use_literal_content,
+            append_cbor,
+            metadata_hash_type,
+        ),
+        settings.llvm_options.clone(),
+    )?;
+
+    let solc_compiler = solx_solc::Compiler::default();
+
+    let mut solc_output = match callback {
+        Some(callback) => {
+            solc_compiler.standard_json_with_callback(&mut solc_input, &mut messages, callback)?
+        }
+        None => solc_compiler.standard_json(&mut solc_input, &mut messages, None, vec![], None)?,
+    };
+    messages.extend(solc_output.take_warnings());
+    solc_output.check_errors()?;
+
+    let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
+
+    let mut project = Project::try_from_solc_output(
+        solc_input.settings.libraries,
+        via_ir,
+        &mut solc_output,
+        None,
+    )?;
+    messages.extend(solc_output.take_warnings());
+    solc_output.check_errors()?;
+    project.set_options(CompileOptions {
+        optimization_overrides: settings.optimizer.contract_modes.clone(),
+        evm_version: settings.evm_version,
+        ..CompileOptions::default()
+    })?;
+
+    let mut build = project.compile_to_evm(
+        &mut messages,
+        output_bytecode,
+        metadata_hash_type,
+        optimizer_settings,
+        settings.llvm_options.clone(),
+        None,
+    )?;
+    build.set_source_hashes(EVMBuild::hash_sources(&solc_input.sources));
     build.check_errors()?;
 
     let cbor_data = if append_cbor {
@@ -243,25 +567,189 @@ pub fn standard_output_evm(
     };
 
     let mut build = build.link(linker_symbols, cbor_data);
-    build.take_and_write_warnings();
+    build.options.strict_size_limit = settings.strict_size_limit;
+    build.options.evm_version = settings.evm_version;
     build.check_errors()?;
     Ok(build)
 }
 
+///
+/// Compiles a single Solidity source string and returns the runtime bytecode disassembly of each contract.
+///
+/// The disassembly is keyed by the contract name, and is formatted as the [`BytecodeAssembly`] text.
+/// Contracts without runtime code, e.g. interfaces, are omitted. Nothing is written to the file system.
+///
+/// # Errors
+/// If the output selection is not empty and does not request the runtime bytecode.
+///
+pub fn disassemble_source(
+    source: &str,
+    settings: &solx_standard_json::InputSettings,
+) -> anyhow::Result<BTreeMap<String, String>> {
+    if !settings.output_selection.is_empty()
+        && !settings
+            .output_selection
+            .is_set_for_any(solx_standard_json::InputSelector::RuntimeBytecodeObject)
+    {
+        anyhow::bail!("Disassembly requires the runtime bytecode in the output selection.");
+    }
+
+    let build = compile_solidity(source, settings)?;
+    let disassembly = build
+        .results
+        .into_values()
+        .flatten()
+        .filter_map(|contract| {
+            let assembly = contract.assembly()?;
+            let name = contract.name.name.unwrap_or(contract.name.full_path);
+            Some((name, assembly.to_string()))
+        })
+        .collect();
+    Ok(disassembly)
+}
+
+///
+/// Returns the files imported by the Solidity source at `source_path`, directly or transitively.
+///
+/// Only the `solc` parser is run, so the sources are neither analyzed nor compiled.
+/// The paths are the `solc` source unit names, joined with `base_path` if it is specified.
+/// They are sorted and do not include `source_path` itself.
+///
+/// # Errors
+/// If an import cannot be resolved. The error points at the import directive.
+///
+pub fn resolve_imports(
+    source_path: &Path,
+    base_path: Option<String>,
+    allow_paths: Option<String>,
+    remappings: BTreeSet<String>,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut solc_input = solx_standard_json::Input::try_from_solidity_paths(
+        &[source_path.to_owned()],
+        &[],
+        remappings,
+        solx_standard_json::InputOptimizer::default(),
+        None,
+        false,
+        solx_standard_json::InputSelection::default(),
+        solx_standard_json::InputMetadata::default(),
+        vec![],
+    )?;
+
+    let solc_compiler = solx_solc::Compiler::default();
+    let solc_output = solc_compiler.parse_standard_json(
+        &mut solc_input,
+        base_path.clone(),
+        vec![],
+        allow_paths,
+    )?;
+    solc_output.check_errors()?;
+
+    let source_name = source_path.to_string_lossy();
+    let imports = solc_output
+        .sources
+        .into_keys()
+        .filter(|path| path.as_str() != source_name)
+        .map(|path| match base_path {
+            Some(ref base_path) => Path::new(base_path).join(path),
+            None => PathBuf::from(path),
+        })
+        .collect();
+    Ok(imports)
+}
+
 ///
 /// Runs the standard JSON mode for the EVM target.
 ///
+/// Multiple inputs are compiled one by one, and their outputs are merged into a single one.
+/// Errors preventing an input from being compiled are reported in the merged output.
+///
 pub fn standard_json_evm(
-    json_path: Option<PathBuf>,
+    json_paths: Vec<PathBuf>,
     messages: &mut Vec<solx_standard_json::OutputError>,
     base_path: Option<String>,
     include_paths: Vec<String>,
     allow_paths: Option<String>,
+    strict_settings: bool,
+    pretty_json: bool,
+    options: CompileOptions,
+    build_options: EVMBuildOptions,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<()> {
+    if json_paths.len() <= 1 {
+        let solc_input = solx_standard_json::Input::try_from(
+            json_paths.first().map(PathBuf::as_path),
+            strict_settings,
+        )?;
+        let (solc_output, output_selection) = standard_json_evm_single(
+            solc_input,
+            messages,
+            base_path,
+            include_paths,
+            allow_paths,
+            options,
+            build_options,
+            debug_config,
+        )?;
+        solc_output.write_and_exit(&output_selection, pretty_json);
+    }
+
+    let mut merged_output = solx_standard_json::Output::new_with_messages(std::mem::take(messages));
+    merged_output.set_warning_codes(
+        build_options.escalation.clone(),
+        build_options.suppression.clone(),
+    );
+    for json_path in json_paths.into_iter() {
+        match solx_standard_json::Input::try_from(Some(json_path.as_path()), strict_settings)
+            .and_then(|solc_input| {
+                standard_json_evm_single(
+                    solc_input,
+                    &mut Vec::new(),
+                    base_path.clone(),
+                    include_paths.clone(),
+                    allow_paths.clone(),
+                    options.clone(),
+                    build_options.clone(),
+                    debug_config.clone(),
+                )
+            }) {
+            Ok((mut solc_output, output_selection)) => {
+                solc_output.prune(&output_selection);
+                merged_output.merge(solc_output);
+            }
+            Err(error) => merged_output
+                .errors
+                .push(solx_standard_json::OutputError::new_error(
+                    None,
+                    format!("Standard JSON input {json_path:?}: {error}"),
+                    None,
+                    None,
+                )),
+        }
+    }
+    merged_output.write_pruned_and_exit(pretty_json);
+}
+
+///
+/// Compiles a single standard JSON input for the EVM target.
+///
+/// Returns the unpruned output along with the output selection of the input.
+///
+fn standard_json_evm_single(
+    mut solc_input: solx_standard_json::Input,
+    messages: &mut Vec<solx_standard_json::OutputError>,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    mut options: CompileOptions,
+    mut build_options: EVMBuildOptions,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<(
+    solx_standard_json::Output,
+    solx_standard_json::InputSelection,
+)> {
     let solc_compiler = solx_solc::Compiler::default();
 
-    let mut solc_input = solx_standard_json::Input::try_from(json_path.as_deref())?;
     let language = solc_input.language;
     let via_ir = solc_input.settings.via_ir;
     let output_bytecode = solc_input
@@ -273,6 +761,7 @@ pub fn standard_json_evm(
             .output_selection
             .is_set_for_any(solx_standard_json::InputSelector::RuntimeBytecodeObject);
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
+    let source_hashes = EVMBuild::hash_sources(&solc_input.sources);
 
     let mut optimizer_settings = era_compiler_llvm_context::OptimizerSettings::try_from_cli(
         solc_input.settings.optimizer.mode,
@@ -280,6 +769,9 @@ pub fn standard_json_evm(
     if solc_input.settings.optimizer.size_fallback {
         optimizer_settings.enable_fallback_to_size();
     }
+    options.optimization_overrides =
+        std::mem::take(&mut solc_input.settings.optimizer.contract_modes);
+    options.evm_version = solc_input.settings.evm_version;
     let llvm_options = solc_input.settings.llvm_options.clone();
 
     let metadata_hash_type = solc_input.settings.metadata.bytecode_hash;
@@ -308,7 +800,7 @@ pub fn standard_json_evm(
         None
     };
 
-    let (mut solc_output, project) = match language {
+    let (mut solc_output, mut project) = match language {
         solx_standard_json::InputLanguage::Solidity => {
             let mut solc_output = solc_compiler.standard_json(
                 &mut solc_input,
@@ -317,8 +809,13 @@ pub fn standard_json_evm(
                 include_paths,
                 allow_paths,
             )?;
+            solc_output.set_warning_codes(
+                build_options.escalation.clone(),
+                build_options.suppression.clone(),
+            );
+            solc_output.check_unused_remappings(&solc_input.settings.remappings);
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                return Ok((solc_output, solc_input.settings.output_selection));
             }
 
             let project = Project::try_from_solc_output(
@@ -328,7 +825,7 @@ pub fn standard_json_evm(
                 debug_config.as_ref(),
             )?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                return Ok((solc_output, solc_input.settings.output_selection));
             }
 
             (solc_output, project)
@@ -336,25 +833,45 @@ pub fn standard_json_evm(
         solx_standard_json::InputLanguage::Yul => {
             let mut solc_output =
                 solc_compiler.validate_yul_standard_json(&mut solc_input, messages)?;
+            solc_output.set_warning_codes(
+                build_options.escalation.clone(),
+                build_options.suppression.clone(),
+            );
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                return Ok((solc_output, solc_input.settings.output_selection));
             }
 
             let project = Project::try_from_yul_sources(
                 solc_input.sources,
                 solc_input.settings.libraries,
                 &solc_input.settings.output_selection,
+                YulDialect::Era,
                 Some(&mut solc_output),
                 debug_config.as_ref(),
             )?;
+            solc_output.errors.extend(project.yul_arity_errors());
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                return Ok((solc_output, solc_input.settings.output_selection));
             }
+            solc_output.errors.extend(project.yul_shadowing_warnings());
+            solc_output
+                .errors
+                .extend(project.yul_switch_default_warnings());
+            solc_output
+                .errors
+                .extend(project.yul_dead_function_warnings());
+            solc_output
+                .errors
+                .extend(project.yul_empty_object_warnings());
 
             (solc_output, project)
         }
         solx_standard_json::InputLanguage::LLVMIR => {
             let mut solc_output = solx_standard_json::Output::new(&solc_input.sources, messages);
+            solc_output.set_warning_codes(
+                build_options.escalation.clone(),
+                build_options.suppression.clone(),
+            );
 
             let project = Project::try_from_llvm_ir_sources(
                 solc_input.sources,
@@ -363,14 +880,15 @@ pub fn standard_json_evm(
                 Some(&mut solc_output),
             )?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                return Ok((solc_output, solc_input.settings.output_selection));
             }
 
             (solc_output, project)
         }
     };
+    project.set_options(options)?;
 
-    let build = project.compile_to_evm(
+    let mut build = project.compile_to_evm(
         messages,
         output_bytecode,
         metadata_hash_type,
@@ -378,12 +896,29 @@ pub fn standard_json_evm(
         llvm_options,
         debug_config,
     )?;
+    build_options.strict_size_limit = solc_input.settings.strict_size_limit;
+    build_options.evm_version = solc_input.settings.evm_version;
+    build_options.compiler_version = solc_input.settings.metadata.compiler_version.clone();
+    build_options.output_selection = Some(solc_input.settings.output_selection.clone());
+    build.set_options(build_options);
+    build.set_source_hashes(source_hashes);
     if build.has_errors() {
         build.write_to_standard_json(&mut solc_output)?;
-        solc_output.write_and_exit(&solc_input.settings.output_selection);
+        return Ok((solc_output, solc_input.settings.output_selection));
     }
 
     let build = build.link(linker_symbols, cbor_data);
+    if solc_input.settings.output_timings {
+        solc_output.timings = Some(
+            build
+                .timings()
+                .into_iter()
+                .map(|(path, (deploy, runtime))| {
+                    (path, solx_standard_json::OutputTiming::new(deploy, runtime))
+                })
+                .collect(),
+        );
+    }
     build.write_to_standard_json(&mut solc_output)?;
-    solc_output.write_and_exit(&solc_input.settings.output_selection);
+    Ok((solc_output, solc_input.settings.output_selection))
 }
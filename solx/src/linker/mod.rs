@@ -4,6 +4,7 @@
 
 pub mod input;
 pub mod output;
+pub mod symbols;
 
 use std::collections::BTreeMap;
 
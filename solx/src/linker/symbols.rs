@@ -0,0 +1,98 @@
+//!
+//! The linker symbol file loader.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+///
+/// Loads the linker symbols from the file at `path`.
+///
+/// Two formats are supported:
+/// 1. Lines in format `<path>:<library>=<address>`, with empty lines and `#` comments skipped.
+/// 2. The `solc` standard JSON `libraries` object, e.g. `{"<path>": {"<library>": "<address>"}}`.
+///
+pub fn load(
+    path: &Path,
+) -> anyhow::Result<BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|error| anyhow::anyhow!("Linker symbol file {path:?} reading: {error}"))?;
+    parse(content.as_str())
+        .map_err(|error| anyhow::anyhow!("Linker symbol file {path:?} parsing: {error}"))
+}
+
+///
+/// Parses the linker symbols from `content` in either of the formats supported by [`load`].
+///
+pub fn parse(
+    content: &str,
+) -> anyhow::Result<BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>> {
+    if content.trim_start().starts_with('{') {
+        return parse_json(content);
+    }
+
+    let mut symbols = BTreeMap::new();
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (symbol, address) = line.split_once('=').ok_or_else(|| {
+            anyhow::anyhow!("line {line_number}: expected `<path>:<library>=<address>`")
+        })?;
+        let symbol = symbol.trim();
+        match symbol.rsplit_once(':') {
+            Some((path, library)) if !path.is_empty() && !library.is_empty() => {}
+            _ => anyhow::bail!("line {line_number}: expected `<path>:<library>`, found `{symbol}`"),
+        }
+        let address = parse_address(address.trim())
+            .map_err(|error| anyhow::anyhow!("line {line_number}: {error}"))?;
+        if symbols.insert(symbol.to_owned(), address).is_some() {
+            anyhow::bail!("line {line_number}: library `{symbol}` is specified more than once");
+        }
+    }
+    Ok(symbols)
+}
+
+///
+/// Parses the linker symbols from the `solc` standard JSON `libraries` object.
+///
+fn parse_json(
+    content: &str,
+) -> anyhow::Result<BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>> {
+    let libraries: BTreeMap<String, BTreeMap<String, String>> = serde_json::from_str(content)?;
+
+    let mut symbols = BTreeMap::new();
+    for (path, libraries) in libraries.into_iter() {
+        for (library, address) in libraries.into_iter() {
+            let symbol = format!("{path}:{library}");
+            let address = parse_address(address.as_str())
+                .map_err(|error| anyhow::anyhow!("library `{symbol}`: {error}"))?;
+            symbols.insert(symbol, address);
+        }
+    }
+    Ok(symbols)
+}
+
+///
+/// Parses a `0x`-prefixed hexadecimal address.
+///
+fn parse_address(
+    address: &str,
+) -> anyhow::Result<[u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]> {
+    let address_stripped = address
+        .strip_prefix("0x")
+        .ok_or_else(|| anyhow::anyhow!("address `{address}` must be prefixed with `0x`"))?;
+    if address_stripped.len() != era_compiler_common::BYTE_LENGTH_ETH_ADDRESS * 2 {
+        anyhow::bail!(
+            "address `{address}` must be {} bytes long",
+            era_compiler_common::BYTE_LENGTH_ETH_ADDRESS
+        );
+    }
+    let bytes = hex::decode(address_stripped).map_err(|error| {
+        anyhow::anyhow!("address `{address}` is not valid hexadecimal: {error}")
+    })?;
+    Ok(bytes.try_into().expect("Always valid"))
+}
@@ -28,6 +28,12 @@ pub struct Input {
     pub optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     /// The extra LLVM arguments.
     pub llvm_options: Vec<String>,
+    /// The EVM version the code generator targets.
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
+    /// Whether to emit the textual LLVM IR.
+    pub emit_llvm: bool,
+    /// The optimization passes to emit the textual LLVM IR after.
+    pub emit_llvm_after: Vec<String>,
     /// The debug output config.
     pub debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 }
@@ -44,6 +50,9 @@ impl Input {
         metadata_hash_type: era_compiler_common::EVMMetadataHashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        emit_llvm: bool,
+        emit_llvm_after: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> Self {
         Self {
@@ -54,6 +63,9 @@ impl Input {
             metadata_hash_type,
             optimizer_settings,
             llvm_options,
+            evm_version,
+            emit_llvm,
+            emit_llvm_after,
             debug_config,
         }
     }
@@ -5,11 +5,15 @@
 pub mod input;
 pub mod output;
 
+use std::io::Read;
 use std::io::Write;
 use std::path::PathBuf;
+use std::process::Child;
 use std::process::Command;
 use std::sync::OnceLock;
 use std::thread::Builder;
+use std::time::Duration;
+use std::time::Instant;
 
 use self::input::Input as EVMInput;
 use self::output::Output as EVMOutput;
@@ -17,6 +21,9 @@ use self::output::Output as EVMOutput;
 /// The overridden executable name used when the compiler is run as a library.
 pub static EXECUTABLE: OnceLock<PathBuf> = OnceLock::new();
 
+/// The interval between two checks of the subprocess status if the time limit is set.
+pub const TIMEOUT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 ///
 /// Read input from `stdin`, compile a contract, and write the output to `stdout`.
 ///
@@ -41,6 +48,9 @@ pub fn run() -> anyhow::Result<()> {
                     input.metadata_hash_type,
                     input.optimizer_settings,
                     input.llvm_options,
+                    input.evm_version,
+                    input.emit_llvm,
+                    input.emit_llvm_after,
                     input.debug_config,
                 )
                 .map(EVMOutput::new)
@@ -66,7 +76,12 @@ pub fn run() -> anyhow::Result<()> {
 ///
 /// Runs this process recursively to compile a single contract.
 ///
-pub fn call<I, O>(path: &str, input: I) -> crate::Result<O>
+/// Returns the output along with the subprocess `stderr`, which contains the diagnostics printed by LLVM.
+///
+/// If the subprocess does not finish within `timeout`, it is killed, and an error of the
+/// [`solx_standard_json::OutputError::TYPE_TIMEOUT`] type is returned.
+///
+pub fn call<I, O>(path: &str, input: I, timeout: Option<Duration>) -> crate::Result<(O, String)>
 where
     I: serde::Serialize,
     O: serde::de::DeserializeOwned,
@@ -96,9 +111,28 @@ where
         .write_all(stdin_input.as_slice())
         .unwrap_or_else(|error| panic!("{executable:?} subprocess stdin writing: {error:?}"));
 
-    let result = process
-        .wait_with_output()
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess output reading: {error:?}"));
+    let result = match timeout {
+        Some(timeout) => wait_with_timeout(process, timeout),
+        None => process.wait_with_output().map(Some),
+    }
+    .unwrap_or_else(|error| panic!("{executable:?} subprocess output reading: {error:?}"));
+    let result = match result {
+        Some(result) => result,
+        None => {
+            return Err(solx_standard_json::OutputError::new_error(
+                None,
+                format!(
+                    "Compilation has exceeded the time limit of {}s and has been aborted.",
+                    timeout.expect("Always exists").as_secs_f64()
+                ),
+                Some(solx_standard_json::OutputErrorSourceLocation::new(
+                    path.to_owned(),
+                )),
+                None,
+            )
+            .with_type(solx_standard_json::OutputError::TYPE_TIMEOUT));
+        }
+    };
 
     if result.status.code() != Some(era_compiler_common::EXIT_CODE_SUCCESS) {
         let message = format!(
@@ -121,7 +155,12 @@ where
     }
 
     match era_compiler_common::deserialize_from_slice(result.stdout.as_slice()) {
-        Ok(output) => output,
+        Ok(output) => output.map(|output| {
+            (
+                output,
+                String::from_utf8_lossy(result.stderr.as_slice()).to_string(),
+            )
+        }),
         Err(error) => {
             panic!(
                 "{executable:?} subprocess stdout parsing error: {error:?}\n{}\n{}",
@@ -131,3 +170,46 @@ where
         }
     }
 }
+
+///
+/// Waits for the subprocess to finish within `timeout`, collecting its output.
+///
+/// Returns `None` if the subprocess has been killed after the timeout.
+///
+fn wait_with_timeout(
+    mut process: Child,
+    timeout: Duration,
+) -> std::io::Result<Option<std::process::Output>> {
+    drop(process.stdin.take());
+
+    // The pipes are drained in the background, so the subprocess never blocks on a full pipe.
+    let mut stdout = process.stdout.take().expect("Always exists");
+    let stdout_reader = std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        stdout.read_to_end(&mut buffer).map(|_| buffer)
+    });
+    let mut stderr = process.stderr.take().expect("Always exists");
+    let stderr_reader = std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        stderr.read_to_end(&mut buffer).map(|_| buffer)
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = process.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            process.kill()?;
+            process.wait()?;
+            return Ok(None);
+        }
+        std::thread::sleep(TIMEOUT_POLL_INTERVAL);
+    };
+
+    Ok(Some(std::process::Output {
+        status,
+        stdout: stdout_reader.join().expect("Threading error")?,
+        stderr: stderr_reader.join().expect("Threading error")?,
+    }))
+}
@@ -26,6 +26,17 @@ pub enum IR {
 }
 
 impl IR {
+    ///
+    /// Returns the list of factory dependencies.
+    ///
+    pub fn factory_dependencies(&self) -> BTreeSet<String> {
+        match self {
+            IR::Yul(ref yul) => yul.object.0.factory_dependencies.iter().cloned().collect(),
+            IR::EVMLA(ref evm) => evm.assembly.factory_dependencies.iter().cloned().collect(),
+            IR::LLVMIR(_) => BTreeSet::new(),
+        }
+    }
+
     ///
     /// Drains the list of factory dependencies.
     ///
@@ -3,11 +3,19 @@
 //!
 
 use std::collections::BTreeSet;
+use std::collections::HashSet;
 
 use solx_yul::yul::lexer::Lexer;
+use solx_yul::yul::parser::statement::block::Block;
+use solx_yul::yul::parser::statement::code::Code;
 use solx_yul::yul::parser::statement::object::Object;
+use solx_yul::yul::visitor::arity::ArityChecker;
+use solx_yul::yul::visitor::dead_function::DeadFunctionChecker;
+use solx_yul::yul::visitor::shadowing::ShadowingChecker;
+use solx_yul::yul::visitor::switch_default::SwitchDefaultChecker;
 
 use crate::yul::parser::dialect::era::EraDialect;
+use crate::yul::parser::dialect::kind::Kind as YulDialect;
 use crate::yul::parser::wrapper::Wrap;
 
 ///
@@ -23,9 +31,13 @@ impl Yul {
     ///
     /// Transforms the `solc` standard JSON output contract into a Yul object.
     ///
+    /// The source code is parsed only once, and the resulting object is shared by the analyses
+    /// and the code generator.
+    ///
     pub fn try_from_source(
         path: &str,
         source_code: &str,
+        dialect: YulDialect,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Option<Self>> {
         if source_code.is_empty() {
@@ -36,13 +48,148 @@ impl Yul {
             debug_config.dump_yul(path, source_code)?;
         }
 
+        let object = Self::parse(source_code, dialect)?;
+        Ok(Some(Self::from(object)))
+    }
+
+    ///
+    /// Parses the Yul `source_code` into an owned syntax tree.
+    ///
+    /// The syntax tree is checked against the `dialect` after being parsed as Era Yul.
+    ///
+    pub fn parse(source_code: &str, dialect: YulDialect) -> anyhow::Result<Object<EraDialect>> {
         let mut lexer = Lexer::new(source_code.to_owned());
-        let object = Object::parse(&mut lexer, None)
-            .map_err(|error| anyhow::anyhow!("Yul parsing: {error:?}"))?;
+        let object = Object::parse(&mut lexer, None).map_err(|error| {
+            let message = format!("Yul parsing: {error:?}");
+            anyhow::Error::new(error).context(message)
+        })?;
+        dialect.check(&object).map_err(|error| {
+            let message = format!("Yul parsing: {error:?}");
+            anyhow::Error::new(error).context(message)
+        })?;
+        Ok(object)
+    }
 
-        Ok(Some(Self {
-            object: object.wrap(),
-        }))
+    ///
+    /// Returns the errors about user-defined function calls with a wrong number of arguments.
+    ///
+    pub fn arity_errors(&self, path: &str) -> Vec<solx_standard_json::OutputError> {
+        ArityChecker::check(&self.object.0)
+            .into_iter()
+            .map(|mismatch| {
+                solx_standard_json::OutputError::new_error(
+                    None,
+                    format!(
+                        "{} Function `{}` expects {} arguments, but {} were provided.",
+                        mismatch.location, mismatch.identifier, mismatch.expected, mismatch.found,
+                    ),
+                    Some(solx_standard_json::OutputErrorSourceLocation::new(
+                        path.to_owned(),
+                    )),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the warnings about variables shadowing the ones declared in enclosing scopes.
+    ///
+    pub fn shadowing_warnings(&self, path: &str) -> Vec<solx_standard_json::OutputError> {
+        Self::warnings(
+            path,
+            ShadowingChecker::check(&self.object.0)
+                .into_iter()
+                .map(|shadowing| {
+                    format!(
+                        "{} Variable `{}` shadows the variable declared at {}.",
+                        shadowing.location, shadowing.identifier, shadowing.shadowed_location,
+                    )
+                }),
+        )
+    }
+
+    ///
+    /// Returns the warnings about `switch` statements without a `default` case.
+    ///
+    pub fn switch_default_warnings(&self, path: &str) -> Vec<solx_standard_json::OutputError> {
+        Self::warnings(
+            path,
+            SwitchDefaultChecker::check(&self.object.0)
+                .into_iter()
+                .map(|location| {
+                    format!(
+                        "{location} The `switch` statement has no `default` case, so unmatched values are silently ignored.",
+                    )
+                }),
+        )
+    }
+
+    ///
+    /// Returns the warnings about user-defined functions that are never called.
+    ///
+    /// Such functions are still compiled, so they may bloat the bytecode if not removed by the optimizer.
+    ///
+    pub fn dead_function_warnings(&self, path: &str) -> Vec<solx_standard_json::OutputError> {
+        Self::warnings(
+            path,
+            DeadFunctionChecker::check(&self.object.0)
+                .into_iter()
+                .map(|function| {
+                    format!(
+                        "{} Function `{}` is never called.",
+                        function.location, function.identifier,
+                    )
+                }),
+        )
+    }
+
+    ///
+    /// Returns the warnings about objects with empty `code` blocks or without runtime code.
+    ///
+    /// Such objects are compiled to code that stops the execution immediately.
+    ///
+    pub fn empty_object_warnings(&self, path: &str) -> Vec<solx_standard_json::OutputError> {
+        let mut warnings = Vec::new();
+        let objects = std::iter::once(&self.object.0).chain(self.object.0.inner_object.as_deref());
+        for object in objects {
+            if object.code.block.statements.is_empty() {
+                warnings.push(format!(
+                    "{} Object `{}` is empty, so its code only stops the execution.",
+                    object.location, object.identifier,
+                ));
+            }
+        }
+        if self.object.0.inner_object.is_none() {
+            warnings.push(format!(
+                "{} Object `{}` has no runtime code object, so its runtime code is empty.",
+                self.object.0.location, self.object.0.identifier,
+            ));
+        }
+
+        Self::warnings(path, warnings)
+    }
+
+    ///
+    /// Converts the checker `messages` into warnings located in the source file at `path`.
+    ///
+    fn warnings(
+        path: &str,
+        messages: impl IntoIterator<Item = String>,
+    ) -> Vec<solx_standard_json::OutputError> {
+        messages
+            .into_iter()
+            .map(|message| {
+                solx_standard_json::OutputError::new_warning(
+                    None,
+                    message,
+                    Some(solx_standard_json::OutputErrorSourceLocation::new(
+                        path.to_owned(),
+                    )),
+                    None,
+                )
+            })
+            .collect()
     }
 
     ///
@@ -52,6 +199,27 @@ impl Yul {
         self.object.0.inner_object.take().map(|object| *object)
     }
 
+    ///
+    /// Creates an empty runtime code object for the Yul object without one.
+    ///
+    pub fn empty_runtime_code(&self) -> Object<EraDialect> {
+        let location = self.object.0.location;
+        Object {
+            location,
+            identifier: format!("{}_deployed", self.object.0.identifier),
+            code: Code {
+                location,
+                block: Block {
+                    location,
+                    statements: vec![],
+                    comments: None,
+                },
+            },
+            inner_object: None,
+            factory_dependencies: HashSet::new(),
+        }
+    }
+
     ///
     /// Get the list of unlinked deployable libraries.
     ///
@@ -70,6 +238,14 @@ impl Yul {
     }
 }
 
+impl From<Object<EraDialect>> for Yul {
+    fn from(object: Object<EraDialect>) -> Self {
+        Self {
+            object: object.wrap(),
+        }
+    }
+}
+
 impl era_compiler_llvm_context::EVMWriteLLVM for Yul {
     fn declare(
         &mut self,
@@ -19,6 +19,13 @@ pub struct Metadata<'a> {
     pub optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     /// The LLVM extra arguments.
     pub llvm_options: &'a [String],
+    /// Whether all optional LLVM passes are skipped. Only serialized if set,
+    /// so the metadata of optimized builds is not affected.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub no_optimize: bool,
+    /// The EVM version the code generator targets. Only serialized if set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<String>,
 }
 
 impl<'a> Metadata<'a> {
@@ -28,6 +35,7 @@ impl<'a> Metadata<'a> {
     pub fn new(
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: &'a [String],
+        evm_version: Option<era_compiler_common::EVMVersion>,
     ) -> Self {
         let solc_version = solx_solc::Compiler::default().version;
         Self {
@@ -36,6 +44,10 @@ impl<'a> Metadata<'a> {
             solx_version: crate::version().parse().expect("Always valid"),
             optimizer_settings,
             llvm_options,
+            no_optimize: llvm_options
+                .iter()
+                .any(|option| option == crate::r#const::LLVM_OPTION_NO_OPTIMIZE),
+            evm_version: evm_version.map(|evm_version| evm_version.to_string()),
         }
     }
 
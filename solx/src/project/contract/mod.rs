@@ -7,10 +7,14 @@ pub mod metadata;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::time::Duration;
+use std::time::Instant;
 
 use era_compiler_llvm_context::IContext;
 
+use crate::build::contract::llvm_ir_snapshot::Snapshot as LLVMIRSnapshot;
 use crate::build::contract::object::Object as EVMContractObject;
+use crate::build::contract::optimization::Optimization as EVMContractOptimization;
 use crate::build::contract::Contract as EVMContractBuild;
 use crate::yul::parser::wrapper::Wrap;
 
@@ -29,6 +33,10 @@ pub struct Contract {
     pub ir: IR,
     /// The original `solc` metadata.
     pub metadata: Option<String>,
+    /// The `solc` ABI JSON.
+    /// Not passed to the recursive process, as it is not needed for compilation.
+    #[serde(skip)]
+    pub abi: Option<serde_json::Value>,
 }
 
 impl Contract {
@@ -36,7 +44,12 @@ impl Contract {
     /// A shortcut constructor.
     ///
     pub fn new(name: era_compiler_common::ContractName, ir: IR, metadata: Option<String>) -> Self {
-        Self { name, ir, metadata }
+        Self {
+            name,
+            ir,
+            metadata,
+            abi: None,
+        }
     }
 
     ///
@@ -67,19 +80,24 @@ impl Contract {
         metadata_hash_type: era_compiler_common::EVMMetadataHashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        emit_llvm: bool,
+        emit_llvm_after: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<EVMContractBuild> {
         use era_compiler_llvm_context::EVMWriteLLVM;
 
         let solc_version = solx_solc::Compiler::default().version;
 
-        let identifier = self.identifier().to_owned();
-
         let optimizer = era_compiler_llvm_context::Optimizer::new(optimizer_settings);
 
         let metadata = self.metadata.map(|metadata| {
-            Metadata::new(optimizer.settings().to_owned(), llvm_options.as_slice())
-                .insert_into(metadata.as_str())
+            Metadata::new(
+                optimizer.settings().to_owned(),
+                llvm_options.as_slice(),
+                evm_version,
+            )
+            .insert_into(metadata.as_str())
         });
         let metadata_bytes = metadata
             .as_ref()
@@ -91,17 +109,30 @@ impl Contract {
             });
 
         if !output_bytecode {
-            return Ok(EVMContractBuild::new(self.name, None, None, metadata));
+            return Ok(EVMContractBuild::new(
+                self.name,
+                None,
+                None,
+                metadata,
+                (Duration::ZERO, Duration::ZERO),
+            ));
+        }
+
+        // The IR printing options are not a part of the metadata, as they do not affect the bytecode.
+        let mut llvm_options = llvm_options;
+        if !emit_llvm_after.is_empty() {
+            llvm_options.extend(LLVMIRSnapshot::llvm_options(emit_llvm_after.as_slice()));
         }
 
+        let optimization = EVMContractOptimization::new(optimizer.settings());
         let deploy_code_segment = era_compiler_common::CodeSegment::Deploy;
         let runtime_code_segment = era_compiler_common::CodeSegment::Runtime;
 
-        match self.ir {
+        let mut build = match self.ir {
             IR::Yul(mut deploy_code) => {
-                let runtime_code = deploy_code.take_runtime_code().ok_or_else(|| {
-                    anyhow::anyhow!("Contract `{identifier}` has no runtime code")
-                })?;
+                let runtime_code = deploy_code
+                    .take_runtime_code()
+                    .unwrap_or_else(|| deploy_code.empty_runtime_code());
 
                 let mut deploy_code_libraries = deploy_code.get_unlinked_libraries();
                 deploy_code_libraries.retain(|library| !deployed_libraries.contains(library));
@@ -115,6 +146,7 @@ impl Contract {
                 let deploy_code_identifier = deploy_code.object.0.identifier.clone();
                 let runtime_code_identifier = runtime_code.0.identifier.clone();
 
+                let runtime_start = Instant::now();
                 let runtime_llvm = inkwell::context::Context::create();
                 let runtime_module = runtime_llvm.create_module(
                     format!("{}.{runtime_code_segment}", self.name.full_path).as_str(),
@@ -127,6 +159,7 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
+                runtime_context.set_evm_version(evm_version);
                 runtime_context.set_yul_data(era_compiler_llvm_context::EVMContextYulData::new(
                     identifier_paths.clone(),
                 ));
@@ -136,8 +169,11 @@ impl Contract {
                     .map_err(|error| {
                         anyhow::anyhow!("{runtime_code_segment} code LLVM IR generator: {error}")
                     })?;
+                let runtime_llvm_ir =
+                    emit_llvm.then(|| runtime_context.module().print_to_string().to_string());
                 let (runtime_buffer, runtime_code_warnings) = runtime_context.build()?;
-                let runtime_object = EVMContractObject::new(
+                let runtime_time = runtime_start.elapsed();
+                let mut runtime_object = EVMContractObject::new(
                     runtime_code_identifier,
                     self.name.clone(),
                     runtime_buffer.as_slice().to_owned(),
@@ -149,9 +185,12 @@ impl Contract {
                     era_compiler_common::ObjectFormat::ELF,
                     runtime_code_warnings,
                 );
+                runtime_object.llvm_ir = runtime_llvm_ir;
 
                 let immutables_map = runtime_buffer.get_immutables_evm();
+                let immutable_references = immutables_map.clone();
 
+                let deploy_start = Instant::now();
                 let deploy_llvm = inkwell::context::Context::create();
                 let deploy_module = deploy_llvm.create_module(self.name.full_path.as_str());
                 let mut deploy_context = era_compiler_llvm_context::EVMContext::new(
@@ -162,6 +201,7 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
+                deploy_context.set_evm_version(evm_version);
                 deploy_context.set_solidity_data(
                     era_compiler_llvm_context::EVMContextSolidityData::new(immutables_map),
                 );
@@ -174,8 +214,11 @@ impl Contract {
                     .map_err(|error| {
                         anyhow::anyhow!("{deploy_code_segment} code LLVM IR generator: {error}")
                     })?;
+                let deploy_llvm_ir =
+                    emit_llvm.then(|| deploy_context.module().print_to_string().to_string());
                 let (deploy_buffer, deploy_code_warnings) = deploy_context.build()?;
-                let deploy_object = EVMContractObject::new(
+                let deploy_time = deploy_start.elapsed();
+                let mut deploy_object = EVMContractObject::new(
                     deploy_code_identifier,
                     self.name.clone(),
                     deploy_buffer.as_slice().to_owned(),
@@ -187,13 +230,17 @@ impl Contract {
                     era_compiler_common::ObjectFormat::ELF,
                     deploy_code_warnings,
                 );
+                deploy_object.llvm_ir = deploy_llvm_ir;
 
-                Ok(EVMContractBuild::new(
+                let mut build = EVMContractBuild::new(
                     self.name,
                     Some(deploy_object),
                     Some(runtime_object),
                     metadata,
-                ))
+                    (deploy_time, runtime_time),
+                );
+                build.immutable_references = immutable_references;
+                Ok(build)
             }
             IR::EVMLA(mut deploy_code) => {
                 let mut runtime_code_assembly = deploy_code.assembly.runtime_code()?.to_owned();
@@ -218,6 +265,7 @@ impl Contract {
                 let evmla_data =
                     era_compiler_llvm_context::EVMContextEVMLAData::new(solc_version.default);
 
+                let runtime_start = Instant::now();
                 let runtime_llvm = inkwell::context::Context::create();
                 let runtime_module = runtime_llvm.create_module(runtime_code_identifier.as_str());
                 let mut runtime_context = era_compiler_llvm_context::EVMContext::new(
@@ -228,6 +276,7 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
+                runtime_context.set_evm_version(evm_version);
                 runtime_context.set_evmla_data(evmla_data.clone());
                 runtime_code_assembly.declare(&mut runtime_context)?;
                 runtime_code_assembly
@@ -235,8 +284,11 @@ impl Contract {
                     .map_err(|error| {
                         anyhow::anyhow!("{runtime_code_segment} code LLVM IR generator: {error}")
                     })?;
+                let runtime_llvm_ir =
+                    emit_llvm.then(|| runtime_context.module().print_to_string().to_string());
                 let (runtime_buffer, runtime_code_warnings) = runtime_context.build()?;
-                let runtime_object = EVMContractObject::new(
+                let runtime_time = runtime_start.elapsed();
+                let mut runtime_object = EVMContractObject::new(
                     runtime_code_identifier,
                     self.name.clone(),
                     runtime_buffer.as_slice().to_owned(),
@@ -248,9 +300,12 @@ impl Contract {
                     era_compiler_common::ObjectFormat::ELF,
                     runtime_code_warnings,
                 );
+                runtime_object.llvm_ir = runtime_llvm_ir;
 
                 let immutables_map = runtime_buffer.get_immutables_evm();
+                let immutable_references = immutables_map.clone();
 
+                let deploy_start = Instant::now();
                 let deploy_llvm = inkwell::context::Context::create();
                 let deploy_module = deploy_llvm.create_module(deploy_code_identifier.as_str());
                 let mut deploy_context = era_compiler_llvm_context::EVMContext::new(
@@ -261,6 +316,7 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
+                deploy_context.set_evm_version(evm_version);
                 deploy_context.set_solidity_data(
                     era_compiler_llvm_context::EVMContextSolidityData::new(immutables_map),
                 );
@@ -271,8 +327,11 @@ impl Contract {
                     .map_err(|error| {
                         anyhow::anyhow!("{deploy_code_segment} code LLVM IR generator: {error}")
                     })?;
+                let deploy_llvm_ir =
+                    emit_llvm.then(|| deploy_context.module().print_to_string().to_string());
                 let (deploy_buffer, deploy_code_warnings) = deploy_context.build()?;
-                let deploy_object = EVMContractObject::new(
+                let deploy_time = deploy_start.elapsed();
+                let mut deploy_object = EVMContractObject::new(
                     deploy_code_identifier,
                     self.name.clone(),
                     deploy_buffer.as_slice().to_owned(),
@@ -284,13 +343,17 @@ impl Contract {
                     era_compiler_common::ObjectFormat::ELF,
                     deploy_code_warnings,
                 );
+                deploy_object.llvm_ir = deploy_llvm_ir;
 
-                Ok(EVMContractBuild::new(
+                let mut build = EVMContractBuild::new(
                     self.name,
                     Some(deploy_object),
                     Some(runtime_object),
                     metadata,
-                ))
+                    (deploy_time, runtime_time),
+                );
+                build.immutable_references = immutable_references;
+                Ok(build)
             }
             IR::LLVMIR(mut runtime_llvm_ir) => {
                 let deploy_code_identifier = self.name.full_path.to_owned();
@@ -326,11 +389,12 @@ impl Contract {
                 let runtime_code_dependencies =
                     solx_yul::Dependencies::new(runtime_code_identifier.as_str());
 
+                let runtime_start = Instant::now();
                 let runtime_llvm = inkwell::context::Context::create();
                 let runtime_module = runtime_llvm
                     .create_module_from_ir(runtime_memory_buffer)
                     .map_err(|error| anyhow::anyhow!(error.to_string()))?;
-                let runtime_context = era_compiler_llvm_context::EVMContext::new(
+                let mut runtime_context = era_compiler_llvm_context::EVMContext::new(
                     &runtime_llvm,
                     runtime_module,
                     llvm_options.clone(),
@@ -338,8 +402,12 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
+                runtime_context.set_evm_version(evm_version);
+                let runtime_llvm_ir =
+                    emit_llvm.then(|| runtime_context.module().print_to_string().to_string());
                 let (runtime_buffer, runtime_code_warnings) = runtime_context.build()?;
-                let runtime_object = EVMContractObject::new(
+                let runtime_time = runtime_start.elapsed();
+                let mut runtime_object = EVMContractObject::new(
                     runtime_code_identifier,
                     self.name.clone(),
                     runtime_buffer.as_slice().to_owned(),
@@ -351,12 +419,14 @@ impl Contract {
                     era_compiler_common::ObjectFormat::ELF,
                     runtime_code_warnings,
                 );
+                runtime_object.llvm_ir = runtime_llvm_ir;
 
+                let deploy_start = Instant::now();
                 let deploy_llvm = inkwell::context::Context::create();
                 let deploy_module = deploy_llvm
                     .create_module_from_ir(deploy_memory_buffer)
                     .map_err(|error| anyhow::anyhow!(error.to_string()))?;
-                let deploy_context = era_compiler_llvm_context::EVMContext::new(
+                let mut deploy_context = era_compiler_llvm_context::EVMContext::new(
                     &deploy_llvm,
                     deploy_module,
                     llvm_options,
@@ -364,8 +434,12 @@ impl Contract {
                     optimizer,
                     debug_config,
                 );
+                deploy_context.set_evm_version(evm_version);
+                let deploy_llvm_ir =
+                    emit_llvm.then(|| deploy_context.module().print_to_string().to_string());
                 let (deploy_buffer, deploy_code_warnings) = deploy_context.build()?;
-                let deploy_object = EVMContractObject::new(
+                let deploy_time = deploy_start.elapsed();
+                let mut deploy_object = EVMContractObject::new(
                     deploy_code_identifier,
                     self.name.clone(),
                     deploy_buffer.as_slice().to_owned(),
@@ -377,15 +451,19 @@ impl Contract {
                     era_compiler_common::ObjectFormat::ELF,
                     deploy_code_warnings,
                 );
+                deploy_object.llvm_ir = deploy_llvm_ir;
 
                 Ok(EVMContractBuild::new(
                     self.name,
                     Some(deploy_object),
                     Some(runtime_object),
                     metadata,
+                    (deploy_time, runtime_time),
                 ))
             }
-        }
+        }?;
+        build.optimization = Some(optimization);
+        Ok(build)
     }
 
     ///
@@ -3,24 +3,31 @@
 //!
 
 pub mod contract;
+pub mod options;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
+use crate::build::contract::analysis::Analysis as BytecodeAnalysis;
+use crate::build::contract::llvm_ir_snapshot::Snapshot as LLVMIRSnapshot;
 use crate::build::contract::Contract as EVMContractBuild;
 use crate::build::Build as EVMBuild;
+use crate::cache::Cache;
 use crate::evmla::assembly::Assembly;
 use crate::process::input::Input as EVMProcessInput;
 use crate::process::output::Output as EVMOutput;
+use crate::yul::parser::dialect::kind::Kind as YulDialect;
 
 use self::contract::ir::evmla::EVMLA as ContractEVMLA;
 use self::contract::ir::llvm_ir::LLVMIR as ContractLLVMIR;
 use self::contract::ir::yul::Yul as ContractYul;
 use self::contract::ir::IR as ContractIR;
 use self::contract::Contract;
+use self::options::Options;
 
 ///
 /// The project representation.
@@ -37,6 +44,12 @@ pub struct Project {
     pub identifier_paths: BTreeMap<String, String>,
     /// The library addresses.
     pub libraries: era_compiler_common::Libraries,
+    /// The compilation options.
+    #[serde(default)]
+    pub options: Options,
+    /// The incremental compilation cache.
+    #[serde(skip)]
+    pub cache: Option<Cache>,
 }
 
 impl Project {
@@ -59,6 +72,8 @@ impl Project {
             contracts,
             identifier_paths,
             libraries,
+            options: Options::default(),
+            cache: None,
         }
     }
 
@@ -93,6 +108,7 @@ impl Project {
                     ContractYul::try_from_source(
                         name.full_path.as_str(),
                         contract.ir_optimized.as_str(),
+                        YulDialect::Era,
                         debug_config,
                     )
                     .map(|yul| yul.map(ContractIR::from))
@@ -103,7 +119,9 @@ impl Project {
                     Ok(ir) => ir?,
                     Err(error) => return Some((name.full_path, Err(error))),
                 };
-                let contract = Contract::new(name.clone(), ir, contract.metadata.clone());
+                let abi = Some(contract.abi.clone()).filter(|abi| !abi.is_null());
+                let mut contract = Contract::new(name.clone(), ir, contract.metadata.clone());
+                contract.abi = abi;
                 Some((name.full_path, Ok(contract)))
             })
             .collect::<BTreeMap<String, anyhow::Result<Contract>>>();
@@ -131,6 +149,7 @@ impl Project {
         paths: &[PathBuf],
         libraries: era_compiler_common::Libraries,
         output_selection: &solx_standard_json::InputSelection,
+        dialect: YulDialect,
         solc_output: Option<&mut solx_standard_json::Output>,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Self> {
@@ -145,6 +164,7 @@ impl Project {
             sources,
             libraries,
             output_selection,
+            dialect,
             solc_output,
             debug_config,
         )
@@ -157,6 +177,7 @@ impl Project {
         sources: BTreeMap<String, solx_standard_json::InputSource>,
         libraries: era_compiler_common::Libraries,
         output_selection: &solx_standard_json::InputSelection,
+        dialect: YulDialect,
         mut solc_output: Option<&mut solx_standard_json::Output>,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Self> {
@@ -170,6 +191,7 @@ impl Project {
                 let ir = match ContractYul::try_from_source(
                     path.as_str(),
                     source_code.as_str(),
+                    dialect,
                     debug_config,
                 ) {
                     Ok(ir) => ir?,
@@ -302,6 +324,235 @@ impl Project {
         ))
     }
 
+    ///
+    /// Reads the textual EVM assembly `paths` in the `solc --asm` format and returns an EVM assembly project.
+    ///
+    pub fn try_from_assembly_paths(
+        paths: &[PathBuf],
+        libraries: era_compiler_common::Libraries,
+        output_selection: &solx_standard_json::InputSelection,
+    ) -> anyhow::Result<Self> {
+        let mut assemblies = BTreeMap::new();
+        let mut source_hashes = BTreeMap::new();
+        for path in paths.iter() {
+            let source_code = std::fs::read_to_string(path)
+                .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+            let source_hash =
+                era_compiler_common::Keccak256Hash::from_slice(source_code.as_bytes()).to_string();
+            let sections = crate::evmla::assembly::text::parse(
+                path.to_string_lossy().as_ref(),
+                source_code.as_str(),
+            )
+            .map_err(|error| anyhow::anyhow!("File {path:?} parsing: {error}"))?;
+
+            for section in sections.into_iter() {
+                let contract = solx_standard_json::OutputContract {
+                    evm: Some(solx_standard_json::OutputContractEVM {
+                        legacy_assembly: serde_json::to_value(&section.assembly)
+                            .expect("Always valid"),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                };
+                source_hashes.insert(
+                    format!("{}:{}", section.path, section.name),
+                    source_hash.clone(),
+                );
+                assemblies
+                    .entry(section.path)
+                    .or_insert_with(BTreeMap::new)
+                    .insert(section.name, contract);
+            }
+        }
+        Assembly::preprocess_dependencies(&mut assemblies)?;
+
+        let mut contracts = BTreeMap::new();
+        for (path, file) in assemblies.iter() {
+            for (name, contract) in file.iter() {
+                let name =
+                    era_compiler_common::ContractName::new(path.to_owned(), Some(name.to_owned()));
+                let ir = ContractEVMLA::try_from_contract(contract).expect("Always exists");
+
+                let metadata = if output_selection.check_selection(
+                    path.as_str(),
+                    name.name.as_deref(),
+                    solx_standard_json::InputSelector::Metadata,
+                ) {
+                    let metadata_json = serde_json::json!({
+                        "source_hash": source_hashes.get(name.full_path.as_str()).expect("Always exists"),
+                        "llvm_version": era_compiler_llvm_context::LLVM_VERSION,
+                    });
+                    Some(serde_json::to_string(&metadata_json).expect("Always valid"))
+                } else {
+                    None
+                };
+
+                contracts.insert(
+                    name.full_path.clone(),
+                    Contract::new(name, ir.into(), metadata),
+                );
+            }
+        }
+        Ok(Self::new(
+            solx_standard_json::InputLanguage::Solidity,
+            contracts,
+            libraries,
+        ))
+    }
+
+    ///
+    /// Returns the errors about Yul user-defined function calls with a wrong number of arguments.
+    ///
+    pub fn yul_arity_errors(&self) -> Vec<solx_standard_json::OutputError> {
+        self.yul_messages(ContractYul::arity_errors)
+    }
+
+    ///
+    /// Returns the warnings about Yul variables shadowing the ones declared in enclosing scopes.
+    ///
+    pub fn yul_shadowing_warnings(&self) -> Vec<solx_standard_json::OutputError> {
+        self.yul_messages(ContractYul::shadowing_warnings)
+    }
+
+    ///
+    /// Returns the warnings about Yul `switch` statements without a `default` case.
+    ///
+    pub fn yul_switch_default_warnings(&self) -> Vec<solx_standard_json::OutputError> {
+        self.yul_messages(ContractYul::switch_default_warnings)
+    }
+
+    ///
+    /// Returns the warnings about Yul user-defined functions that are never called.
+    ///
+    pub fn yul_dead_function_warnings(&self) -> Vec<solx_standard_json::OutputError> {
+        self.yul_messages(ContractYul::dead_function_warnings)
+    }
+
+    ///
+    /// Returns the warnings about empty Yul objects.
+    ///
+    pub fn yul_empty_object_warnings(&self) -> Vec<solx_standard_json::OutputError> {
+        self.yul_messages(ContractYul::empty_object_warnings)
+    }
+
+    ///
+    /// Collects the `check` messages of all Yul contracts, located in their source files.
+    ///
+    fn yul_messages(
+        &self,
+        check: impl Fn(&ContractYul, &str) -> Vec<solx_standard_json::OutputError>,
+    ) -> Vec<solx_standard_json::OutputError> {
+        self.contracts
+            .values()
+            .flat_map(|contract| match contract.ir {
+                ContractIR::Yul(ref yul) => check(yul, contract.name.path.as_str()),
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    ///
+    /// Sets the compilation options.
+    ///
+    /// If the target is specified, only the target contract and its dependencies are retained.
+    /// If the cache directory is specified, the contracts whose code generation input is found
+    /// in the cache are not compiled, and the freshly compiled ones are stored in it.
+    ///
+    /// # Errors
+    /// If an optimization mode override is invalid, the EVM version is not supported,
+    /// or the target contract is not found.
+    ///
+    pub fn set_options(&mut self, options: Options) -> anyhow::Result<()> {
+        for (pattern, mode) in options.optimization_overrides.iter() {
+            era_compiler_llvm_context::OptimizerSettings::try_from_cli(*mode).map_err(|error| {
+                anyhow::anyhow!("Optimization mode override for `{pattern}`: {error}")
+            })?;
+        }
+        if let Some(evm_version) = options.evm_version {
+            let evm_version = evm_version.to_string();
+            if !BytecodeAnalysis::EVM_VERSIONS.contains(&evm_version.as_str()) {
+                anyhow::bail!(
+                    "EVM version `{evm_version}` is not supported. Supported versions: {}.",
+                    BytecodeAnalysis::EVM_VERSIONS
+                        .iter()
+                        .map(|version| format!("`{version}`"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                );
+            }
+        }
+        if let Some(target) = options.target.as_deref() {
+            self.retain_target(target)?;
+        }
+        self.cache = options.cache_directory.clone().map(Cache::new);
+        self.options = options;
+        Ok(())
+    }
+
+    ///
+    /// Retains only the `target` contract and the contracts it deploys, directly or transitively.
+    ///
+    /// The deployed contracts are kept, as they are required to assemble the target bytecode.
+    ///
+    /// # Errors
+    /// If there is no contract with the `target` full path.
+    ///
+    pub fn retain_target(&mut self, target: &str) -> anyhow::Result<()> {
+        if !self.contracts.contains_key(target) {
+            anyhow::bail!(
+                "Target contract `{target}` not found. Available contracts: {}.",
+                self.contracts
+                    .keys()
+                    .map(|path| format!("`{path}`"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+        }
+
+        let mut required = BTreeSet::new();
+        let mut queue = vec![target.to_owned()];
+        while let Some(path) = queue.pop() {
+            let contract = match self.contracts.get(path.as_str()) {
+                Some(contract) => contract,
+                None => continue,
+            };
+            if !required.insert(path) {
+                continue;
+            }
+            queue.extend(
+                contract
+                    .ir
+                    .factory_dependencies()
+                    .into_iter()
+                    .map(|dependency| {
+                        self.identifier_paths
+                            .get(dependency.as_str())
+                            .cloned()
+                            .unwrap_or(dependency)
+                    }),
+            );
+        }
+        self.contracts.retain(|path, _| required.contains(path));
+        Ok(())
+    }
+
+    ///
+    /// Returns the optimization mode override for the contract `name`, if any.
+    ///
+    fn optimization_override(
+        optimization_overrides: &BTreeMap<String, char>,
+        name: &era_compiler_common::ContractName,
+    ) -> Option<char> {
+        [
+            Some(name.full_path.as_str()),
+            name.name.as_deref(),
+            Some(name.path.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(|pattern| optimization_overrides.get(pattern).copied())
+    }
+
     ///
     /// Compiles all contracts to EVM, returning their build artifacts.
     ///
@@ -315,27 +566,138 @@ impl Project {
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<EVMBuild> {
         let deployed_libraries = self.libraries.as_paths();
-        let results = self
+        let contracts = self
             .contracts
             .into_par_iter()
             .map(|(path, contract)| {
+                let optimization_mode = Self::optimization_override(
+                    &self.options.optimization_overrides,
+                    &contract.name,
+                );
+                let optimizer_settings = match optimization_mode {
+                    Some(mode) => {
+                        let mut settings =
+                            era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)
+                                .expect("Always valid");
+                        if optimizer_settings.is_fallback_to_size_enabled() {
+                            settings.enable_fallback_to_size();
+                        }
+                        settings.is_verify_each_enabled = optimizer_settings.is_verify_each_enabled;
+                        settings.is_debug_logging_enabled =
+                            optimizer_settings.is_debug_logging_enabled;
+                        settings
+                    }
+                    None => optimizer_settings.clone(),
+                };
+
+                let size_input = (self.options.size_advisory
+                    && optimizer_settings != era_compiler_llvm_context::OptimizerSettings::size())
+                .then(|| {
+                    EVMProcessInput::new(
+                        contract.clone(),
+                        self.identifier_paths.clone(),
+                        output_bytecode,
+                        deployed_libraries.clone(),
+                        metadata_hash_type,
+                        era_compiler_llvm_context::OptimizerSettings::size(),
+                        llvm_options.clone(),
+                        self.options.evm_version,
+                        false,
+                        vec![],
+                        None,
+                    )
+                });
+
+                let abi = contract.abi.clone();
                 let input = EVMProcessInput::new(
                     contract,
                     self.identifier_paths.clone(),
                     output_bytecode,
                     deployed_libraries.clone(),
                     metadata_hash_type,
-                    optimizer_settings.clone(),
+                    optimizer_settings,
                     llvm_options.clone(),
+                    self.options.evm_version,
+                    self.options.emit_llvm,
+                    self.options.emit_llvm_after.clone(),
                     debug_config.clone(),
                 );
-                let result: crate::Result<EVMOutput> = crate::process::call(path.as_str(), input);
-                let result = result.map(|output| output.build);
-                (path, result)
+                let cache_key = self
+                    .cache
+                    .as_ref()
+                    .filter(|_| input.debug_config.is_none())
+                    .map(|_| Cache::key(&input));
+                let cached = self
+                    .cache
+                    .as_ref()
+                    .zip(cache_key.as_deref())
+                    .and_then(|(cache, key)| cache.load(key));
+                let mut cache_warning = None;
+                let result = match cached {
+                    Some(build) => Ok(build),
+                    None => {
+                        let result: crate::Result<(EVMOutput, String)> =
+                            crate::process::call(path.as_str(), input, self.options.timeout);
+                        result.map(|(mut output, stderr)| {
+                            if !self.options.emit_llvm_after.is_empty() {
+                                output.build.llvm_ir_snapshots =
+                                    LLVMIRSnapshot::parse_dump(stderr.as_str());
+                            }
+                            if let Some((cache, key)) =
+                                self.cache.as_ref().zip(cache_key.as_deref())
+                            {
+                                // The cache is an optimization, so failing to store an entry is not fatal.
+                                if let Err(error) = cache.store(key, &output.build) {
+                                    cache_warning = Some(solx_standard_json::OutputError::new_warning(
+                                        None,
+                                        format!(
+                                            "Contract `{path}` could not be stored in the cache directory {:?}, so it will be compiled anew on the next run: {error}",
+                                            cache.directory(),
+                                        ),
+                                        None,
+                                        None,
+                                    ));
+                                }
+                            }
+                            output.build
+                        })
+                    }
+                };
+                let size_build = size_input.and_then(|size_input| {
+                    let size_result: crate::Result<(EVMOutput, String)> =
+                        crate::process::call(path.as_str(), size_input, self.options.timeout);
+                    size_result
+                        .ok()
+                        .map(|(size_output, _stderr)| size_output.build)
+                });
+                let result = result.map(|mut build| {
+                    build.optimization_mode = optimization_mode;
+                    build.abi = abi;
+                    build
+                });
+                (path, result, size_build, cache_warning)
             })
-            .collect::<BTreeMap<String, Result<EVMContractBuild, solx_standard_json::OutputError>>>(
-            );
+            .collect::<Vec<(
+                String,
+                Result<EVMContractBuild, solx_standard_json::OutputError>,
+                Option<EVMContractBuild>,
+                Option<solx_standard_json::OutputError>,
+            )>>();
 
-        Ok(EVMBuild::new(results, messages))
+        let mut results = BTreeMap::new();
+        let mut size_results = BTreeMap::new();
+        for (path, result, size_build, cache_warning) in contracts.into_iter() {
+            messages.extend(cache_warning);
+            if self.options.size_advisory {
+                // The contracts already optimized for size are assembled as dependencies as is.
+                if let Some(size_build) = size_build.or_else(|| result.as_ref().ok().cloned()) {
+                    size_results.insert(path.clone(), size_build);
+                }
+            }
+            results.insert(path, result);
+        }
+        let mut build = EVMBuild::new(results, messages);
+        build.size_results = size_results;
+        Ok(build)
     }
 }
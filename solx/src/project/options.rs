@@ -0,0 +1,44 @@
+//!
+//! The project compilation options.
+//!
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+///
+/// The project compilation options.
+///
+/// Groups the code generation options which are not part of the `solc` input, so they are passed
+/// to the compilation entry points and the project at once.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Options {
+    /// The per-contract optimization mode overrides.
+    #[serde(default)]
+    pub optimization_overrides: BTreeMap<String, char>,
+    /// The full path of the only contract to compile, along with the contracts it deploys.
+    #[serde(default)]
+    pub target: Option<String>,
+    /// The incremental compilation cache directory.
+    #[serde(default)]
+    pub cache_directory: Option<PathBuf>,
+    /// Whether to emit the textual LLVM IR of each contract.
+    #[serde(default)]
+    pub emit_llvm: bool,
+    /// The optimization passes to emit the textual LLVM IR after.
+    #[serde(default)]
+    pub emit_llvm_after: Vec<String>,
+    /// The time limit of the code generation of each contract.
+    #[serde(default)]
+    pub timeout: Option<Duration>,
+    /// Whether to compare the contract sizes with the size optimization mode.
+    #[serde(default)]
+    pub size_advisory: bool,
+    /// Whether the successfully compiled contracts are kept despite the failed ones.
+    #[serde(default)]
+    pub keep_going: bool,
+    /// The EVM version the code generator targets.
+    #[serde(default)]
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
+}
@@ -0,0 +1,206 @@
+//!
+//! The compiler server.
+//!
+
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::panic::AssertUnwindSafe;
+use std::sync::Mutex;
+use std::time::Duration;
+
+///
+/// The compiler server.
+///
+/// Accepts standard JSON inputs over TCP and responds with standard JSON outputs, so clients
+/// such as editor integrations do not pay the process startup cost for each compilation.
+///
+/// The protocol is line-delimited: each request is a standard JSON input serialized into
+/// a single line, and each response is a standard JSON output serialized into a single line.
+/// Responses are sent in the order of requests. A connection may send any number of requests,
+/// and up to the maximum number of connections are served concurrently by a pool of worker threads.
+/// Further connections wait until one of the served ones is closed.
+///
+/// Each request is compiled in isolation, so errors, including internal ones, are reported
+/// in the response to the failed request only. Set the timeout to prevent pathological requests
+/// from occupying the server indefinitely.
+///
+#[derive(Debug)]
+pub struct Server {
+    /// The TCP listener.
+    listener: TcpListener,
+    /// The `solc` base path.
+    base_path: Option<String>,
+    /// The `solc` include paths.
+    include_paths: Vec<String>,
+    /// The `solc` allowed paths.
+    allow_paths: Option<String>,
+    /// Whether to reject unknown keys in the input settings.
+    strict_settings: bool,
+    /// The time limit of the code generation of each contract.
+    timeout: Option<Duration>,
+    /// The maximum number of connections served concurrently.
+    max_connections: usize,
+}
+
+impl Server {
+    /// The default maximum number of connections served concurrently.
+    pub const MAX_CONNECTIONS_DEFAULT: usize = 16;
+
+    ///
+    /// Binds the server to `address`, e.g. `127.0.0.1:8000`.
+    ///
+    /// Use port `0` to bind to an arbitrary free port, which can be queried with [`Self::local_address`].
+    ///
+    pub fn bind(
+        address: &str,
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+        strict_settings: bool,
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind(address)
+            .map_err(|error| anyhow::anyhow!("Server address `{address}` binding: {error}"))?;
+        Ok(Self {
+            listener,
+            base_path,
+            include_paths,
+            allow_paths,
+            strict_settings,
+            timeout,
+            max_connections: Self::MAX_CONNECTIONS_DEFAULT,
+        })
+    }
+
+    ///
+    /// Sets the maximum number of connections served concurrently.
+    ///
+    /// At least one connection is always served.
+    ///
+    pub fn set_max_connections(&mut self, max_connections: usize) {
+        self.max_connections = max_connections.max(1);
+    }
+
+    ///
+    /// Returns the address the server is listening on.
+    ///
+    pub fn local_address(&self) -> anyhow::Result<SocketAddr> {
+        self.listener
+            .local_addr()
+            .map_err(|error| anyhow::anyhow!("Server address getting: {error}"))
+    }
+
+    ///
+    /// Serves the incoming connections until the process is terminated.
+    ///
+    /// Failures of a single connection, e.g. a client disconnecting abruptly, do not stop the server.
+    ///
+    /// The accepted connections are dispatched to a fixed pool of worker threads, so the number
+    /// of threads does not grow with the number of clients.
+    ///
+    pub fn run(&self) -> anyhow::Result<()> {
+        let (sender, receiver) = std::sync::mpsc::sync_channel::<TcpStream>(0);
+        let receiver = Mutex::new(receiver);
+        std::thread::scope(|scope| {
+            for _ in 0..self.max_connections {
+                let receiver = &receiver;
+                std::thread::Builder::new()
+                    .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+                    .spawn_scoped(scope, move || loop {
+                        let stream = match receiver.lock().expect("Sync").recv() {
+                            Ok(stream) => stream,
+                            Err(_) => break,
+                        };
+                        if let Err(error) = self.serve(stream) {
+                            let _ = writeln!(std::io::stderr(), "Server connection: {error}");
+                        }
+                    })
+                    .expect("Threading error");
+            }
+
+            // Moved into the scope to be dropped on return, so the idle workers stop.
+            let sender = sender;
+            for stream in self.listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(error) => {
+                        writeln!(std::io::stderr(), "Server connection accepting: {error}")?;
+                        continue;
+                    }
+                };
+                sender.send(stream).expect("Sync");
+            }
+            Ok(())
+        })
+    }
+
+    ///
+    /// Compiles the standard JSON `input_json` and returns the standard JSON output serialized into a single line.
+    ///
+    /// Errors are reported in the output, and never returned.
+    ///
+    pub fn compile(&self, input_json: &str) -> String {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            solx_standard_json::Input::try_from_str(input_json, self.strict_settings).and_then(
+                |solc_input| {
+                    crate::standard_json_evm_single(
+                        solc_input,
+                        &mut Vec::new(),
+                        self.base_path.clone(),
+                        self.include_paths.clone(),
+                        self.allow_paths.clone(),
+                        crate::CompileOptions {
+                            timeout: self.timeout,
+                            ..crate::CompileOptions::default()
+                        },
+                        crate::EVMBuildOptions::default(),
+                        None,
+                    )
+                },
+            )
+        }));
+
+        let output = match result {
+            Ok(Ok((mut solc_output, output_selection))) => {
+                solc_output.prune(&output_selection);
+                solc_output
+            }
+            Ok(Err(error)) => solx_standard_json::Output::new_with_messages(vec![
+                solx_standard_json::OutputError::new_error(None, error, None, None),
+            ]),
+            Err(_) => solx_standard_json::Output::new_with_messages(vec![
+                solx_standard_json::OutputError::new_error(
+                    None,
+                    "Internal compiler error: the request has caused a panic.",
+                    None,
+                    None,
+                ),
+            ]),
+        };
+        output.into_pruned_json(false)
+    }
+
+    ///
+    /// Serves the requests sent over `stream` until the client disconnects.
+    ///
+    fn serve(&self, stream: TcpStream) -> anyhow::Result<()> {
+        let reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response = self.compile(line.as_str());
+            writer.write_all(response.as_bytes())?;
+            writer.write_all(b"\n")?;
+            writer.flush()?;
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 //! Solidity compiler arguments.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::path::Path;
 use std::path::PathBuf;
@@ -9,6 +10,8 @@ use std::path::PathBuf;
 use clap::Parser;
 use path_slash::PathExt;
 
+use crate::config::Config;
+
 ///
 /// Compiles the provided Solidity input files (or use the standard input if no files
 /// are given or "-" is specified as a file name). Outputs the components based on the
@@ -18,7 +21,12 @@ use path_slash::PathExt;
 /// Example: solx ERC20.sol -O3 --bin --output-dir "./build/"
 ///
 #[derive(Debug, Parser)]
-#[command(about, long_about = None, arg_required_else_help = true)]
+#[command(
+    about,
+    long_about = None,
+    arg_required_else_help = true,
+    after_help = Arguments::EXIT_CODES_HELP
+)]
 pub struct Arguments {
     /// Print the version and exit.
     #[arg(long)]
@@ -28,6 +36,17 @@ pub struct Arguments {
     /// If an argument contains a '=', it is considered a remapping.
     pub inputs: Vec<String>,
 
+    /// Set the virtual path of the source read from the standard input with `-`.
+    /// The path is used in the output and diagnostics instead of `-`. Defaults to `stdin.sol`.
+    #[arg(long)]
+    pub stdin_path: Option<String>,
+
+    /// Read the defaults of the options from the specified JSON config file.
+    /// If not specified, `solx.json` in the working directory is read if it exists.
+    /// Command line options take precedence over the config file values.
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
     /// Set the given path as the root of the source tree instead of the root of the filesystem.
     /// Passed to `solc` without changes.
     #[arg(long)]
@@ -40,6 +59,8 @@ pub struct Arguments {
     pub include_path: Vec<String>,
 
     /// Allow a given path for imports. A list of paths can be supplied by separating them with a comma.
+    /// Useful for dependencies in sibling directories, e.g. in monorepos.
+    /// Imports outside of the base path, include paths, and allowed paths are rejected by `solc`.
     /// Passed to `solc` without changes.
     #[arg(long)]
     pub allow_paths: Option<String>,
@@ -52,6 +73,60 @@ pub struct Arguments {
     #[arg(long)]
     pub overwrite: bool,
 
+    /// Only write the artifacts whose content has changed since the last write, leaving the
+    /// modification times of the unchanged files intact (used together with -o).
+    /// Implies `--overwrite` for the changed files.
+    #[arg(long)]
+    pub only_changed: bool,
+
+    /// Set the output file name template (used together with -o).
+    /// Available placeholders: `{path}` for the source file name, `{name}` for the contract name,
+    /// and `{ext}` for the artifact extension. The default is `{path}/{name}.{ext}`.
+    #[arg(long)]
+    pub output_filename_template: Option<String>,
+
+    /// Mirror the source directory structure in the output directory (used together with -o).
+    /// Prevents collisions of contracts with the same file and contract names in different directories.
+    #[arg(long)]
+    pub mirror_source_tree: bool,
+
+    /// Write a `manifest.json` file listing the written artifacts with their sizes and SHA-256 hashes,
+    /// along with the compiler settings used (used together with -o).
+    #[arg(long)]
+    pub manifest: bool,
+
+    /// Write a single JSON file per contract with its ABI, bytecode, runtime bytecode, and metadata,
+    /// in the layout of the Hardhat artifacts consumed by `ethers.js` and `web3.js` (used together with -o).
+    #[arg(long)]
+    pub emit_contract_json: bool,
+
+    /// Set the terminal color mode of diagnostics and success messages: `auto`, `always`, or `never`.
+    /// The default is `auto`, where colors are enabled if the output stream is a terminal.
+    /// JSON output is never colored.
+    #[arg(long, value_name = "WHEN")]
+    pub color: Option<solx_standard_json::OutputErrorColor>,
+
+    /// Disable terminal colors, which is equivalent to `--color=never`.
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Suppress warnings with the specified `solc` numeric codes, separated by commas.
+    /// Suppressed warnings are removed from both the terminal and standard JSON output. Errors are never suppressed.
+    /// Example: `--suppress-warnings 2072,5667`.
+    #[arg(long, value_name = "CODES", value_delimiter = ',')]
+    pub suppress_warnings: Vec<String>,
+
+    /// Print the suppressed warnings after the compilation.
+    /// Cannot be used in standard JSON mode.
+    #[arg(long)]
+    pub show_suppressed: bool,
+
+    /// Escalate warnings with the specified `solc` numeric codes to errors, separated by commas.
+    /// The other warnings are left unchanged.
+    /// Example: `--escalate-warnings 8417` to reject `block.timestamp` used as a source of randomness.
+    #[arg(long, value_name = "CODES", value_delimiter = ',')]
+    pub escalate_warnings: Vec<String>,
+
     /// Set the optimization parameter -O[0 | 1 | 2 | 3 | s | z].
     /// Use `3` for best performance and `z` for minimal size.
     #[arg(short = 'O', long)]
@@ -61,6 +136,29 @@ pub struct Arguments {
     #[arg(long = "optimization-size-fallback")]
     pub size_fallback: bool,
 
+    /// Skip all optional LLVM passes to minimize the compilation time of development builds.
+    /// The bytecode is larger and slower, and is marked as unoptimized in the metadata.
+    /// Must not be used for production builds.
+    #[arg(long)]
+    pub no_optimize: bool,
+
+    /// Override the optimization parameter for specific contracts.
+    /// The argument is `<pattern>=<mode>`, where the pattern is the full contract path, the contract name, or the source path.
+    /// Can be repeated. Example: `--optimize-contract 'Token=3'`.
+    #[arg(long = "optimize-contract")]
+    pub optimize_contract: Vec<String>,
+
+    /// Compile only the contract with the specified full path, e.g. `--target 'src/Token.sol:Token'`.
+    /// The contracts deployed by the target are compiled as well, but only the target is written to the output.
+    #[arg(long, value_name = "PATH:NAME")]
+    pub target: Option<String>,
+
+    /// Cache the contract builds in the specified directory, and reuse them while the contract,
+    /// its dependencies, and the compiler settings remain unchanged.
+    /// The frontend still runs on each invocation, but the code generation of the cached contracts is skipped.
+    #[arg(long, value_name = "PATH")]
+    pub cache_dir: Option<PathBuf>,
+
     /// Pass arbitrary space-separated options to LLVM.
     /// The argument must be a single-quoted string following a `=` separator.
     /// Example: `--llvm-options='arg1 arg2 arg3 ... argN'`.
@@ -72,15 +170,42 @@ pub struct Arguments {
     #[arg(long)]
     pub evm_version: Option<era_compiler_common::EVMVersion>,
 
+    /// Require the `solc` version to satisfy the semver range, e.g. `--expect-solc '>=0.8.20, <0.9'`.
+    /// Compilation fails with a version mismatch error otherwise.
+    #[arg(long, value_name = "RANGE")]
+    pub expect_solc: Option<semver::VersionReq>,
+
     /// Specify addresses of deployable libraries. Syntax: `<libraryFullPath1>=<address1> ... <libraryFullPathN>=<addressN>`.
     /// Addresses are interpreted as hexadecimal strings prefixed with `0x`.
     #[arg(short, long, num_args = 1..)]
     pub libraries: Vec<String>,
 
+    /// Fail if any contract remains unlinked, as its libraries are not provided with `--libraries`.
+    /// Prevents deploying objects with placeholders instead of the library addresses.
+    #[arg(long)]
+    pub require_linked: bool,
+
     /// Switch to standard JSON input/output mode. Read from stdin or specified file, write the result to stdout.
     /// This is the default used by the Hardhat plugin.
+    /// Can be specified multiple times, in which case the outputs are merged into a single one.
+    #[arg(long, num_args = 0..=1, action = clap::ArgAction::Append)]
+    pub standard_json: Option<Vec<String>>,
+
+    /// Pretty-print the standard JSON output with a 2-space indentation.
+    /// Only available in standard JSON mode.
+    #[arg(long, conflicts_with = "compact_json")]
+    pub pretty_json: bool,
+
+    /// Print the standard JSON output in a single line. This is the default.
+    /// Only available in standard JSON mode.
+    #[arg(long)]
+    pub compact_json: bool,
+
+    /// Reject unknown keys in the standard JSON input settings, suggesting the closest known ones.
+    /// By default, unknown keys are ignored, so typos silently fall back to the default settings.
+    /// Only available in standard JSON and server modes.
     #[arg(long)]
-    pub standard_json: Option<Option<String>>,
+    pub strict_settings: bool,
 
     /// Sets the number of threads, where each thread compiles its own translation unit in a child process.
     #[arg(short, long)]
@@ -92,6 +217,12 @@ pub struct Arguments {
     #[arg(long)]
     pub yul: bool,
 
+    /// Set the Yul dialect: `era` or `evm`.
+    /// The `evm` dialect only accepts vanilla `solc` Yul, rejecting the Era extensions such as LLVM attributes.
+    /// The default is `era`. Only allowed in Yul mode.
+    #[arg(long, value_name = "DIALECT")]
+    pub yul_dialect: Option<solx::YulDialect>,
+
     /// Switch to LLVM IR mode.
     /// Only one input LLVM IR file is allowed.
     /// Cannot be used with standard JSON mode.
@@ -99,6 +230,12 @@ pub struct Arguments {
     #[arg(long)]
     pub llvm_ir: bool,
 
+    /// Switch to EVM assembly mode.
+    /// Accepts the textual assembly printed by `solc --asm`.
+    /// Cannot be used with standard JSON mode.
+    #[arg(long)]
+    pub assembly: bool,
+
     /// Specify the bytecode file to link.
     /// In default mode, input bytecode files and `--libraries` are required, and the input files are modified in place.
     /// In standard JSON mode, the result of linking is returned via stdout in a JSON.
@@ -112,7 +249,7 @@ pub struct Arguments {
     /// Set the metadata hash type.
     /// Available types: `none`, `ipfs`.
     /// The default is `ipfs`.
-    #[arg(long)]
+    #[arg(long, value_parser = solx_standard_json::InputMetadata::parse_bytecode_hash)]
     pub metadata_hash: Option<era_compiler_common::EVMMetadataHashType>,
 
     /// Sets the literal content flag for contract metadata.
@@ -121,9 +258,24 @@ pub struct Arguments {
     pub metadata_literal: bool,
 
     /// Turn off CBOR metadata at the end of bytecode.
+    /// The metadata is not appended during assembling, so the bytecode is never truncated afterwards.
+    /// In standard JSON mode, the same is done with `settings.metadata.appendCBOR` set to `false`,
+    /// and the runtime bytecode is reported with `cborMetadata` set to `false`.
     #[arg(long)]
     pub no_cbor_metadata: bool,
 
+    /// Merge the JSON object from the specified file into the metadata of each contract.
+    /// The keys must not collide with the reserved metadata keys, e.g. `compiler`, `language`, or `sources`.
+    /// The merged object is only written to the metadata artifact and does not affect the metadata hash.
+    #[arg(long)]
+    pub metadata_extra: Option<PathBuf>,
+
+    /// Override the compiler version in the metadata `compiler` field, e.g. for verifiers expecting an exact version label.
+    /// The metadata hash is not affected, and a warning is emitted, as the metadata no longer reflects the actual compiler.
+    /// In standard JSON mode, the same is done with `settings.metadata.compilerVersion`.
+    #[arg(long, value_name = "VERSION")]
+    pub metadata_compiler_version: Option<String>,
+
     /// Output metadata of the compiled project.
     #[arg(long = "metadata")]
     pub output_metadata: bool,
@@ -132,6 +284,93 @@ pub struct Arguments {
     #[arg(long = "bin")]
     pub output_bytecode: bool,
 
+    /// Output runtime bytecode of the compiled contracts.
+    /// Can be requested independently of `--bin`, which outputs the creation bytecode.
+    #[arg(long = "bin-runtime")]
+    pub output_runtime_bytecode: bool,
+
+    /// Prefix the bytecode written to the terminal with `0x`.
+    #[arg(long)]
+    pub hex_prefix: bool,
+
+    /// Write the bytecode to the terminal with the uppercase hexadecimal digits.
+    #[arg(long)]
+    pub hex_uppercase: bool,
+
+    /// Output ABI of the compiled contracts.
+    /// Only available in Solidity mode, as the ABI is provided by `solc`.
+    #[arg(long = "abi")]
+    pub output_abi: bool,
+
+    /// Output the textual LLVM IR of the deploy and runtime code of the compiled contracts.
+    /// The IR is captured before optimization. Use `--debug-output-dir` to dump the optimized IR as well.
+    /// Not available in LLVM IR mode, as the IR is already the input.
+    #[arg(long)]
+    pub emit_llvm: bool,
+
+    /// Output the textual LLVM IR of the compiled contracts after the specified optimization passes, e.g. `instcombine,sroa`.
+    /// The whole module is printed each time one of the passes runs, which helps to bisect the pass introducing a change.
+    /// Not available in LLVM IR mode.
+    #[arg(long, value_name = "PASSES", value_delimiter = ',')]
+    pub emit_llvm_after: Vec<String>,
+
+    /// Output only the runtime bytecode, omitting the deploy code.
+    /// Useful for predeploys and genesis allocations.
+    /// In standard JSON mode, the `evm.bytecode` object is omitted and only `evm.deployedBytecode` is kept.
+    #[arg(long)]
+    pub runtime_only: bool,
+
+    /// Print a table of the deploy and runtime bytecode sizes of the compiled contracts.
+    /// Contracts whose runtime code exceeds the EIP-170 limit of 24576 bytes are reported with a warning.
+    #[arg(long)]
+    pub size_report: bool,
+
+    /// Report contracts whose runtime code exceeds the EIP-170 limit of 24576 bytes as errors instead of warnings.
+    /// In standard JSON mode, the same is done with `settings.strictSizeLimit` set to `true`.
+    #[arg(long)]
+    pub strict_size_limit: bool,
+
+    /// Wrap the runtime bytecode into an EVM Object Format (EOF) container with a single code section.
+    /// The deploy code is kept legacy, but returns the wrapped runtime code.
+    /// The code using instructions deprecated in EOF, such as `JUMP` or `GAS`, is rejected.
+    #[arg(long)]
+    pub eof: bool,
+
+    /// Write the artifacts of the successfully compiled contracts even if some contracts fail.
+    /// The failures are reported after the artifacts are written, and do not cause a nonzero exit code.
+    #[arg(long)]
+    pub keep_going: bool,
+
+    /// Watch the input files, and recompile the project each time they change.
+    /// Each rebuild writes the output again, and prints the bytecode changes since the previous build.
+    /// Compilation errors are reported without leaving the watch loop.
+    /// Imported files that are not specified as inputs are not watched. Implies `--overwrite` and `--keep-going`.
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Abort the code generation of a contract if it takes longer than the specified number of seconds.
+    /// The contract is reported with a timeout error, and the process exits with a distinct code.
+    /// Use `--keep-going` to produce the output for the other contracts.
+    #[arg(long, value_name = "SECONDS")]
+    pub timeout: Option<u64>,
+
+    /// Compile each contract once more optimized for size, and warn if the selected optimization mode
+    /// has made it notably larger. The warning names the contracts and their size differences.
+    /// Doubles the code generation time, so it is disabled by default.
+    #[arg(long)]
+    pub size_advisory: bool,
+
+    /// Run the compiler server, accepting standard JSON inputs over TCP at the `--listen` address.
+    /// Each request and response is a standard JSON input or output serialized into a single line.
+    /// Avoids the process startup cost for each compilation, e.g. in editor integrations.
+    #[arg(long)]
+    pub server: bool,
+
+    /// Set the address the compiler server listens on, e.g. `127.0.0.1:8000`.
+    /// Only allowed in server mode.
+    #[arg(long, value_name = "ADDRESS")]
+    pub listen: Option<String>,
+
     /// Dump all IRs to files in the specified directory.
     /// Only for testing and debugging.
     #[arg(long)]
@@ -154,6 +393,16 @@ pub struct Arguments {
 }
 
 impl Arguments {
+    /// The exit codes description appended to the help message.
+    /// The codes are stable and must not be changed across releases.
+    pub const EXIT_CODES_HELP: &'static str = "Exit codes:
+  0  Success
+  1  Compilation failure
+  2  Input or output error
+  3  Syntax error
+  4  Linker error
+  5  Timeout";
+
     ///
     /// Validates the arguments.
     ///
@@ -169,21 +418,123 @@ impl Arguments {
             ));
         }
 
+        if self.color.is_some() && self.no_color {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "`--color` and `--no-color` cannot be used together.",
+                None,
+                None,
+            ));
+        }
+
+        for code in self
+            .suppress_warnings
+            .iter()
+            .chain(self.escalate_warnings.iter())
+        {
+            if code.is_empty() || !code.chars().all(|character| character.is_ascii_digit()) {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    format!("Invalid warning code `{code}`. Warning codes must be numeric, e.g. `2072`."),
+                    None,
+                    None,
+                ));
+            }
+        }
+        for code in self
+            .escalate_warnings
+            .iter()
+            .filter(|code| self.suppress_warnings.contains(code))
+        {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                format!("Warning code `{code}` cannot be both suppressed and escalated."),
+                None,
+                None,
+            ));
+        }
+        if self.show_suppressed && self.suppress_warnings.is_empty() {
+            messages.push(solx_standard_json::OutputError::new_warning(
+                None,
+                "No warnings are suppressed, as `--suppress-warnings` is not specified.",
+                None,
+                None,
+            ));
+        }
+
+        if self.no_optimize {
+            if self.optimization.is_some()
+                || self.size_fallback
+                || !self.optimize_contract.is_empty()
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Disabling optimizations is not allowed together with optimization settings.",
+                    None,
+                    None,
+                ));
+            } else if self.standard_json.is_none() {
+                messages.push(solx_standard_json::OutputError::new_warning(
+                    None,
+                    "Optimizations are disabled with `--no-optimize`. The bytecode is larger and slower, and must not be deployed to production.",
+                    None,
+                    None,
+                ));
+            }
+        }
+
         let modes_count = [
             self.yul,
             self.llvm_ir,
+            self.assembly,
             self.link,
             self.standard_json.is_some(),
+            self.server,
         ]
         .iter()
         .filter(|&&x| x)
         .count();
         if modes_count > 1 + ((self.link && self.standard_json.is_some()) as usize) {
             messages.push(solx_standard_json::OutputError::new_error(
-                None, "Only one mode is allowed at the same time: Yul, LLVM IR, standard JSON. Only linker can be used with `--standard-json`.", None, None));
+                None, "Only one mode is allowed at the same time: Yul, LLVM IR, EVM assembly, standard JSON, server. Only linker can be used with `--standard-json`.", None, None));
+        }
+
+        if self.timeout == Some(0) {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Timeout must be at least one second.",
+                None,
+                None,
+            ));
+        }
+
+        if self.server {
+            if self.listen.is_none() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Server mode requires an address to listen on, e.g. `--listen 127.0.0.1:8000`.",
+                    None,
+                    None,
+                ));
+            }
+            if !self.inputs.is_empty() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Input files are not allowed in server mode, as they must be passed via standard JSON requests.",
+                    None,
+                    None,
+                ));
+            }
+        } else if self.listen.is_some() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Listening address is only allowed in server mode.",
+                None,
+                None,
+            ));
         }
 
-        if self.yul || self.llvm_ir || self.link {
+        if self.yul || self.llvm_ir || self.assembly || self.link {
             if self.base_path.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
@@ -209,6 +560,50 @@ impl Arguments {
                 ));
             }
 
+            if !self.optimize_contract.is_empty() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Per-contract optimization overrides are only allowed in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.metadata_literal {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Metadata literal content flag is only allowed in Solidity mode, as the source content is not retained otherwise.",
+                    None,
+                    None,
+                ));
+            }
+
+            if self.target.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Target contract is only allowed in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
+
+            if self.cache_dir.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Compilation cache is only allowed in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
+
+            if self.output_abi {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "ABI output is only allowed in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
+
             if self.evm_version.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
@@ -228,6 +623,86 @@ impl Arguments {
             }
         }
 
+        if self.output_filename_template.is_some() && self.output_dir.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Output file name template is only allowed together with the output directory.",
+                None,
+                None,
+            ));
+        }
+
+        if self.only_changed && self.output_dir.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Writing only the changed artifacts is only allowed together with the output directory.",
+                None,
+                None,
+            ));
+        }
+
+        if self.mirror_source_tree && self.output_dir.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Mirroring the source tree is only allowed together with the output directory.",
+                None,
+                None,
+            ));
+        }
+
+        if self.emit_contract_json && self.output_dir.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Contract JSON artifacts are only allowed together with the output directory.",
+                None,
+                None,
+            ));
+        }
+
+        if self.manifest && self.output_dir.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Build manifest is only allowed together with the output directory.",
+                None,
+                None,
+            ));
+        }
+
+        if self.watch
+            && self.standard_json.is_none()
+            && (self.link
+                || self.inputs.iter().all(|input| input.contains('='))
+                || self
+                    .inputs
+                    .iter()
+                    .any(|input| input.trim() == solx_standard_json::InputSource::STDIN_PATH))
+        {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Watch mode requires input files, as neither the standard input nor linked bytecode can be watched.",
+                None,
+                None,
+            ));
+        }
+
+        if self.yul_dialect.is_some() && !self.yul {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Yul dialect is only allowed in Yul mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.llvm_ir && (self.emit_llvm || !self.emit_llvm_after.is_empty()) {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "LLVM IR output is not available in LLVM IR mode.",
+                None,
+                None,
+            ));
+        }
+
         if self.llvm_ir && !self.libraries.is_empty() {
             messages.push(solx_standard_json::OutputError::new_error(
                 None,
@@ -239,8 +714,8 @@ impl Arguments {
 
         let mut linker_default_arguments_count = 2;
         linker_default_arguments_count += match self.standard_json {
-            Some(Some(_)) => 2,
-            Some(None) => 1,
+            Some(ref paths) if paths.is_empty() => 1,
+            Some(ref paths) => paths.len() * 2,
             _ => self.inputs.len() + ((!self.libraries.is_empty()) as usize) + self.libraries.len(),
         };
         if self.link && std::env::args().count() > linker_default_arguments_count {
@@ -253,7 +728,16 @@ impl Arguments {
         }
 
         if self.standard_json.is_some() {
-            if self.output_metadata || self.output_bytecode {
+            if self.output_metadata
+                || self.output_bytecode
+                || self.output_runtime_bytecode
+                || self.output_abi
+                || self.emit_contract_json
+                || self.hex_prefix
+                || self.hex_uppercase
+                || self.emit_llvm
+                || !self.emit_llvm_after.is_empty()
+            {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
                     "Cannot output data outside of JSON in standard JSON mode.",
@@ -296,6 +780,15 @@ impl Arguments {
                 ));
             }
 
+            if self.stdin_path.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Standard input path cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+
             if self.output_dir.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
@@ -312,6 +805,70 @@ impl Arguments {
                     None,
                 ));
             }
+            if self.keep_going {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Keep-going flag cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.size_advisory {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Size advisory cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.target.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Target contract cannot be used in standard JSON mode. Use `settings.outputSelection` instead.",
+                    None,
+                    None,
+                ));
+            }
+            if self.cache_dir.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Compilation cache cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.require_linked {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Requiring linked contracts cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.watch {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Watch mode is not allowed in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.metadata_compiler_version.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Metadata compiler version must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.show_suppressed {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Showing suppressed warnings is not allowed in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
             if self.optimization.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
@@ -328,6 +885,30 @@ impl Arguments {
                     None,
                 ));
             }
+            if self.no_optimize {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Disabling optimizations is not allowed in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.config.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Config file cannot be used in standard JSON mode. Use the standard JSON input settings instead.",
+                    None,
+                    None,
+                ));
+            }
+            if !self.optimize_contract.is_empty() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Per-contract optimization overrides must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
             if self.llvm_options.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
@@ -352,6 +933,56 @@ impl Arguments {
                     None,
                 ));
             }
+            if self.metadata_extra.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Extra metadata cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.eof {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "EOF output cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.strict_size_limit {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Strict size limit must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.size_report {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Size report cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        if (self.pretty_json || self.compact_json) && self.standard_json.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "JSON output formatting is only allowed in standard JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.strict_settings && self.standard_json.is_none() && !self.server {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Strict settings are only allowed in standard JSON and server modes.",
+                None,
+                None,
+            ));
         }
 
         messages
@@ -394,6 +1025,80 @@ impl Arguments {
         Ok((input_files, remappings))
     }
 
+    ///
+    /// Returns the terminal color mode.
+    ///
+    pub fn color(&self) -> solx_standard_json::OutputErrorColor {
+        if self.no_color {
+            return solx_standard_json::OutputErrorColor::Never;
+        }
+        self.color.unwrap_or_default()
+    }
+
+    ///
+    /// Fills the options not specified on the command line from the config file.
+    ///
+    /// The config file is not read in standard JSON mode, where the settings are provided in the input.
+    ///
+    pub fn apply_config(&mut self) -> anyhow::Result<()> {
+        if self.standard_json.is_some() {
+            return Ok(());
+        }
+
+        let path = match self.config {
+            Some(ref path) => path.to_owned(),
+            None => {
+                let path = PathBuf::from(Config::FILE_NAME);
+                if !path.is_file() {
+                    return Ok(());
+                }
+                path
+            }
+        };
+        Config::try_from_path(path.as_path())?.apply(path.as_path(), self)
+    }
+
+    ///
+    /// Returns the warnings promoted to errors by code.
+    ///
+    pub fn escalation(&self) -> solx_standard_json::OutputErrorEscalation {
+        solx_standard_json::OutputErrorEscalation::new(
+            self.escalate_warnings.iter().cloned().collect(),
+        )
+    }
+
+    ///
+    /// Returns the warnings removed from the output by code.
+    ///
+    pub fn suppression(&self) -> solx_standard_json::OutputErrorSuppression {
+        solx_standard_json::OutputErrorSuppression::new(
+            self.suppress_warnings.iter().cloned().collect(),
+        )
+    }
+
+    ///
+    /// Returns the per-contract optimization mode overrides.
+    ///
+    pub fn optimization_overrides(&self) -> anyhow::Result<BTreeMap<String, char>> {
+        let mut overrides = BTreeMap::new();
+        for specifier in self.optimize_contract.iter() {
+            let (pattern, mode) = specifier.rsplit_once('=').ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid optimization override `{specifier}`: expected `<pattern>=<mode>`."
+                )
+            })?;
+            let mut mode_chars = mode.trim().chars();
+            let mode = match (mode_chars.next(), mode_chars.next()) {
+                (Some(mode), None) => mode,
+                _ => anyhow::bail!(
+                    "Invalid optimization override `{specifier}`: the mode must be a single character."
+                ),
+            };
+            overrides.insert(pattern.trim().to_owned(), mode);
+        }
+        Ok(overrides)
+    }
+
     ///
     /// Normalizes an input path by converting it to POSIX format.
     ///
@@ -0,0 +1,161 @@
+//!
+//! Solidity compiler config file.
+//!
+
+use std::path::Path;
+
+use crate::arguments::Arguments;
+
+///
+/// Solidity compiler config file.
+///
+/// Provides the defaults of the command line options, so the project settings can be kept
+/// in a single JSON object. The keys are the camel-cased option names, e.g. `basePath` for `--base-path`.
+///
+/// The command line options take precedence over the file values. The list options, such as
+/// remappings, are merged, and the flags can only be enabled in the file.
+///
+#[derive(Debug, Default)]
+pub struct Config {
+    /// The optimization mode.
+    pub optimization: Option<char>,
+    /// Whether to recompile with `-Oz` if the bytecode is too large.
+    pub optimization_size_fallback: bool,
+    /// Whether to output the bytecode.
+    pub output_bytecode: bool,
+    /// Whether to output the runtime bytecode.
+    pub output_runtime_bytecode: bool,
+    /// Whether to output the ABI.
+    pub output_abi: bool,
+    /// Whether to output the metadata.
+    pub output_metadata: bool,
+    /// The `solc` base path.
+    pub base_path: Option<String>,
+    /// The `solc` include paths.
+    pub include_paths: Vec<String>,
+    /// The `solc` allowed paths.
+    pub allow_paths: Option<String>,
+    /// The `solc` remappings.
+    pub remappings: Vec<String>,
+}
+
+impl Config {
+    /// The config file name looked up in the working directory.
+    pub const FILE_NAME: &'static str = "solx.json";
+
+    /// The available keys.
+    pub const KEYS: [&'static str; 10] = [
+        "optimization",
+        "optimizationSizeFallback",
+        "bin",
+        "binRuntime",
+        "abi",
+        "metadata",
+        "basePath",
+        "includePaths",
+        "allowPaths",
+        "remappings",
+    ];
+
+    ///
+    /// Reads the config file at `path`.
+    ///
+    /// Errors name the offending key, as the values are parsed one by one.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Config file {path:?} reading: {error}"))?;
+        let value: serde_json::Value = serde_json::from_str(content.as_str())
+            .map_err(|error| anyhow::anyhow!("Config file {path:?} parsing: {error}"))?;
+        let serde_json::Value::Object(object) = value else {
+            anyhow::bail!("Config file {path:?} must contain a JSON object.");
+        };
+
+        let mut config = Self::default();
+        for (key, value) in object.into_iter() {
+            match key.as_str() {
+                "optimization" => config.optimization = Some(Self::parse(path, &key, value)?),
+                "optimizationSizeFallback" => {
+                    config.optimization_size_fallback = Self::parse(path, &key, value)?
+                }
+                "bin" => config.output_bytecode = Self::parse(path, &key, value)?,
+                "binRuntime" => config.output_runtime_bytecode = Self::parse(path, &key, value)?,
+                "abi" => config.output_abi = Self::parse(path, &key, value)?,
+                "metadata" => config.output_metadata = Self::parse(path, &key, value)?,
+                "basePath" => config.base_path = Some(Self::parse(path, &key, value)?),
+                "includePaths" => config.include_paths = Self::parse(path, &key, value)?,
+                "allowPaths" => config.allow_paths = Some(Self::parse(path, &key, value)?),
+                "remappings" => {
+                    let remappings: Vec<String> = Self::parse(path, &key, value)?;
+                    if let Some(remapping) =
+                        remappings.iter().find(|remapping| !remapping.contains('='))
+                    {
+                        anyhow::bail!(
+                            "Config file {path:?} key `{key}`: invalid remapping `{remapping}`, expected `<prefix>=<target>`."
+                        );
+                    }
+                    config.remappings = remappings;
+                }
+                key => anyhow::bail!(
+                    "Config file {path:?} has an unknown key `{key}`. Available keys: {}.",
+                    Self::KEYS
+                        .iter()
+                        .map(|key| format!("`{key}`"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+            }
+        }
+        Ok(config)
+    }
+
+    ///
+    /// Fills the options not specified in `arguments` with the config values.
+    ///
+    /// # Errors
+    /// If the config has remappings, but `arguments` select a mode other than Solidity.
+    ///
+    pub fn apply(self, path: &Path, arguments: &mut Arguments) -> anyhow::Result<()> {
+        if !self.remappings.is_empty()
+            && (arguments.yul || arguments.llvm_ir || arguments.assembly || arguments.link)
+        {
+            anyhow::bail!(
+                "Config file {path:?} key `remappings`: remappings are only allowed in Solidity mode."
+            );
+        }
+
+        if arguments.optimization.is_none() {
+            arguments.optimization = self.optimization;
+        }
+        arguments.size_fallback |= self.optimization_size_fallback;
+        arguments.output_bytecode |= self.output_bytecode;
+        arguments.output_runtime_bytecode |= self.output_runtime_bytecode;
+        arguments.output_abi |= self.output_abi;
+        arguments.output_metadata |= self.output_metadata;
+        if arguments.base_path.is_none() {
+            arguments.base_path = self.base_path;
+        }
+        if arguments.include_path.is_empty() {
+            arguments.include_path = self.include_paths;
+        }
+        if arguments.allow_paths.is_none() {
+            arguments.allow_paths = self.allow_paths;
+        }
+        // The command line remappings come last, so they take precedence in `solc`.
+        let mut inputs = self.remappings;
+        inputs.append(&mut arguments.inputs);
+        arguments.inputs = inputs;
+        Ok(())
+    }
+
+    ///
+    /// Parses the `value` of `key`, naming the key in the error.
+    ///
+    fn parse<T>(path: &Path, key: &str, value: serde_json::Value) -> anyhow::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_value(value)
+            .map_err(|error| anyhow::anyhow!("Config file {path:?} key `{key}`: {error}"))
+    }
+}
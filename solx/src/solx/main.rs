@@ -3,9 +3,11 @@
 //!
 
 pub mod arguments;
+pub mod config;
 
 use std::io::Write;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::Parser;
 
@@ -15,16 +17,26 @@ use self::arguments::Arguments;
 /// The application entry point.
 ///
 fn main() -> anyhow::Result<()> {
-    let arguments = Arguments::try_parse()?;
+    let mut arguments = Arguments::try_parse()?;
+    let color = arguments.color();
     let is_standard_json = arguments.standard_json.is_some();
-    let mut messages = arguments.validate();
+    let show_suppressed = arguments.show_suppressed;
+    let pretty_json = arguments.pretty_json;
+    let escalation = arguments.escalation();
+    let suppression = arguments.suppression();
+    let mut messages = match arguments.apply_config() {
+        Ok(()) => arguments.validate(),
+        Err(error) => vec![solx_standard_json::OutputError::new_error(
+            None, error, None, None,
+        )],
+    };
     if messages.iter().all(|error| error.severity != "error") {
         if !is_standard_json {
             std::io::stderr()
                 .write_all(
                     messages
                         .drain(..)
-                        .map(|error| error.to_string())
+                        .map(|error| error.to_terminal_string(color))
                         .collect::<Vec<String>>()
                         .join("\n")
                         .as_bytes(),
@@ -32,27 +44,51 @@ fn main() -> anyhow::Result<()> {
                 .expect("Stderr writing error");
         }
         if let Err(error) = main_inner(arguments, &mut messages) {
-            messages.push(solx_standard_json::OutputError::new_error(
-                None, error, None, None,
-            ));
+            let r#type = if error
+                .chain()
+                .any(|cause| cause.is::<solx_yul::yul::error::Error>())
+            {
+                solx_standard_json::OutputError::TYPE_PARSER
+            } else if error.chain().any(|cause| cause.is::<std::io::Error>()) {
+                solx_standard_json::OutputError::TYPE_IO
+            } else {
+                "Error"
+            };
+            messages.push(
+                solx_standard_json::OutputError::new_error(None, error, None, None)
+                    .with_type(r#type),
+            );
         }
     }
 
     if is_standard_json {
-        let output = solx_standard_json::Output::new_with_messages(messages);
-        output.write_and_exit(&solx_standard_json::InputSelection::default());
+        let mut output = solx_standard_json::Output::new_with_messages(messages);
+        output.set_warning_codes(escalation, suppression);
+        output.write_and_exit(&solx_standard_json::InputSelection::default(), pretty_json);
     }
 
-    let exit_code = if messages.iter().any(|error| error.severity == "error") {
-        era_compiler_common::EXIT_CODE_FAILURE
-    } else {
-        era_compiler_common::EXIT_CODE_SUCCESS
+    escalation.apply(messages.as_mut_slice());
+    let (mut messages, suppressed) = suppression.filter(messages);
+    if show_suppressed && !suppressed.is_empty() {
+        messages.push(solx_standard_json::OutputError::new(
+            "Info",
+            None,
+            format!("{} warning(s) suppressed:", suppressed.len()),
+            None,
+            None,
+        ));
+        messages.extend(suppressed);
+    }
+
+    let exit_code = match solx_standard_json::OutputErrorExitReason::from_errors(messages.iter()) {
+        Some(exit_reason) => exit_reason.code(),
+        None => era_compiler_common::EXIT_CODE_SUCCESS,
     };
     std::io::stderr()
         .write_all(
             messages
                 .into_iter()
-                .map(|error| error.to_string())
+                .map(|error| error.to_terminal_string(color))
                 .collect::<Vec<String>>()
                 .join("\n")
                 .as_bytes(),
@@ -94,15 +130,95 @@ fn main_inner(
 
     inkwell::support::enable_llvm_pretty_stack_trace();
     era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EVM);
+    solx::check_evm_target()?;
 
     if arguments.recursive_process {
         return solx::run_recursive();
     }
 
+    if let Some(ref required) = arguments.expect_solc {
+        solx_solc::Compiler::default().check_version(required)?;
+    }
+
+    if arguments.server {
+        let server = solx::Server::bind(
+            arguments.listen.as_deref().expect("Always exists"),
+            arguments.base_path.clone(),
+            arguments.include_path.clone(),
+            arguments.allow_paths.clone(),
+            arguments.strict_settings,
+            arguments.timeout.map(Duration::from_secs),
+        )?;
+        writeln!(
+            std::io::stderr(),
+            "Listening on {}...",
+            server.local_address()?
+        )?;
+        return server.run();
+    }
+
+    let (input_files, _) = arguments.split_input_files_and_remappings()?;
+    let mut watcher = solx::Watcher::new(input_files.as_slice());
+    let mut previous_build = None;
+    loop {
+        let result = compile(&arguments, messages);
+        if !arguments.watch {
+            return result.map(|_| ());
+        }
+
+        let (mut messages, _suppressed) = arguments.suppression().filter(std::mem::take(messages));
+        match result {
+            Ok(Some(build)) => {
+                if let Some(ref previous_build) = previous_build {
+                    write!(std::io::stderr(), "{}", previous_build.diff(&build, true))?;
+                }
+                previous_build = Some(build);
+            }
+            Ok(None) => {}
+            Err(error) => messages.push(solx_standard_json::OutputError::new_error(
+                None, error, None, None,
+            )),
+        }
+        for message in messages.into_iter() {
+            writeln!(
+                std::io::stderr(),
+                "{}",
+                message.to_terminal_string(arguments.color())
+            )?;
+        }
+
+        writeln!(
+            std::io::stderr(),
+            "Watching {} file(s) for changes...",
+            input_files.len()
+        )?;
+        let changed = watcher
+            .wait()
+            .into_iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        writeln!(
+            std::io::stderr(),
+            "Recompiling after changes in {changed}..."
+        )?;
+    }
+}
+
+///
+/// Compiles the project according to the `arguments`, and writes the output.
+///
+/// In watch mode, returns a snapshot of the build to compare the next one with.
+///
+fn compile(
+    arguments: &Arguments,
+    messages: &mut Vec<solx_standard_json::OutputError>,
+) -> anyhow::Result<Option<solx::EVMBuild>> {
     let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
 
     let mut optimizer_settings = match arguments.optimization {
         Some(mode) => era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)?,
+        None if arguments.no_optimize => era_compiler_llvm_context::OptimizerSettings::none(),
         None => era_compiler_llvm_context::OptimizerSettings::cycles(),
     };
     if arguments.size_fallback {
@@ -110,8 +226,14 @@ fn main_inner(
     }
     optimizer_settings.is_verify_each_enabled = arguments.llvm_verify_each;
     optimizer_settings.is_debug_logging_enabled = arguments.llvm_debug_logging;
+    let optimization_overrides = arguments.optimization_overrides()?;
+    let filename_template = arguments
+        .output_filename_template
+        .as_deref()
+        .map(str::parse::<solx::EVMContractFilenameTemplate>)
+        .transpose()?;
 
-    let llvm_options: Vec<String> = arguments
+    let mut llvm_options: Vec<String> = arguments
         .llvm_options
         .as_ref()
         .map(|options| {
@@ -121,6 +243,9 @@ fn main_inner(
                 .collect()
         })
         .unwrap_or_default();
+    if arguments.no_optimize {
+        llvm_options.push(solx::LLVM_OPTION_NO_OPTIMIZE.to_owned());
+    }
 
     let debug_config = match arguments.debug_output_dir {
         Some(ref debug_output_directory) => {
@@ -136,80 +261,177 @@ fn main_inner(
         .metadata_hash
         .unwrap_or(era_compiler_common::EVMMetadataHashType::IPFS);
     let append_cbor = !arguments.no_cbor_metadata;
+    let stdin_path = arguments
+        .stdin_path
+        .as_deref()
+        .unwrap_or(solx::DEFAULT_STDIN_PATH);
+    let output_bytecode = arguments.output_bytecode
+        || arguments.output_runtime_bytecode
+        || arguments.emit_contract_json
+        || arguments.emit_llvm
+        || !arguments.emit_llvm_after.is_empty();
+
+    let options = solx::CompileOptions {
+        optimization_overrides,
+        target: arguments.target.clone(),
+        cache_directory: arguments.cache_dir.clone(),
+        emit_llvm: arguments.emit_llvm,
+        emit_llvm_after: arguments.emit_llvm_after.clone(),
+        timeout: arguments.timeout.map(Duration::from_secs),
+        size_advisory: arguments.size_advisory,
+        keep_going: arguments.keep_going,
+        evm_version: arguments.evm_version,
+    };
+    let build_options = solx::EVMBuildOptions {
+        warnings_as_errors: false,
+        escalation: arguments.escalation(),
+        suppression: arguments.suppression(),
+        runtime_only: arguments.runtime_only,
+        strict_size_limit: arguments.strict_size_limit,
+        eof: arguments.eof,
+        keep_going: arguments.keep_going || arguments.watch,
+        require_linked: arguments.require_linked,
+        mirror_source_tree: arguments.mirror_source_tree,
+        compiler_version: arguments.metadata_compiler_version.clone(),
+        manifest: arguments.manifest,
+        output_bytecode: arguments.output_bytecode,
+        output_runtime_bytecode: arguments.output_runtime_bytecode,
+        output_contract_json: arguments.emit_contract_json,
+        hex_format: solx::EVMContractHexFormat::new(arguments.hex_prefix, arguments.hex_uppercase),
+        evm_version: arguments.evm_version,
+        only_changed: arguments.only_changed,
+        filename_template: filename_template.unwrap_or_default(),
+        output_selection: None,
+        color: arguments.color(),
+    };
 
-    let build = if arguments.yul {
+    let mut build = if arguments.yul {
         solx::yul_to_evm(
             input_files.as_slice(),
+            stdin_path,
             arguments.libraries.as_slice(),
-            arguments.output_bytecode,
+            arguments.yul_dialect.unwrap_or_default(),
+            output_bytecode,
             arguments.output_metadata,
             messages,
             metadata_hash_type,
             append_cbor,
             optimizer_settings,
             llvm_options,
+            options,
+            build_options,
             debug_config,
         )
     } else if arguments.llvm_ir {
         solx::llvm_ir_to_evm(
             input_files.as_slice(),
             arguments.libraries.as_slice(),
-            arguments.output_bytecode,
+            output_bytecode,
             arguments.output_metadata,
             messages,
             metadata_hash_type,
             append_cbor,
             optimizer_settings,
             llvm_options,
+            options,
+            build_options,
+            debug_config,
+        )
+    } else if arguments.assembly {
+        solx::assembly_to_evm(
+            input_files.as_slice(),
+            arguments.libraries.as_slice(),
+            output_bytecode,
+            arguments.output_metadata,
+            messages,
+            metadata_hash_type,
+            append_cbor,
+            optimizer_settings,
+            llvm_options,
+            options,
+            build_options,
             debug_config,
         )
     } else if arguments.link {
         anyhow::bail!("The EVM target does not support linking yet.");
-    } else if let Some(standard_json) = arguments.standard_json {
+    } else if let Some(ref standard_json) = arguments.standard_json {
         return solx::standard_json_evm(
-            standard_json.map(PathBuf::from),
+            standard_json.iter().map(PathBuf::from).collect(),
             messages,
-            arguments.base_path,
-            arguments.include_path,
-            arguments.allow_paths,
+            arguments.base_path.clone(),
+            arguments.include_path.clone(),
+            arguments.allow_paths.clone(),
+            arguments.strict_settings,
+            arguments.pretty_json,
+            options,
+            build_options,
             debug_config,
-        );
-    } else if arguments.output_bytecode || arguments.output_metadata {
+        )
+        .map(|()| None);
+    } else if output_bytecode || arguments.output_metadata || arguments.output_abi {
         solx::standard_output_evm(
             input_files.as_slice(),
+            stdin_path,
             arguments.libraries.as_slice(),
-            arguments.output_bytecode,
+            output_bytecode,
+            arguments.output_abi || arguments.emit_contract_json,
             messages,
             arguments.evm_version,
             arguments.via_ir,
             metadata_hash_type,
             arguments.metadata_literal,
             append_cbor,
-            arguments.base_path,
-            arguments.include_path,
-            arguments.allow_paths,
+            arguments.base_path.clone(),
+            arguments.include_path.clone(),
+            arguments.allow_paths.clone(),
             remappings,
             optimizer_settings,
             llvm_options,
+            options,
+            build_options,
             debug_config,
         )
     } else {
         writeln!(
             std::io::stdout(),
-            "Compiler run successful. No output generated."
+            "{}",
+            arguments.color().success(
+                "Compiler run successful. No output generated.",
+                &std::io::stdout()
+            )
         )?;
-        return Ok(());
+        return Ok(None);
     }?;
 
-    if let Some(output_directory) = arguments.output_dir {
+    if let Some(ref path) = arguments.metadata_extra {
+        let extra = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Extra metadata file {path:?} reading: {error}"))?;
+        let extra: serde_json::Value = serde_json::from_str(extra.as_str())
+            .map_err(|error| anyhow::anyhow!("Extra metadata file {path:?} parsing: {error}"))?;
+        build.inject_metadata(&extra)?;
+    }
+
+    let snapshot = arguments
+        .watch
+        .then(|| solx::EVMBuild::new(build.results.clone(), &mut Vec::new()));
+
+    messages.extend(std::mem::take(&mut build.suppressed));
+
+    if arguments.size_report {
+        build.statistics().write_table(&mut std::io::stdout())?;
+    }
+
+    if let Some(ref output_directory) = arguments.output_dir {
         build.write_to_directory(
-            &output_directory,
-            arguments.overwrite,
+            None,
+            output_directory,
+            arguments.overwrite || arguments.watch || arguments.only_changed,
             arguments.output_metadata,
+            arguments.output_abi,
         )?;
     } else {
-        build.write_to_terminal(arguments.output_metadata)?;
+        build.write_to_terminal(None, arguments.output_metadata, arguments.output_abi)?;
     }
 
-    Ok(())
+    Ok(snapshot)
 }
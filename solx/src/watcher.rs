@@ -0,0 +1,83 @@
+//!
+//! The source file watcher.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+///
+/// The source file watcher.
+///
+/// Polls the modification times of the watched files, as the file system notification
+/// APIs are platform-specific.
+///
+#[derive(Debug)]
+pub struct Watcher {
+    /// The last known modification times of the watched files.
+    /// Files that cannot be accessed have no modification time.
+    modified: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Watcher {
+    /// The interval between two polls of the watched files.
+    pub const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    /// The time the watched files must remain unchanged before a change is reported.
+    /// Prevents rebuilding on each of several rapid saves.
+    pub const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(500);
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(paths: &[PathBuf]) -> Self {
+        let modified = paths
+            .iter()
+            .map(|path| (path.to_owned(), Self::modified(path)))
+            .collect();
+        Self { modified }
+    }
+
+    ///
+    /// Blocks until at least one of the watched files changes, and then remains unchanged for
+    /// [`Self::DEBOUNCE_INTERVAL`].
+    ///
+    /// Returns the paths of the changed files.
+    ///
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        let mut last_change = None;
+        loop {
+            std::thread::sleep(Self::POLL_INTERVAL);
+
+            for (path, modified) in self.modified.iter_mut() {
+                let current = Self::modified(path);
+                if current != *modified {
+                    *modified = current;
+                    if !changed.contains(path) {
+                        changed.push(path.to_owned());
+                    }
+                    last_change = Some(Instant::now());
+                }
+            }
+
+            if let Some(last_change) = last_change {
+                if last_change.elapsed() >= Self::DEBOUNCE_INTERVAL {
+                    return changed;
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns the modification time of the file at `path`, if it can be accessed.
+    ///
+    fn modified(path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+}
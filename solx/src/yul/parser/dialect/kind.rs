@@ -0,0 +1,191 @@
+//!
+//! The Yul dialect selector.
+//!
+
+use solx_yul::yul::parser::error::Error as ParserError;
+use solx_yul::yul::parser::statement::block::Block;
+use solx_yul::yul::parser::statement::object::Object;
+use solx_yul::yul::parser::statement::Statement;
+
+use crate::yul::parser::dialect::era::attributes::LLVM_ATTRIBUTE_PREFIX;
+use crate::yul::parser::dialect::era::attributes::LLVM_ATTRIBUTE_SUFFIX;
+use crate::yul::parser::dialect::era::EraDialect;
+
+///
+/// The Yul dialect selector.
+///
+/// The code generator always works with the Era dialect, which is a superset of the EVM one.
+/// Selecting the EVM dialect makes the parsed Era object rejected if it uses the Era extensions.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// The Era dialect with LLVM attributes in function names.
+    #[default]
+    Era,
+    /// The vanilla `solc` EVM dialect.
+    EVM,
+}
+
+impl Kind {
+    ///
+    /// Checks that the parsed `object` does not use extensions unsupported by the dialect.
+    ///
+    /// Works on the already parsed object, so the source code is not parsed again.
+    ///
+    pub fn check(&self, object: &Object<EraDialect>) -> Result<(), solx_yul::yul::error::Error> {
+        match self {
+            Self::Era => Ok(()),
+            Self::EVM => self.check_object(object),
+        }
+    }
+
+    ///
+    /// Checks the object and its inner objects.
+    ///
+    fn check_object(&self, object: &Object<EraDialect>) -> Result<(), solx_yul::yul::error::Error> {
+        self.check_block(&object.code.block)?;
+        if let Some(ref inner_object) = object.inner_object {
+            self.check_object(inner_object)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks the block and its nested blocks.
+    ///
+    fn check_block(&self, block: &Block<EraDialect>) -> Result<(), solx_yul::yul::error::Error> {
+        for statement in block.statements.iter() {
+            match statement {
+                Statement::Object(object) => self.check_object(object)?,
+                Statement::Code(code) => self.check_block(&code.block)?,
+                Statement::Block(block) => self.check_block(block)?,
+                Statement::FunctionDefinition(function) => {
+                    let attribute_begin = function.identifier.find(LLVM_ATTRIBUTE_PREFIX);
+                    let attribute_end = function.identifier.find(LLVM_ATTRIBUTE_SUFFIX);
+                    if let (Some(attribute_begin), Some(attribute_end)) =
+                        (attribute_begin, attribute_end)
+                    {
+                        if attribute_begin < attribute_end {
+                            return Err(ParserError::UnsupportedAttributes {
+                                location: function.location,
+                                identifier: function.identifier.to_owned(),
+                                dialect: self.to_string(),
+                            }
+                            .into());
+                        }
+                    }
+                    self.check_block(&function.body)?;
+                }
+                Statement::IfConditional(conditional) => self.check_block(&conditional.block)?,
+                Statement::Switch(switch) => {
+                    for case in switch.cases.iter() {
+                        self.check_block(&case.block)?;
+                    }
+                    if let Some(ref default) = switch.default {
+                        self.check_block(default)?;
+                    }
+                }
+                Statement::ForLoop(for_loop) => {
+                    self.check_block(&for_loop.initializer)?;
+                    self.check_block(&for_loop.finalizer)?;
+                    self.check_block(&for_loop.body)?;
+                }
+                Statement::Expression(_)
+                | Statement::VariableDeclaration(_)
+                | Statement::Assignment(_)
+                | Statement::Continue(_)
+                | Statement::Break(_)
+                | Statement::Leave(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Kind {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "era" => Ok(Self::Era),
+            "evm" => Ok(Self::EVM),
+            string => {
+                anyhow::bail!("Invalid Yul dialect `{string}`. Available dialects: `era`, `evm`.")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Era => write!(f, "era"),
+            Self::EVM => write!(f, "evm"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solx_yul::yul::error::Error as YulError;
+    use solx_yul::yul::lexer::Lexer;
+    use solx_yul::yul::parser::error::Error as ParserError;
+    use solx_yul::yul::parser::statement::object::Object;
+
+    use crate::yul::parser::dialect::era::EraDialect;
+
+    use super::Kind;
+
+    const SOURCE_CODE: &str = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, answer$llvm_Hot_llvm$())
+                return(0, 32)
+            }
+
+            function answer$llvm_Hot_llvm$() -> result {
+                result := 42
+            }
+        }
+    }
+}
+"#;
+
+    fn parse(source_code: &str) -> Object<EraDialect> {
+        let mut lexer = Lexer::new(source_code.to_owned());
+        Object::parse(&mut lexer, None).expect("Test failure")
+    }
+
+    #[test]
+    fn era() {
+        Kind::Era.check(&parse(SOURCE_CODE)).expect("Test failure");
+    }
+
+    #[test]
+    fn evm() {
+        let error = Kind::EVM
+            .check(&parse(SOURCE_CODE))
+            .expect_err("Test failure");
+        assert!(matches!(
+            error,
+            YulError::Parser(ParserError::UnsupportedAttributes { ref identifier, .. })
+                if identifier == "answer$llvm_Hot_llvm$"
+        ));
+    }
+
+    #[test]
+    fn evm_without_extensions() {
+        let source_code = SOURCE_CODE.replace("$llvm_Hot_llvm$", "");
+        Kind::EVM
+            .check(&parse(source_code.as_str()))
+            .expect("Test failure");
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 pub mod era;
+pub mod kind;
 
 use std::collections::BTreeSet;
 use std::fmt::Debug;
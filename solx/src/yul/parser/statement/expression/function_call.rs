@@ -389,21 +389,20 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::LoadImmutable => {
-                let mut arguments = self.pop_arguments::<1>(context)?;
-                let id = arguments[0].original.take().ok_or_else(|| {
+                let id = self.string_literal_argument(0).ok_or_else(|| {
                     anyhow::anyhow!("{location} `loadimmutable` literal is missing")
                 })?;
                 era_compiler_llvm_context::evm_immutable::load(context, id.as_str()).map(Some)
             }
             Name::SetImmutable => {
-                let mut arguments = self.pop_arguments::<3>(context)?;
-
-                let id = arguments[1].original.take().ok_or_else(|| {
+                let id = self.string_literal_argument(1).ok_or_else(|| {
                     anyhow::anyhow!("{location} `setimmutable` literal is missing")
                 })?;
+                self.0.arguments.remove(1);
+                let arguments = self.pop_arguments_llvm::<2>(context)?;
 
-                let base_offset = arguments[0].to_llvm().into_int_value();
-                let value = arguments[2].to_llvm().into_int_value();
+                let base_offset = arguments[0].into_int_value();
+                let value = arguments[1].into_int_value();
                 era_compiler_llvm_context::evm_immutable::store(
                     context,
                     id.as_str(),
@@ -666,18 +665,15 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::DataOffset => {
-                let mut arguments = self.pop_arguments::<1>(context)?;
-                let object_name = arguments[0].original.take().ok_or_else(|| {
+                let object_name = self.string_literal_argument(0).ok_or_else(|| {
                     anyhow::anyhow!("{} `dataoffset` literal is missing", location)
                 })?;
                 let object_name = object_name.split('.').last().expect("Always exists");
                 era_compiler_llvm_context::evm_code::data_offset(context, object_name).map(Some)
             }
             Name::DataSize => {
-                let mut arguments = self.pop_arguments::<1>(context)?;
-                let object_name = arguments[0]
-                    .original
-                    .take()
+                let object_name = self
+                    .string_literal_argument(0)
                     .ok_or_else(|| anyhow::anyhow!("{} `datasize` literal is missing", location))?;
                 let object_name = object_name.split('.').last().expect("Always exists");
                 era_compiler_llvm_context::evm_code::data_size(context, object_name).map(Some)
@@ -694,8 +690,7 @@ impl FunctionCall {
             }
 
             Name::LinkerSymbol => {
-                let mut arguments = self.pop_arguments::<1>(context)?;
-                let path = arguments[0].original.take().ok_or_else(|| {
+                let path = self.string_literal_argument(0).ok_or_else(|| {
                     anyhow::anyhow!("{location} Linker symbol literal is missing")
                 })?;
                 era_compiler_llvm_context::evm_call::linker_symbol(context, path.as_str()).map(Some)
@@ -760,6 +755,11 @@ impl FunctionCall {
                 era_compiler_llvm_context::evm_contract_context::msize(context).map(Some)
             }
 
+            Name::Verbatim {
+                input_size,
+                output_size,
+            } => self.verbatim_into_llvm(context, input_size, output_size),
+
             Name::CallCode => {
                 let _arguments = self.pop_arguments_llvm::<7>(context)?;
                 anyhow::bail!("{location} The `CALLCODE` instruction is not supported")
@@ -774,6 +774,120 @@ impl FunctionCall {
         }
     }
 
+    ///
+    /// Emits the `verbatim_<N>i_<M>o` raw bytecode as inline assembly.
+    ///
+    /// The first argument must be a literal with the bytecode, and the rest are the instruction inputs.
+    ///
+    fn verbatim_into_llvm<'ctx>(
+        mut self,
+        context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+        input_size: usize,
+        output_size: usize,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        let location = self.0.location;
+
+        let bytecode = match self.0.arguments.remove(0) {
+            solx_yul::yul::parser::statement::expression::Expression::Literal(
+                solx_yul::yul::parser::statement::expression::literal::Literal {
+                    inner: solx_yul::yul::lexer::token::lexeme::literal::Literal::String(literal),
+                    ..
+                },
+            ) if literal.is_hexadecimal => {
+                hex::decode(literal.inner.as_str()).map_err(|error| {
+                    anyhow::anyhow!("{location} Invalid `verbatim` bytecode: {error}")
+                })?
+            }
+            solx_yul::yul::parser::statement::expression::Expression::Literal(
+                solx_yul::yul::parser::statement::expression::literal::Literal {
+                    inner: solx_yul::yul::lexer::token::lexeme::literal::Literal::String(literal),
+                    ..
+                },
+            ) => literal.inner.into_bytes(),
+            _ => anyhow::bail!("{location} The `verbatim` bytecode must be a string literal"),
+        };
+        if bytecode.is_empty() {
+            anyhow::bail!("{location} The `verbatim` bytecode must not be empty");
+        }
+        if output_size > 1 {
+            anyhow::bail!(
+                "{location} The `verbatim` instruction with more than one output is not supported"
+            );
+        }
+
+        let mut values: Vec<inkwell::values::BasicMetadataValueEnum<'ctx>> =
+            Vec::with_capacity(input_size);
+        for argument in self.0.arguments.into_iter().rev() {
+            let value = argument
+                .wrap()
+                .into_llvm(context)?
+                .expect("Always exists")
+                .value;
+            values.push(value.into());
+        }
+        values.reverse();
+
+        let input_types: Vec<inkwell::types::BasicMetadataTypeEnum<'ctx>> =
+            vec![context.field_type().into(); input_size];
+        let function_type = match output_size {
+            0 => context
+                .llvm()
+                .void_type()
+                .fn_type(input_types.as_slice(), false),
+            _ => context.field_type().fn_type(input_types.as_slice(), false),
+        };
+        let assembly = format!(
+            ".byte {}",
+            bytecode
+                .iter()
+                .map(|byte| format!("0x{byte:02x}"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        let constraints = match output_size {
+            0 => vec!["r"; input_size],
+            _ => std::iter::once("=r")
+                .chain(std::iter::repeat("r").take(input_size))
+                .collect::<Vec<&str>>(),
+        }
+        .join(",");
+        let inline_assembly = context.llvm().create_inline_asm(
+            function_type,
+            assembly,
+            constraints,
+            true,
+            false,
+            None,
+            false,
+        );
+
+        let call = context.builder().build_indirect_call(
+            function_type,
+            inline_assembly,
+            values.as_slice(),
+            "verbatim",
+        )?;
+        Ok(call.try_as_basic_value().left())
+    }
+
+    ///
+    /// Returns the string literal argument at `index`, if it is one.
+    ///
+    /// The literal is not converted into an LLVM value, as the builtins taking object or
+    /// immutable names accept literals longer than 32 bytes.
+    ///
+    fn string_literal_argument(&self, index: usize) -> Option<String> {
+        match self.0.arguments.get(index) {
+            Some(solx_yul::yul::parser::statement::expression::Expression::Literal(
+                solx_yul::yul::parser::statement::expression::literal::Literal {
+                    inner: solx_yul::yul::lexer::token::lexeme::literal::Literal::String(literal),
+                    ..
+                },
+            )) => Some(literal.inner.clone()),
+            _ => None,
+        }
+    }
+
     ///
     /// Pops the specified number of arguments, converted into their LLVM values.
     ///
@@ -61,18 +61,6 @@ impl Literal {
                     .unwrap_or_default()
                     .wrap()
                     .into_llvm(context);
-                let value = match inner {
-                    IntegerLiteral::Decimal { ref inner } => r#type.const_int_from_string(
-                        inner.as_str(),
-                        inkwell::types::StringRadix::Decimal,
-                    ),
-                    IntegerLiteral::Hexadecimal { ref inner } => r#type.const_int_from_string(
-                        &inner["0x".len()..],
-                        inkwell::types::StringRadix::Hexadecimal,
-                    ),
-                }
-                .expect("The value is valid")
-                .as_basic_value_enum();
 
                 let constant = match inner {
                     IntegerLiteral::Decimal { ref inner } => num::BigUint::from_str_radix(
@@ -85,6 +73,26 @@ impl Literal {
                     ),
                 }
                 .expect("Always valid");
+                if constant.bits() > r#type.get_bit_width() as u64 {
+                    anyhow::bail!(
+                        "{} Integer literal `{inner}` does not fit into {} bits",
+                        self.0.location,
+                        r#type.get_bit_width(),
+                    );
+                }
+
+                let value = match inner {
+                    IntegerLiteral::Decimal { ref inner } => r#type.const_int_from_string(
+                        inner.as_str(),
+                        inkwell::types::StringRadix::Decimal,
+                    ),
+                    IntegerLiteral::Hexadecimal { ref inner } => r#type.const_int_from_string(
+                        &inner["0x".len()..],
+                        inkwell::types::StringRadix::Hexadecimal,
+                    ),
+                }
+                .expect("The value is valid")
+                .as_basic_value_enum();
 
                 Ok(era_compiler_llvm_context::Value::new_with_constant(
                     value, constant,
@@ -128,10 +136,10 @@ impl Literal {
                                 let unicode_char = char::from_u32(codepoint).ok_or_else(|| {
                                     anyhow::anyhow!("Invalid codepoint {codepoint}")
                                 })?;
-                                let mut unicode_bytes = vec![0u8; 3];
-                                unicode_char.encode_utf8(&mut unicode_bytes);
+                                let mut unicode_bytes = [0u8; 4];
+                                let unicode_bytes = unicode_char.encode_utf8(&mut unicode_bytes);
 
-                                for byte in unicode_bytes.into_iter() {
+                                for byte in unicode_bytes.as_bytes().iter() {
                                     hex_string.push_str(format!("{:02x}", byte).as_str());
                                 }
                                 index += 5;
@@ -163,10 +171,12 @@ impl Literal {
                 };
 
                 if hex_string.len() > era_compiler_common::BYTE_LENGTH_FIELD * 2 {
-                    return Ok(era_compiler_llvm_context::Value::new_with_original(
-                        r#type.const_zero().as_basic_value_enum(),
-                        string,
-                    ));
+                    anyhow::bail!(
+                        "{} String literal is {} bytes long, exceeding the limit of {} bytes",
+                        self.0.location,
+                        hex_string.len() / 2,
+                        era_compiler_common::BYTE_LENGTH_FIELD,
+                    );
                 }
 
                 if hex_string.len() < era_compiler_common::BYTE_LENGTH_FIELD * 2 {
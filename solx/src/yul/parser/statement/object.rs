@@ -2,6 +2,9 @@
 //! The Yul object.
 //!
 
+use solx_yul::util::printer::write_printer::WritePrinter;
+use solx_yul::yul::visitor::Visitor;
+
 use crate::declare_wrapper;
 use crate::yul::parser::dialect::era::EraDialect;
 use crate::yul::parser::wrapper::Wrap;
@@ -11,6 +14,21 @@ declare_wrapper!(
     Object
 );
 
+impl Object {
+    ///
+    /// Prints the object back into reindented Yul source code.
+    ///
+    /// The output can be parsed again into an equivalent object, which is useful for
+    /// investigating parser issues by diffing the printed source code against the input.
+    ///
+    pub fn to_yul_string(&self) -> String {
+        let mut result = String::new();
+        let mut printer = WritePrinter::new(&mut result);
+        Visitor::<EraDialect>::visit_object(&mut printer, &self.0);
+        result
+    }
+}
+
 impl era_compiler_llvm_context::EVMWriteLLVM for Object {
     fn declare(
         &mut self,
@@ -26,3 +44,70 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Object {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use solx_yul::yul::lexer::Lexer;
+
+    use crate::yul::parser::dialect::era::EraDialect;
+    use crate::yul::parser::wrapper::Wrap;
+
+    fn parse(input: &str) -> super::Object {
+        let mut lexer = Lexer::new(input.to_owned());
+        solx_yul::yul::parser::statement::object::Object::<EraDialect>::parse(&mut lexer, None)
+            .expect("Test failure")
+            .wrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(64, 128)
+            if iszero(callvalue()) { revert(0, 0) }
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x, y := f(1, 0x2a)
+                switch x
+                case 0 { y := add(y, 1) }
+                case "one" { g() }
+                default { y := 0 }
+                for { let i := 0 } lt(i, 10) { i := add(i, 1) }
+                {
+                    if eq(i, 5) { break }
+                    continue
+                }
+                return(x, y)
+            }
+
+            function f(a, b) -> c, d {
+                c := a
+                d := b
+                leave
+            }
+
+            function g() {
+                mstore(0, true)
+            }
+        }
+    }
+}
+    "#;
+
+        let object = parse(input);
+        let printed = object.to_yul_string();
+        let reparsed = parse(printed.as_str());
+        assert_eq!(
+            reparsed.to_yul_string(),
+            printed,
+            "The printed source code must be parsed into an equivalent object"
+        );
+        assert_eq!(reparsed.0.identifier, object.0.identifier);
+    }
+}
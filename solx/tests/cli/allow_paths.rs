@@ -23,6 +23,26 @@ fn default() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn sibling_directory() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--allow-paths",
+        crate::common::TEST_SOLIDITY_CONTRACT_MONOREPO_DEPENDENCY_PATH,
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_MONOREPO_MAIN_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Main"))
+        .stdout(predicate::str::contains("Counter"));
+
+    Ok(())
+}
+
 #[test]
 fn yul() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -0,0 +1,55 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use test_case::test_case;
+
+#[test_case(crate::common::TEST_EVMLA_CONTRACT_PATH)]
+fn default(path: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[path, "--assembly", "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Test.sol:Test"))
+        .stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_input() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--assembly",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains("parsing"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVMLA_CONTRACT_PATH,
+        "--assembly",
+        "--standard-json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Only one mode is allowed at the same time",
+    ));
+
+    Ok(())
+}
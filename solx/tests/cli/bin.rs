@@ -19,6 +19,29 @@ fn invalid_input() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn duplicate_names() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_PATH,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains(
+            "Contract name `Test` is defined in multiple source files",
+        ))
+        .stderr(predicate::str::contains(
+            crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_PATH,
+        ));
+
+    Ok(())
+}
+
 #[test]
 fn standard_json() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -0,0 +1,85 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--bin-runtime",
+    ];
+
+    let result = crate::cli::execute_solx(args)?.success();
+    let stdout = String::from_utf8_lossy(result.get_output().stdout.as_slice()).into_owned();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    let creation_bytecode = lines
+        .iter()
+        .position(|line| *line == "Binary:")
+        .map(|index| lines[index + 1])
+        .expect("Test failure");
+    let runtime_bytecode = lines
+        .iter()
+        .position(|line| *line == "Binary of the runtime part:")
+        .map(|index| lines[index + 1])
+        .expect("Test failure");
+
+    assert!(hex::decode(creation_bytecode).is_ok());
+    assert!(hex::decode(runtime_bytecode).is_ok());
+    assert_ne!(creation_bytecode, runtime_bytecode);
+    assert!(creation_bytecode.ends_with(runtime_bytecode));
+
+    Ok(())
+}
+
+#[test]
+fn without_bin() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--bin-runtime"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary of the runtime part:"))
+        .stdout(predicate::str::contains("Binary:\n").not());
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin-runtime",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let output_directory = tmp_dir_solx
+        .path()
+        .join(crate::common::TEST_SOLIDITY_CONTRACT_NAME);
+    assert!(output_directory
+        .join(crate::common::SOLIDITY_BIN_RUNTIME_OUTPUT_NAME)
+        .exists());
+    assert!(!output_directory
+        .join(crate::common::SOLIDITY_BIN_OUTPUT_NAME)
+        .exists());
+
+    Ok(())
+}
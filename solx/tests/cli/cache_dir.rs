@@ -0,0 +1,95 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--cache-dir",
+        tmp_dir_cache.path().to_str().unwrap(),
+    ];
+
+    let first = crate::cli::execute_solx(args)?.success();
+    assert!(std::fs::read_dir(tmp_dir_cache.path())?.next().is_some());
+
+    let second = crate::cli::execute_solx(args)?.success();
+    assert_eq!(first.get_output().stdout, second.get_output().stdout);
+
+    Ok(())
+}
+
+#[test]
+fn settings_changed() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("solx_cache")?;
+
+    let mut entry_counts = Vec::with_capacity(2);
+    for optimization in ["-O1", "-Oz"] {
+        let args = &[
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            "--bin",
+            optimization,
+            "--cache-dir",
+            tmp_dir_cache.path().to_str().unwrap(),
+        ];
+
+        crate::cli::execute_solx(args)?.success();
+        entry_counts.push(std::fs::read_dir(tmp_dir_cache.path())?.count());
+    }
+    assert_eq!(entry_counts[1], entry_counts[0] * 2);
+
+    Ok(())
+}
+
+#[test]
+fn store_failure() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_file_cache = tempfile::NamedTempFile::with_prefix("solx_cache")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--cache-dir",
+        tmp_file_cache.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary"))
+        .stderr(predicate::str::contains(
+            "could not be stored in the cache directory",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--cache-dir",
+        "cache",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Compilation cache is only allowed in Solidity mode.",
+    ));
+
+    Ok(())
+}
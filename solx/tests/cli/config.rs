@@ -0,0 +1,141 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+///
+/// Writes the config file with `content` to a temporary directory.
+///
+fn write_config(content: &str) -> anyhow::Result<(TempDir, String)> {
+    let tmp_dir = TempDir::with_prefix("solx_config")?;
+    let path = tmp_dir.path().join("solx.json");
+    std::fs::write(path.as_path(), content)?;
+    Ok((tmp_dir, path.to_string_lossy().to_string()))
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config(r#"{ "bin": true, "optimization": "z" }"#)?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn cli_override() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config(r#"{ "optimization": "9" }"#)?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "-O3",
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_key() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config(r#"{ "binary": true }"#)?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("unknown key `binary`"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_value() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config(r#"{ "bin": "yes" }"#)?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("key `bin`: invalid type"));
+
+    Ok(())
+}
+
+#[test]
+fn remappings_yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config(r#"{ "remappings": ["lib/=node_modules/lib/"] }"#)?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--bin",
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "key `remappings`: remappings are only allowed in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config("{}")?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Config file cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+        "--emit-contract-json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let artifact = std::fs::read_to_string(tmp_dir_solx.path().join("Test.sol/Test.json"))?;
+    let artifact: serde_json::Value = serde_json::from_str(artifact.as_str())?;
+    assert_eq!(artifact["contractName"].as_str(), Some("Test"));
+    assert_eq!(
+        artifact["sourceName"].as_str(),
+        Some(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+    );
+    assert!(artifact["abi"].is_array());
+    assert!(artifact["bytecode"]
+        .as_str()
+        .is_some_and(|bytecode| bytecode.starts_with("0x") && bytecode.len() > 2));
+    assert!(artifact["deployedBytecode"]
+        .as_str()
+        .is_some_and(|bytecode| bytecode.starts_with("0x") && bytecode.len() > 2));
+    assert!(artifact["metadata"].is_string());
+    assert!(!tmp_dir_solx.path().join("Test.sol/Test.bin").exists());
+
+    Ok(())
+}
+
+#[test]
+fn deterministic() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut artifacts = Vec::with_capacity(2);
+    for _ in 0..2 {
+        let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+        let args = &[
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            "--output-dir",
+            tmp_dir_solx.path().to_str().unwrap(),
+            "--emit-contract-json",
+        ];
+
+        let result = crate::cli::execute_solx(args)?;
+        result.success();
+
+        artifacts.push(std::fs::read(
+            tmp_dir_solx.path().join("Test.sol/Test.json"),
+        )?);
+    }
+    assert_eq!(artifacts[0], artifacts[1]);
+
+    Ok(())
+}
+
+#[test]
+fn no_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--emit-contract-json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Contract JSON artifacts are only allowed together with the output directory.",
+    ));
+
+    Ok(())
+}
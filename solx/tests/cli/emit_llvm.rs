@@ -0,0 +1,110 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--emit-llvm"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("LLVM IR:\n"))
+        .stdout(predicate::str::contains("LLVM IR of the runtime part:\n"))
+        .stdout(predicate::str::contains("define "))
+        .stdout(predicate::str::contains("Binary:").not());
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--emit-llvm",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("LLVM IR of the runtime part:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--emit-llvm",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let output_directory = tmp_dir_solx.path().join("Test.sol");
+    for file_name in [
+        format!("Test.{}", era_compiler_common::EXTENSION_LLVM_SOURCE),
+        format!(
+            "Test-runtime.{}",
+            era_compiler_common::EXTENSION_LLVM_SOURCE
+        ),
+    ] {
+        let content = std::fs::read_to_string(output_directory.join(file_name))?;
+        assert!(content.contains("define "));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        "--llvm-ir",
+        "--emit-llvm",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM IR output is not available in LLVM IR mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--emit-llvm",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
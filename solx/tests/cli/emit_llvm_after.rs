@@ -0,0 +1,66 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--emit-llvm-after",
+        "instcombine",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "LLVM IR of the runtime part after `InstCombinePass`:\n",
+        ))
+        .stdout(predicate::str::contains(
+            "LLVM IR of the deploy part after `InstCombinePass`:\n",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        "--llvm-ir",
+        "--emit-llvm-after",
+        "instcombine",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM IR output is not available in LLVM IR mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--emit-llvm-after",
+        "instcombine",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
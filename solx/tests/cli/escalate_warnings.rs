@@ -0,0 +1,84 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_WARNINGS_PATH,
+        "--bin",
+        "--escalate-warnings",
+        "2072",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(
+        predicate::str::contains("Error").and(predicate::str::contains("Unused local variable")),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn other_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_WARNINGS_PATH,
+        "--bin",
+        "--escalate-warnings",
+        "5667,1234",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:"))
+        .stderr(predicate::str::contains("Unused local variable"));
+
+    Ok(())
+}
+
+#[test]
+fn suppressed() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_WARNINGS_PATH,
+        "--bin",
+        "--suppress-warnings",
+        "2072",
+        "--escalate-warnings",
+        "2072",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Warning code `2072` cannot be both suppressed and escalated.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_WARNINGS_PATH,
+        "--bin",
+        "--escalate-warnings",
+        "timestamp",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid warning code `timestamp`.",
+    ));
+
+    Ok(())
+}
@@ -24,6 +24,25 @@ fn default() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn unsupported_instructions() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--evm-version",
+        "homestead",
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "uses instructions unavailable in EVM version `homestead`",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn yul() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -21,6 +21,22 @@ fn no_arguments() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn exit_codes_help() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--help"];
+
+    let result = crate::cli::execute_solx(args)?;
+
+    result
+        .stderr(predicate::str::contains("Exit codes:"))
+        .stderr(predicate::str::contains("4  Linker error"))
+        .stderr(predicate::str::contains("5  Timeout"));
+
+    Ok(())
+}
+
 #[test_case(crate::common::SOLIDITY_BIN_OUTPUT_NAME)]
 fn multiple_output_options(bin_output_file_name: &str) -> anyhow::Result<()> {
     crate::common::setup()?;
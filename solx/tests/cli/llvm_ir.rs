@@ -98,6 +98,31 @@ fn linker_error() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn linker_error_keep_going() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        "--keep-going",
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        crate::common::TEST_LLVM_IR_CONTRACT_LINKER_ERROR_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .stdout(predicate::str::contains(
+            crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        ))
+        .stdout(predicate::str::contains("Binary"))
+        .stderr(predicate::str::contains(
+            "unable to evaluate offset to undefined symbol",
+        ));
+
+    Ok(())
+}
+
 #[test]
 fn excess_mode_standard_json() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -0,0 +1,88 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+        "--manifest",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let manifest = std::fs::read_to_string(tmp_dir_solx.path().join("manifest.json"))?;
+    let manifest: serde_json::Value = serde_json::from_str(manifest.as_str())?;
+    let artifacts = manifest["artifacts"].as_object().expect("Test failure");
+    assert!(!artifacts.is_empty());
+    for (path, artifact) in artifacts.iter() {
+        let content = std::fs::read(tmp_dir_solx.path().join(path))?;
+        assert_eq!(artifact["size"].as_u64(), Some(content.len() as u64));
+        assert_eq!(artifact["sha256"].as_str().map(|hash| hash.len()), Some(64));
+    }
+    assert_eq!(manifest["compiler"]["name"].as_str(), Some("solx"));
+    assert_eq!(
+        manifest["solxOutputVersion"].as_u64(),
+        Some(solx_standard_json::Output::VERSION as u64)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deterministic() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut manifests = Vec::with_capacity(2);
+    for _ in 0..2 {
+        let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+        let args = &[
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            "--bin",
+            "--metadata",
+            "--output-dir",
+            tmp_dir_solx.path().to_str().unwrap(),
+            "--manifest",
+        ];
+
+        let result = crate::cli::execute_solx(args)?;
+        result.success();
+
+        manifests.push(std::fs::read(tmp_dir_solx.path().join("manifest.json"))?);
+    }
+    assert_eq!(manifests[0], manifests[1]);
+
+    Ok(())
+}
+
+#[test]
+fn output_dir_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--manifest",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Build manifest is only allowed together with the output directory.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--metadata",
+        "--metadata-compiler-version",
+        "0.8.99+commit.00000000",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            r#""compiler":{"version":"0.8.99+commit.00000000"}"#,
+        ))
+        .stderr(predicate::str::contains(
+            "The metadata compiler version is overridden with `0.8.99+commit.00000000`",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--metadata-compiler-version",
+        "0.8.99",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Metadata compiler version must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--metadata",
+        "--metadata-extra",
+        crate::common::TEST_JSON_METADATA_EXTRA_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Metadata"))
+        .stdout(predicate::str::contains("\"provenance\""))
+        .stdout(predicate::str::contains("0123456789abcdef"));
+
+    Ok(())
+}
+
+#[test]
+fn reserved_key() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--metadata",
+        "--metadata-extra",
+        crate::common::TEST_JSON_METADATA_EXTRA_RESERVED_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Extra metadata key `compiler` is reserved.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--metadata-extra",
+        crate::common::TEST_JSON_METADATA_EXTRA_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Extra metadata cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
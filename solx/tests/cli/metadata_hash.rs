@@ -18,7 +18,10 @@ fn none() -> anyhow::Result<()> {
     ];
 
     let result = crate::cli::execute_solx(args)?;
-    result.success().stdout(predicate::str::contains("a164"));
+    result
+        .success()
+        .stdout(predicate::str::contains("a164736f6c6378"))
+        .stdout(predicate::str::contains("a264697066735822").not());
 
     Ok(())
 }
@@ -36,7 +39,28 @@ fn ipfs() -> anyhow::Result<()> {
     ];
 
     let result = crate::cli::execute_solx(args)?;
-    result.success().stdout(predicate::str::contains("a264"));
+    result
+        .success()
+        .stdout(predicate::str::contains("a264697066735822"));
+
+    Ok(())
+}
+
+#[test]
+fn bzzr1() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--metadata-hash",
+        "bzzr1",
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Metadata hash `bzzr1` is not supported.",
+    ));
 
     Ok(())
 }
@@ -0,0 +1,51 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+        "--mirror-source-tree",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    assert!(tmp_dir_solx
+        .path()
+        .join(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+        .is_dir());
+
+    Ok(())
+}
+
+#[test]
+fn output_dir_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--mirror-source-tree",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Mirroring the source tree is only allowed together with the output directory.",
+    ));
+
+    Ok(())
+}
@@ -7,31 +7,68 @@ use std::process::Command;
 use assert_cmd::assert::OutputAssertExt;
 use assert_cmd::cargo::CommandCargoExt;
 
+mod abi;
 mod allow_paths;
+mod assembly;
 mod base_path;
 mod bin;
+mod bin_runtime;
+mod cache_dir;
+mod color;
+mod config;
 mod debug_output_dir;
+mod emit_contract_json;
+mod emit_llvm;
+mod emit_llvm_after;
+mod eof;
+mod escalate_warnings;
 mod evm_version;
+mod expect_solc;
 mod general;
+mod hex_prefix;
+mod hex_uppercase;
 mod include_path;
+mod keep_going;
 mod libraries;
 mod llvm_ir;
 mod llvm_options;
+mod manifest;
 mod metadata;
+mod metadata_compiler_version;
+mod metadata_extra;
 mod metadata_hash;
 mod metadata_literal;
+mod mirror_source_tree;
 mod no_cbor_metadata;
+mod no_optimize;
+mod only_changed;
 mod optimization;
 mod optimization_size_fallback;
+mod optimize_contract;
 mod output_dir;
+mod output_filename_template;
 mod overwrite;
+mod pretty_json;
 mod recursive_process;
 mod remappings;
+mod require_linked;
+mod runtime_only;
+mod show_suppressed;
+mod size_advisory;
+mod size_report;
 mod standard_json;
+mod stdin_path;
+mod strict_settings;
+mod strict_size_limit;
+mod suppress_warnings;
+mod target;
 mod threads;
+mod timeout;
 mod version;
 mod via_ir;
+mod watch;
 mod yul;
+mod yul_dialect;
 
 ///
 /// Execute `solx` with the given arguments and assert the result.
@@ -41,6 +78,18 @@ pub fn execute_solx(args: &[&str]) -> anyhow::Result<assert_cmd::assert::Assert>
     Ok(cmd.args(args).assert())
 }
 
+///
+/// Execute `solx` with the given arguments and standard input, and assert the result.
+///
+pub fn execute_solx_with_stdin(
+    args: &[&str],
+    stdin_path: &str,
+) -> anyhow::Result<assert_cmd::assert::Assert> {
+    let content = std::fs::read_to_string(stdin_path)?;
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    Ok(cmd.args(args).write_stdin(content).assert())
+}
+
 ///
 /// Check if the file at the given path is empty.
 ///
@@ -109,7 +109,26 @@ fn standard_json() -> anyhow::Result<()> {
     result
         .success()
         .stdout(predicate::str::contains("a264").not())
-        .stdout(predicate::str::ends_with("0055").not());
+        .stdout(predicate::str::ends_with("0055").not())
+        .stdout(predicate::str::contains("\"cborMetadata\":false"))
+        .stdout(predicate::str::contains("\"cborMetadata\":true").not());
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_appended() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"cborMetadata\":true"));
 
     Ok(())
 }
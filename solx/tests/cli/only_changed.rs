@@ -0,0 +1,99 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+        "--only-changed",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("0 unchanged artifact(s) skipped."));
+
+    Ok(())
+}
+
+#[test]
+fn unchanged_files_preserved() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+        "--only-changed",
+    ];
+
+    crate::cli::execute_solx(args)?.success();
+    let modified_times = std::fs::read_dir(
+        tmp_dir_solx
+            .path()
+            .join(crate::common::TEST_SOLIDITY_CONTRACT_NAME),
+    )
+    .expect("Test failure")
+    .map(|entry| {
+        let entry = entry.expect("Test failure");
+        (
+            entry.path(),
+            entry
+                .metadata()
+                .expect("Test failure")
+                .modified()
+                .expect("Test failure"),
+        )
+    })
+    .collect::<Vec<_>>();
+    assert!(!modified_times.is_empty());
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("0 artifact(s) written"));
+
+    for (path, modified_time) in modified_times.into_iter() {
+        assert_eq!(
+            std::fs::metadata(path.as_path())
+                .expect("Test failure")
+                .modified()
+                .expect("Test failure"),
+            modified_time,
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn output_dir_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--only-changed",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Writing only the changed artifacts is only allowed together with the output directory.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+        "--output-filename-template",
+        "{path}__{name}.{ext}",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    assert!(tmp_dir_solx
+        .path()
+        .join(format!(
+            "{}__{}",
+            crate::common::TEST_SOLIDITY_CONTRACT_NAME,
+            crate::common::SOLIDITY_BIN_OUTPUT_NAME
+        ))
+        .is_file());
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+        "--output-filename-template",
+        "../{name}.{ext}",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "must produce paths inside the output directory",
+    ));
+
+    assert!(std::fs::read_dir(tmp_dir_solx.path())?.next().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn output_dir_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-filename-template",
+        "{name}.{ext}",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Output file name template is only allowed together with the output directory.",
+    ));
+
+    Ok(())
+}
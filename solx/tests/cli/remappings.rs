@@ -22,6 +22,47 @@ fn default() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn applied() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let remapping = format!(
+        "@dependency/={}/",
+        crate::common::TEST_SOLIDITY_CONTRACT_MONOREPO_DEPENDENCY_PATH
+    );
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_MONOREPO_REMAPPED_PATH,
+        remapping.as_str(),
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Counter"))
+        .stderr(predicate::str::contains("is not applied to any import").not());
+
+    Ok(())
+}
+
+#[test]
+fn unused() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "./path/to/1.sol=./path/to/2.sol",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("is not applied to any import"));
+
+    Ok(())
+}
+
 #[test]
 fn excess_equals_sign() -> anyhow::Result<()> {
     crate::common::setup()?;
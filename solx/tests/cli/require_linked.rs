@@ -0,0 +1,85 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let library = format!(
+        "{}:UpperLibrary=0xF9702469Dfb84A9aC171E284F71615bd3D3f1EdC",
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH
+    );
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+        "--bin",
+        "--libraries",
+        library.as_str(),
+        "--require-linked",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn unlinked() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+        "--bin",
+        "--require-linked",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "remains unlinked, as the following libraries are not provided",
+        ))
+        .stderr(predicate::str::contains("UpperContract"))
+        .stderr(predicate::str::contains("UpperLibrary"));
+
+    Ok(())
+}
+
+#[test]
+fn unlinked_exit_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+        "--bin",
+        "--require-linked",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .code(solx_standard_json::OutputErrorExitReason::EXIT_CODE_LINKER);
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--require-linked",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Requiring linked contracts cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--runtime-only",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn no_output_requested() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--runtime-only"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("No output generated."));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::new()?;
+    let tmp_dir_runtime_only = TempDir::new()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--runtime-only",
+        "--output-dir",
+        tmp_dir_runtime_only.path().to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let bin_output_file_path = |directory: &TempDir| {
+        directory
+            .path()
+            .join(crate::common::TEST_SOLIDITY_CONTRACT_NAME)
+            .join(crate::common::SOLIDITY_BIN_OUTPUT_NAME)
+    };
+    let bytecode = std::fs::read_to_string(bin_output_file_path(&tmp_dir))?;
+    let runtime_bytecode = std::fs::read_to_string(bin_output_file_path(&tmp_dir_runtime_only))?;
+    assert!(!runtime_bytecode.is_empty());
+    assert!(runtime_bytecode.len() < bytecode.len());
+    assert!(bytecode.ends_with(runtime_bytecode.as_str()));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--runtime-only",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"deployedBytecode\""))
+        .stdout(predicate::str::contains("\"bytecode\"").not());
+
+    Ok(())
+}
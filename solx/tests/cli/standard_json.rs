@@ -21,6 +21,24 @@ fn default() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn output_version() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(format!(
+        "\"solxOutputVersion\":{}",
+        solx_standard_json::Output::VERSION
+    )));
+
+    Ok(())
+}
+
 #[test]
 fn invalid_input_yul() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -154,6 +172,23 @@ fn missing_sources() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn invalid_schema() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_INVALID_SCHEMA_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "settings.optimizer.runs must be a non-negative integer",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn yul() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -171,6 +206,24 @@ fn yul() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn yul_empty_object() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_STANDARD_JSON_EMPTY_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("bytecode"))
+        .stdout(predicate::str::contains("Object `Empty` is empty"));
+
+    Ok(())
+}
+
 #[test]
 fn metadata_hash_ipfs_and_metadata() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -242,3 +295,117 @@ fn metadata_hash_none_no_metadata() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn gas_estimates() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_GAS_ESTIMATES_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"codeDepositCost\""))
+        .stdout(predicate::str::contains("infinite").not())
+        .stdout(predicate::str::contains("methodIdentifiers").not());
+
+    Ok(())
+}
+
+#[test]
+fn multiple_inputs() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--standard-json",
+        crate::common::TEST_YUL_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"A\""))
+        .stdout(predicate::str::contains("\"Test\""))
+        .stdout(predicate::str::contains("bytecode"));
+
+    Ok(())
+}
+
+#[test]
+fn multiple_inputs_collision() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "is defined in multiple standard JSON inputs",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn multiple_inputs_invalid_path() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--standard-json",
+        "tests/data/standard_json_input/non_existent.json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"A\""))
+        .stdout(predicate::str::contains("non_existent.json"));
+
+    Ok(())
+}
+
+#[test]
+fn ast() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"ast\":"))
+        .stdout(predicate::str::contains("\"nodeType\":\"SourceUnit\""));
+
+    Ok(())
+}
+
+#[test]
+fn ast_not_selected() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_GAS_ESTIMATES_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"ast\":").not());
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["-", "--bin"];
+
+    let result =
+        crate::cli::execute_solx_with_stdin(args, crate::common::TEST_SOLIDITY_CONTRACT_PATH)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("======= stdin.sol:Test ======="));
+
+    Ok(())
+}
+
+#[test]
+fn custom() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["-", "--bin", "--stdin-path", "contracts/Piped.sol"];
+
+    let result =
+        crate::cli::execute_solx_with_stdin(args, crate::common::TEST_SOLIDITY_CONTRACT_PATH)?;
+    result.success().stdout(predicate::str::contains(
+        "======= contracts/Piped.sol:Test =======",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["-", "--yul", "--bin", "--stdin-path", "Piped.yul"];
+
+    let result = crate::cli::execute_solx_with_stdin(args, crate::common::TEST_YUL_CONTRACT_PATH)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("======= Piped.yul"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--stdin-path",
+        "stdin.sol",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Standard input path cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_WARNINGS_PATH,
+        "--bin",
+        "--suppress-warnings",
+        "2072",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:"))
+        .stderr(predicate::str::contains("Unused local variable").not());
+
+    Ok(())
+}
+
+#[test]
+fn other_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_WARNINGS_PATH,
+        "--bin",
+        "--suppress-warnings",
+        "5667,1234",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Unused local variable"));
+
+    Ok(())
+}
+
+#[test]
+fn errors_not_suppressed() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--evm-version",
+        "cancun",
+        "--yul",
+        "--suppress-warnings",
+        "2072",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "EVM version is only allowed in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_WARNINGS_PATH,
+        "--bin",
+        "--suppress-warnings",
+        "2072,unused",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid warning code `unused`. Warning codes must be numeric, e.g. `2072`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_WARNINGS_PATH,
+        "--suppress-warnings",
+        "2072",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Unused local variable").not())
+        .stdout(predicate::str::contains("bytecode"));
+
+    Ok(())
+}
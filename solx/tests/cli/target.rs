@@ -0,0 +1,76 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let target = format!(
+        "{}:Main",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH
+    );
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--bin",
+        "--target",
+        target.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "======= {target} ======="
+        )))
+        .stdout(predicate::str::contains("Callable =======").not());
+
+    Ok(())
+}
+
+#[test]
+fn not_found() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--target",
+        "Unknown.sol:Unknown",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "Target contract `Unknown.sol:Unknown` not found. Available contracts:",
+        ))
+        .stderr(predicate::str::contains(format!(
+            "`{}:Test`",
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--bin",
+        "--target",
+        "Test",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Target contract is only allowed in Solidity mode.",
+    ));
+
+    Ok(())
+}
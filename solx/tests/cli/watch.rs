@@ -0,0 +1,37 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn stdin() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["-", "--bin", "--watch"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Watch mode requires input files, as neither the standard input nor linked bytecode can be watched.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--watch",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Watch mode is not allowed in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -28,6 +28,25 @@ fn solc() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn empty() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_EMPTY_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary"))
+        .stderr(predicate::str::contains("Object `Empty` is empty"));
+
+    Ok(())
+}
+
 #[test]
 fn invalid_input() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -37,6 +56,7 @@ fn invalid_input() -> anyhow::Result<()> {
     let result = crate::cli::execute_solx(args)?;
     result
         .failure()
+        .code(solx_standard_json::OutputErrorExitReason::EXIT_CODE_SYNTAX)
         .stderr(predicate::str::contains("Yul parsing"));
 
     Ok(())
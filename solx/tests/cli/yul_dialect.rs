@@ -0,0 +1,85 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use test_case::test_case;
+
+#[test_case("era")]
+#[test_case("evm")]
+fn default(dialect: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--yul-dialect",
+        dialect,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn era_extensions() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_ATTRIBUTES_PATH,
+        "--yul",
+        "--yul-dialect",
+        "era",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn evm_extensions() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_ATTRIBUTES_PATH,
+        "--yul",
+        "--yul-dialect",
+        "evm",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .code(solx_standard_json::OutputErrorExitReason::EXIT_CODE_SYNTAX)
+        .stderr(predicate::str::contains(
+            "not supported in the `evm` dialect",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn solidity() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--yul-dialect",
+        "evm",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Yul dialect is only allowed in Yul mode.",
+    ));
+
+    Ok(())
+}
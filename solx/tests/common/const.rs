@@ -22,6 +22,18 @@ pub const TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH: &str =
     "tests/data/contracts/solidity/caller/Callable.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_MONOREPO_MAIN_PATH: &str =
+    "tests/data/contracts/solidity/monorepo/project/Main.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_MONOREPO_REMAPPED_PATH: &str =
+    "tests/data/contracts/solidity/monorepo/project/Remapped.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_MONOREPO_DEPENDENCY_PATH: &str =
+    "tests/data/contracts/solidity/monorepo/dependency";
+
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH: &str =
     "tests/data/contracts/solidity/SimpleContract.sol";
@@ -42,12 +54,28 @@ pub const TEST_SOLIDITY_CONTRACT_OPTIMIZED_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_INTERFACE_EMPTY_YUL_PATH: &str =
     "tests/data/contracts/solidity/InterfaceEmptyYul.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_WARNINGS_PATH: &str = "tests/data/contracts/solidity/Warnings.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_DUPLICATE_PATH: &str =
+    "tests/data/contracts/solidity/duplicate/Test.sol";
+
 /// A test input file.
 pub const SOLIDITY_BIN_OUTPUT_NAME: &str = "Test.bin";
 
+/// A test input file.
+pub const SOLIDITY_BIN_RUNTIME_OUTPUT_NAME: &str = "Test.bin-runtime";
+
 /// A test input file.
 pub const TEST_YUL_CONTRACT_PATH: &str = "tests/data/contracts/yul/Test.yul";
 
+/// A test input file.
+pub const TEST_YUL_CONTRACT_ATTRIBUTES_PATH: &str = "tests/data/contracts/yul/Attributes.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_EMPTY_PATH: &str = "tests/data/contracts/yul/Empty.yul";
+
 /// A test input file.
 pub const TEST_LLVM_IR_CONTRACT_PATH: &str = "tests/data/contracts/llvm_ir/Test.ll";
 
@@ -58,6 +86,9 @@ pub const TEST_LLVM_IR_CONTRACT_INVALID_PATH: &str = "tests/data/contracts/llvm_
 pub const TEST_LLVM_IR_CONTRACT_LINKER_ERROR_PATH: &str =
     "tests/data/contracts/llvm_ir/LinkerError.ll";
 
+/// A test input file.
+pub const TEST_EVMLA_CONTRACT_PATH: &str = "tests/data/contracts/evmla/Test.asm";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_NON_EXISTENT_PATH: &str =
     "tests/data/standard_json_input/non_existent.json";
@@ -70,6 +101,10 @@ pub const TEST_SOLIDITY_STANDARD_JSON_INVALID_UTF8_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH: &str =
     "tests/data/standard_json_input/solidity_solc.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_GAS_ESTIMATES_PATH: &str =
+    "tests/data/standard_json_input/gas_estimates.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_EMPTY_SOURCES_PATH: &str =
     "tests/data/standard_json_input/solidity_solc_empty_sources.json";
@@ -78,6 +113,18 @@ pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_EMPTY_SOURCES_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_MISSING_SOURCES_PATH: &str =
     "tests/data/standard_json_input/solidity_solc_missing_sources.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_INVALID_SCHEMA_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_invalid_schema.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_UNKNOWN_SETTINGS_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_unknown_settings.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_WARNINGS_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_warnings.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_INVALID_PATH: &str =
     "tests/data/standard_json_input/solidity_solc_invalid.json";
@@ -93,6 +140,9 @@ pub const TEST_SOLIDITY_STANDARD_JSON_SOLX_INVALID_PATH: &str =
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_SOLC_PATH: &str = "tests/data/standard_json_input/yul_solc.json";
 
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_EMPTY_PATH: &str = "tests/data/standard_json_input/yul_empty.json";
+
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_SOLC_INVALID_PATH: &str =
     "tests/data/standard_json_input/yul_solc_urls_invalid.json";
@@ -128,6 +178,12 @@ pub const TEST_JSON_METADATA_HASH_NONE_NO_METADATA: &str =
 /// A test input file.
 pub const TEST_JSON_NO_CBOR_METADATA: &str = "tests/data/standard_json_input/no_cbor_metadata.json";
 
+/// A test input file.
+pub const TEST_JSON_METADATA_EXTRA_PATH: &str = "tests/data/metadata_extra/valid.json";
+
+/// A test input file.
+pub const TEST_JSON_METADATA_EXTRA_RESERVED_PATH: &str = "tests/data/metadata_extra/reserved.json";
+
 /// A test input file.
 pub const TEST_LINKER_BYTECODE_PATH: &str = "tests/data/bytecodes/linker.bin";
 
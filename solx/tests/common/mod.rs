@@ -61,6 +61,30 @@ pub fn build_solidity_standard_json(
     via_ir: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
 ) -> anyhow::Result<solx_standard_json::Output> {
+    let (mut solc_output, build) = self::build_solidity(
+        sources,
+        libraries,
+        metadata_hash_type,
+        remappings,
+        via_ir,
+        optimizer_settings,
+    )?;
+    build.write_to_standard_json(&mut solc_output)?;
+    solc_output.check_errors()?;
+    Ok(solc_output)
+}
+
+///
+/// Builds the Solidity project and returns the `solc` standard JSON output and the linked build.
+///
+pub fn build_solidity(
+    sources: BTreeMap<String, String>,
+    libraries: era_compiler_common::Libraries,
+    metadata_hash_type: era_compiler_common::EVMMetadataHashType,
+    remappings: BTreeSet<String>,
+    via_ir: bool,
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+) -> anyhow::Result<(solx_standard_json::Output, solx::EVMBuild)> {
     self::setup()?;
 
     let solc_compiler = solx_solc::Compiler::default();
@@ -121,9 +145,65 @@ pub fn build_solidity_standard_json(
     ];
 
     let build = build.link(linker_symbols, Some(cbor_data));
-    build.write_to_standard_json(&mut solc_output)?;
-    solc_output.check_errors()?;
-    Ok(solc_output)
+    build.check_errors()?;
+    Ok((solc_output, build))
+}
+
+///
+/// Builds the Solidity files at `paths` with the default settings and returns the linked build.
+///
+pub fn build_solidity_default(paths: &[&str]) -> solx::EVMBuild {
+    let sources = self::read_sources(paths);
+
+    let (_solc_output, build) = self::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    build
+}
+
+///
+/// Builds the Yul `sources` without optimizations and returns the unlinked build.
+///
+/// The build errors are not checked, so they can be inspected in the contract results.
+///
+pub fn build_yul(
+    sources: BTreeMap<String, String>,
+    options: solx::CompileOptions,
+) -> solx::EVMBuild {
+    self::setup().expect("Test failure");
+
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EVM);
+
+    let sources = sources
+        .into_iter()
+        .map(|(path, source)| (path, solx_standard_json::InputSource::from(source)))
+        .collect();
+    let mut project = Project::try_from_yul_sources(
+        sources,
+        era_compiler_common::Libraries::default(),
+        &solx_standard_json::InputSelection::new_compilation(true, false, Some(true)),
+        solx::YulDialect::Era,
+        None,
+        None,
+    )
+    .expect("Test failure");
+    project.set_options(options).expect("Test failure");
+    project
+        .compile_to_evm(
+            &mut vec![],
+            true,
+            era_compiler_common::EVMMetadataHashType::None,
+            era_compiler_llvm_context::OptimizerSettings::none(),
+            vec![],
+            None,
+        )
+        .expect("Test failure")
 }
 
 ///
@@ -153,6 +233,7 @@ pub fn build_yul_standard_json(
         solc_input.sources,
         era_compiler_common::Libraries::default(),
         &solc_input.settings.output_selection,
+        solx::YulDialect::Era,
         Some(&mut solc_output),
         None,
     )?;
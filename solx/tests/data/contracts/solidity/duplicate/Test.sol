@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.4.12;
+
+contract Test {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.4.12;
+
+contract Counter {
+    uint256 value;
+
+    function increment() external {
+        value += 1;
+    }
+}
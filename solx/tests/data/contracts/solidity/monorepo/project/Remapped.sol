@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.4.12;
+
+import "@dependency/Counter.sol";
+
+contract Remapped {
+    function main() external {
+        new Counter().increment();
+    }
+}
@@ -0,0 +1,120 @@
+//!
+//! Unit tests for the build artifacts archive.
+//!
+
+use std::path::Path;
+
+use test_case::test_case;
+
+#[test_case(solx::ArchiveFormat::Tar)]
+#[test_case(solx::ArchiveFormat::Zip)]
+fn default(format: solx::ArchiveFormat) {
+    let build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let plan = build.plan_directory_writes(Path::new(""), true, false);
+    assert!(!plan.is_empty(), "The plan must not be empty");
+
+    let mut archive = Vec::new();
+    build
+        .write_to_archive(None, &mut archive, format, true, false)
+        .expect("Test failure");
+
+    match format {
+        solx::ArchiveFormat::Tar => {
+            assert_eq!(
+                archive.len() % 512,
+                0,
+                "The tar archive must consist of blocks"
+            );
+            assert!(
+                archive.ends_with(&[0u8; 1024]),
+                "The tar archive must end with two empty blocks"
+            );
+        }
+        solx::ArchiveFormat::Zip => {
+            assert!(
+                archive.starts_with(b"PK\x03\x04"),
+                "The ZIP archive must start with a local file header"
+            );
+        }
+    }
+    for (path, _exists) in plan.into_iter() {
+        let path = path.to_string_lossy().to_string();
+        assert!(
+            archive
+                .windows(path.len())
+                .any(|window| window == path.as_bytes()),
+            "The archive must contain the entry `{path}`"
+        );
+    }
+}
+
+#[test_case(solx::ArchiveFormat::Tar)]
+#[test_case(solx::ArchiveFormat::Zip)]
+fn reproducible(format: solx::ArchiveFormat) {
+    let mut archive_1 = Vec::new();
+    crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH])
+        .write_to_archive(None, &mut archive_1, format, true, false)
+        .expect("Test failure");
+
+    let mut archive_2 = Vec::new();
+    crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH])
+        .write_to_archive(None, &mut archive_2, format, true, false)
+        .expect("Test failure");
+
+    assert_eq!(archive_1, archive_2, "The archives must be identical");
+}
+
+#[test_case(solx::ArchiveFormat::Tar)]
+#[test_case(solx::ArchiveFormat::Zip)]
+fn manifest(format: solx::ArchiveFormat) {
+    let mut build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    build.set_options(solx::EVMBuildOptions {
+        manifest: true,
+        ..Default::default()
+    });
+
+    let mut archive = Vec::new();
+    build
+        .write_to_archive(None, &mut archive, format, true, false)
+        .expect("Test failure");
+
+    let path = solx::EVMBuildManifest::FILE_NAME;
+    assert!(
+        archive
+            .windows(path.len())
+            .any(|window| window == path.as_bytes()),
+        "The archive must contain the manifest"
+    );
+}
+
+#[test_case(solx::ArchiveFormat::Tar)]
+#[test_case(solx::ArchiveFormat::Zip)]
+fn selection_unmatched(format: solx::ArchiveFormat) {
+    let selection = [glob::Pattern::new("contracts/token/*").expect("Test failure")];
+
+    let mut archive = Vec::new();
+    crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH])
+        .write_to_archive(Some(&selection), &mut archive, format, true, false)
+        .expect("Test failure");
+
+    assert!(archive.is_empty(), "The archive must not be written");
+}
+
+#[test]
+fn zip_too_many_entries() {
+    let entries = (0..=solx::ArchiveFormat::ZIP_MAX_ENTRIES)
+        .map(|index| (format!("{index}.bin"), vec![]))
+        .collect::<Vec<(String, Vec<u8>)>>();
+
+    let mut archive = Vec::new();
+    let error = solx::ArchiveFormat::Zip
+        .write(&mut archive, entries.as_slice())
+        .expect_err("Test failure");
+    assert!(
+        error.to_string().contains("ZIP64 is not supported"),
+        "Unexpected error: {error}"
+    );
+    assert!(archive.is_empty(), "Nothing must be written on failure");
+}
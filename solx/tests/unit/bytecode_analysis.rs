@@ -0,0 +1,90 @@
+//!
+//! Unit tests for the contract bytecode analysis.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+#[test]
+fn default() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let contract = build
+        .results
+        .get(format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH).as_str())
+        .expect("Always exists")
+        .as_ref()
+        .expect("Always valid");
+    let analysis = contract.bytecode_analysis();
+    assert!(
+        analysis.instructions_count() > 0,
+        "The runtime code must not be empty"
+    );
+    assert!(
+        analysis.instructions_count()
+            <= contract
+                .runtime_object
+                .as_ref()
+                .expect("Always exists")
+                .bytecode
+                .len(),
+        "The push data must not be counted as instructions"
+    );
+}
+
+#[test]
+fn push_data() {
+    // PUSH2 0x6060, PUSH1 0x60, PUSH2 0x6060, PUSH0, ADD, PUSH32 truncated
+    let bytecode = hex::decode("61606060606160605f017fff").expect("Always valid");
+    let analysis = solx::BytecodeAnalysis::new(bytecode.as_slice());
+
+    assert_eq!(
+        analysis.opcodes,
+        BTreeMap::from([(0x01, 1), (0x5F, 1), (0x60, 1), (0x61, 2), (0x7F, 1)])
+    );
+    assert_eq!(
+        analysis.push_sizes,
+        BTreeMap::from([(0, 1), (1, 1), (2, 2), (32, 1)])
+    );
+    assert_eq!(
+        analysis.repeated_constants,
+        BTreeMap::from([("6060".to_owned(), 2)])
+    );
+    assert_eq!(analysis.largest_repeated_constant(), Some(("6060", 2)));
+}
+
+#[test]
+fn empty() {
+    let analysis = solx::BytecodeAnalysis::new(&[]);
+    assert_eq!(analysis.instructions_count(), 0);
+    assert_eq!(analysis.largest_repeated_constant(), None);
+}
+
+#[test]
+fn unsupported_opcodes() {
+    // PUSH0, MCOPY, SHL, PUSH1 0x5E
+    let bytecode = hex::decode("5f5e1b605e").expect("Always valid");
+    let analysis = solx::BytecodeAnalysis::new(bytecode.as_slice());
+
+    assert_eq!(analysis.unsupported_opcodes("cancun"), Some(vec![]));
+    assert_eq!(analysis.unsupported_opcodes("shanghai"), Some(vec![0x5E]));
+    assert_eq!(
+        analysis.unsupported_opcodes("paris"),
+        Some(vec![0x5F, 0x5E])
+    );
+    assert_eq!(
+        analysis.unsupported_opcodes("byzantium"),
+        Some(vec![0x1B, 0x5F, 0x5E])
+    );
+    assert_eq!(analysis.unsupported_opcodes("unknown"), None);
+}
@@ -0,0 +1,112 @@
+//!
+//! Unit tests for the contract bytecode assembly.
+//!
+
+#[test]
+fn default() {
+    let build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let contract = build
+        .results
+        .get(format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH).as_str())
+        .expect("Always exists")
+        .as_ref()
+        .expect("Always valid");
+    let assembly = contract.assembly().expect("Always exists");
+    assert_eq!(
+        assembly.instructions.len(),
+        contract.bytecode_analysis().instructions_count()
+    );
+    assert_eq!(
+        assembly.to_bytecode(),
+        contract
+            .runtime_object
+            .as_ref()
+            .expect("Always exists")
+            .bytecode
+    );
+    assert_eq!(contract.disassemble(), assembly.instructions);
+    for instruction in assembly.instructions.iter() {
+        assert_eq!(
+            contract.decode_at(instruction.offset).as_ref(),
+            Some(instruction)
+        );
+    }
+}
+
+#[test]
+fn display() {
+    let assembly = solx::BytecodeAssembly::new(&[0x60, 0x80, 0x5F, 0x52, 0x0C, 0x61, 0xAB]);
+
+    assert_eq!(
+        assembly.to_string(),
+        "0000: PUSH1 0x80\n0002: PUSH0\n0003: MSTORE\n0004: UNKNOWN_0x0c\n0005: PUSH2 0xab\n"
+    );
+    assert_eq!(
+        assembly.to_bytecode(),
+        vec![0x60, 0x80, 0x5F, 0x52, 0x0C, 0x61, 0xAB]
+    );
+}
+
+#[test]
+fn instruction_at() {
+    let assembly = solx::BytecodeAssembly::new(&[0x60, 0x80, 0x5F, 0x61, 0x5B, 0x5B, 0x00]);
+
+    let push2 = assembly.instruction_at(3).expect("Test failure");
+    assert_eq!(push2.mnemonic(), Some("PUSH2"));
+    assert_eq!(push2.operand.as_deref(), Some([0x5B, 0x5B].as_slice()));
+    assert_eq!(
+        assembly
+            .instruction_at(6)
+            .and_then(|instruction| instruction.mnemonic()),
+        Some("STOP")
+    );
+
+    assert!(assembly.instruction_at(1).is_none());
+    assert!(assembly.instruction_at(4).is_none());
+    assert!(assembly.instruction_at(5).is_none());
+    assert!(assembly.instruction_at(7).is_none());
+}
+
+#[test]
+fn disassemble_source() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let source =
+        std::fs::read_to_string(crate::common::TEST_SOLIDITY_CONTRACT_PATH).expect("Test failure");
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str("{}").expect("Test failure");
+
+    let disassembly = solx::disassemble_source(source.as_str(), &settings).expect("Test failure");
+    let build = solx::compile_solidity(source.as_str(), &settings).expect("Test failure");
+    let contract = build
+        .results
+        .get(format!("{}:Test", solx::COMPILE_SOLIDITY_SOURCE_PATH).as_str())
+        .expect("Always exists")
+        .as_ref()
+        .expect("Always valid");
+    assert_eq!(
+        disassembly.get("Test").map(String::as_str),
+        Some(
+            contract
+                .assembly()
+                .expect("Always exists")
+                .to_string()
+                .as_str()
+        )
+    );
+}
+
+#[test]
+fn disassemble_source_no_runtime_bytecode() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str(r#"{"outputSelection":{"*":{"*":["abi"]}}}"#).expect("Test failure");
+
+    let result = solx::disassemble_source("contract Test {}", &settings);
+    assert!(result.is_err());
+}
@@ -0,0 +1,145 @@
+//!
+//! Unit tests for the programmatic compilation API.
+//!
+
+use solx_standard_json::CollectableError;
+
+#[test]
+fn default() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let source =
+        std::fs::read_to_string(crate::common::TEST_SOLIDITY_CONTRACT_PATH).expect("Test failure");
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str("{}").expect("Test failure");
+
+    let build = solx::compile_solidity(source.as_str(), &settings).expect("Test failure");
+    assert!(!build.has_errors());
+    assert!(!build.results.is_empty());
+    assert!(build
+        .results
+        .keys()
+        .all(|path| path.starts_with(solx::COMPILE_SOLIDITY_SOURCE_PATH)));
+    assert!(build.is_fully_linked());
+}
+
+#[test]
+fn evm_version_metadata() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let source =
+        std::fs::read_to_string(crate::common::TEST_SOLIDITY_CONTRACT_PATH).expect("Test failure");
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str(r#"{"evmVersion": "cancun"}"#).expect("Test failure");
+
+    let build = solx::compile_solidity(source.as_str(), &settings).expect("Test failure");
+    assert!(!build.has_errors());
+    for contract in build.results.values() {
+        let contract = contract.as_ref().expect("Test failure");
+        let metadata: serde_json::Value =
+            serde_json::from_str(contract.metadata.as_deref().expect("Test failure"))
+                .expect("Test failure");
+        assert_eq!(metadata[env!("CARGO_PKG_NAME")]["evm_version"], "cancun");
+        assert_eq!(
+            metadata[env!("CARGO_PKG_NAME")]["llvm_options"],
+            serde_json::json!([]),
+            "The EVM version must not be passed as an LLVM option"
+        );
+    }
+}
+
+#[test]
+fn syntax_error() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str("{}").expect("Test failure");
+
+    let result = solx::compile_solidity("contract Test {", &settings);
+    assert!(result.is_err());
+}
+
+#[test]
+fn import_callback() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str("{}").expect("Test failure");
+
+    let mut imported = Vec::new();
+    let build = solx::compile_solidity_with_callback(
+        "import \"virtual/Library.sol\"; contract Test { function f() public pure returns (uint256) { return Library.f(); } }",
+        &settings,
+        &mut |path: &str| {
+            imported.push(path.to_owned());
+            match path {
+                "virtual/Library.sol" => Ok(
+                    "library Library { function f() internal pure returns (uint256) { return 42; } }"
+                        .to_owned(),
+                ),
+                path => Err(format!("File `{path}` not found.")),
+            }
+        },
+    )
+    .expect("Test failure");
+    assert!(!build.has_errors());
+    assert!(build.is_fully_linked());
+    assert_eq!(imported, vec!["virtual/Library.sol".to_owned()]);
+}
+
+#[test]
+fn import_callback_error() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str("{}").expect("Test failure");
+
+    let result = solx::compile_solidity_with_callback(
+        "import \"virtual/Missing.sol\"; contract Test {}",
+        &settings,
+        &mut |path: &str| Err(format!("File `{path}` is not in the virtual file system.")),
+    );
+    let error = result.expect_err("Test failure").to_string();
+    assert!(error.contains("File `virtual/Missing.sol` is not in the virtual file system."));
+    assert!(error.contains(solx::COMPILE_SOLIDITY_SOURCE_PATH));
+}
+
+#[test]
+fn import_callback_panic() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str("{}").expect("Test failure");
+
+    let result = solx::compile_solidity_with_callback(
+        "import \"virtual/Panic.sol\"; contract Test {}",
+        &settings,
+        &mut |_path: &str| panic!("Virtual file system is unavailable."),
+    );
+    let error = result.expect_err("Test failure").to_string();
+    assert!(error.contains("Import callback for `virtual/Panic.sol` panicked"));
+    assert!(error.contains("Virtual file system is unavailable."));
+}
+
+#[test]
+fn import_callback_null_byte() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str("{}").expect("Test failure");
+
+    let result = solx::compile_solidity_with_callback(
+        "import \"virtual/Library.sol\"; contract Test {}",
+        &settings,
+        &mut |_path: &str| Ok("library Library {}\0contract Hidden {}".to_owned()),
+    );
+    let error = result.expect_err("Test failure").to_string();
+    assert!(error.contains("Contents of `virtual/Library.sol` contain a null byte"));
+}
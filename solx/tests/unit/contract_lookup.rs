@@ -0,0 +1,60 @@
+//!
+//! Unit tests for the build contract lookup.
+//!
+
+#[test]
+fn full_path() {
+    let build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let full_path = format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH);
+
+    let contract = build
+        .contract(full_path.as_str())
+        .expect("Test failure")
+        .expect("Test failure");
+    assert_eq!(contract.name.full_path, full_path);
+}
+
+#[test]
+fn simple_name() {
+    let build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let contract = build
+        .contract("Test")
+        .expect("Test failure")
+        .expect("Test failure");
+    assert_eq!(
+        contract.name.full_path,
+        format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+    );
+}
+
+#[test]
+fn missing() {
+    let build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    assert!(build.contract("Missing").expect("Test failure").is_none());
+}
+
+#[test]
+fn ambiguous() {
+    let build = crate::common::build_solidity_default(&[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_PATH,
+    ]);
+
+    let error = build.contract("Test").expect_err("Test failure");
+    assert!(error.to_string().contains("is ambiguous"));
+    assert!(build
+        .contract(
+            format!(
+                "{}:Test",
+                crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_PATH
+            )
+            .as_str()
+        )
+        .expect("Test failure")
+        .is_some());
+}
@@ -0,0 +1,78 @@
+//!
+//! Unit tests for the contract coverage map.
+//!
+
+use std::collections::BTreeSet;
+
+#[test]
+fn default() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let contract = build
+        .results
+        .get(format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH).as_str())
+        .expect("Always exists")
+        .as_ref()
+        .expect("Always valid");
+    let coverage_map = contract.coverage_map().expect("Always exists");
+    assert!(
+        !coverage_map.blocks.is_empty(),
+        "The runtime code must have at least one block"
+    );
+    for blocks in coverage_map.blocks.windows(2) {
+        assert!(
+            blocks[0].end <= blocks[1].start,
+            "The blocks must not overlap"
+        );
+    }
+}
+
+#[test]
+fn branches() {
+    // PUSH1 0x06 CALLDATASIZE JUMPI STOP JUMPDEST PUSH0 PUSH0 REVERT
+    let coverage_map = solx::EVMContractCoverageMap::new(&[
+        0x60, 0x06, 0x36, 0x57, 0x00, 0x00, 0x5B, 0x5F, 0x5F, 0xFD,
+    ]);
+
+    assert_eq!(
+        coverage_map.blocks,
+        vec![
+            solx::EVMContractBasicBlock {
+                start: 0,
+                end: 4,
+                is_jump_target: false,
+                is_branch_point: true,
+            },
+            solx::EVMContractBasicBlock {
+                start: 4,
+                end: 5,
+                is_jump_target: false,
+                is_branch_point: false,
+            },
+            solx::EVMContractBasicBlock {
+                start: 5,
+                end: 6,
+                is_jump_target: false,
+                is_branch_point: false,
+            },
+            solx::EVMContractBasicBlock {
+                start: 6,
+                end: 10,
+                is_jump_target: true,
+                is_branch_point: false,
+            },
+        ]
+    );
+    assert_eq!(coverage_map.branch_points().count(), 1);
+    assert_eq!(coverage_map.block_at(8).map(|block| block.start), Some(6));
+}
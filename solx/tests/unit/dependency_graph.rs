@@ -0,0 +1,78 @@
+//!
+//! Unit tests for the build dependency graph.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+fn full_path(name: &str) -> String {
+    format!(
+        "{}:{name}",
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH
+    )
+}
+
+#[test]
+fn unlinked() {
+    let graph = crate::common::build_solidity_default(&[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+    ])
+    .dependency_graph();
+
+    assert_eq!(
+        graph.get(full_path("UpperContract").as_str()),
+        Some(&BTreeSet::from([full_path("UpperLibrary")]))
+    );
+    assert_eq!(
+        graph.get(full_path("UpperLibrary").as_str()),
+        Some(&BTreeSet::from([full_path("InnerContract")]))
+    );
+    assert_eq!(
+        graph.get(full_path("InnerContract").as_str()),
+        Some(&BTreeSet::new())
+    );
+}
+
+#[test]
+fn linked() {
+    let build = crate::common::build_solidity_default(&[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+    ])
+    .link(
+        BTreeMap::from([(full_path("UpperLibrary"), [0x42; 20])]),
+        None,
+    );
+    assert!(build.is_fully_linked());
+
+    let graph = build.dependency_graph();
+
+    assert_eq!(
+        graph.get(full_path("UpperContract").as_str()),
+        Some(&BTreeSet::from([full_path("UpperLibrary")]))
+    );
+    assert_eq!(
+        graph.get(full_path("InnerContract").as_str()),
+        Some(&BTreeSet::new())
+    );
+}
+
+#[test]
+fn dot() {
+    let mut output = Vec::new();
+    crate::common::build_solidity_default(&[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+    ])
+    .write_dependency_graph_dot(&mut output)
+    .expect("Test failure");
+    let output = String::from_utf8(output).expect("Test failure");
+
+    assert!(output.starts_with("digraph dependencies {"));
+    assert!(output.contains(
+        format!(
+            "{:?} -> {:?};",
+            full_path("UpperContract"),
+            full_path("UpperLibrary")
+        )
+        .as_str()
+    ));
+}
@@ -0,0 +1,43 @@
+//!
+//! Unit tests for the JSON diagnostics output.
+//!
+
+use std::collections::BTreeSet;
+
+#[test]
+fn default() {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let mut output = Vec::new();
+    build
+        .write_diagnostics_json(&mut output)
+        .expect("Test failure");
+    let diagnostics: Vec<serde_json::Value> =
+        serde_json::from_slice(output.as_slice()).expect("Invalid JSON");
+
+    assert!(
+        diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic["severity"] == "warning"
+                && diagnostic["sourceLocation"]["file"]
+                    == crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH),
+        "The unlinked library warning is missing"
+    );
+    assert!(
+        diagnostics
+            .windows(2)
+            .all(|pair| !(pair[0]["severity"] == "warning" && pair[1]["severity"] == "error")),
+        "Errors must precede warnings"
+    );
+}
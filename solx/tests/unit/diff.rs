@@ -0,0 +1,90 @@
+//!
+//! Unit tests for the build diff.
+//!
+
+use solx::build::diff::ObjectDiff;
+
+#[test]
+fn default() {
+    let this = crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let mut other =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    assert!(this.diff(&other, false).is_empty());
+
+    let (path, contract) = other.results.iter_mut().next().expect("Always exists");
+    let path = path.to_owned();
+    let object = contract
+        .as_mut()
+        .expect("Test failure")
+        .runtime_object
+        .as_mut()
+        .expect("Always exists");
+    object.bytecode[1] ^= 0xFF;
+    object.bytecode.push(0x00);
+
+    let diff = this.diff(&other, false);
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    let contract_diff = diff.changed.get(path.as_str()).expect("Always exists");
+    assert!(contract_diff.deploy.is_none());
+    assert_eq!(
+        contract_diff.runtime,
+        Some(ObjectDiff {
+            length_delta: 1,
+            first_difference: 1,
+        })
+    );
+}
+
+#[test]
+fn added_and_removed() {
+    let this = crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let mut other =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let (path, contract) = other.results.pop_first().expect("Always exists");
+    other.results.insert("Other.sol:Other".to_owned(), contract);
+
+    let diff = this.diff(&other, false);
+    assert_eq!(diff.removed, vec![path]);
+    assert_eq!(diff.added, vec!["Other.sol:Other".to_owned()]);
+}
+
+#[test]
+fn ignore_metadata() {
+    let code = vec![0x60, 0x80, 0x60, 0x40, 0x52];
+    let mut this = code.clone();
+    this.extend([0xA1, 0x64, 0x73, 0x6F, 0x6C, 0x78, 0x01, 0x00, 0x07]);
+    let mut other = code.clone();
+    other.extend([0xA1, 0x64, 0x73, 0x6F, 0x6C, 0x78, 0x02, 0x00, 0x07]);
+
+    assert_eq!(ObjectDiff::strip_metadata(this.as_slice()), code.as_slice());
+    assert!(ObjectDiff::new(this.as_slice(), other.as_slice(), false).is_some());
+    assert!(ObjectDiff::new(this.as_slice(), other.as_slice(), true).is_none());
+}
+
+#[test]
+fn display() {
+    let this = crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let mut other =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    assert_eq!(
+        this.diff(&other, false).to_string(),
+        "No bytecode changes.\n"
+    );
+
+    let (path, contract) = other.results.iter_mut().next().expect("Always exists");
+    let path = path.to_owned();
+    let object = contract
+        .as_mut()
+        .expect("Test failure")
+        .runtime_object
+        .as_mut()
+        .expect("Always exists");
+    object.bytecode[1] ^= 0xFF;
+    object.bytecode.push(0x00);
+
+    assert_eq!(
+        this.diff(&other, false).to_string(),
+        format!("~ {path}: runtime +1 bytes, first difference at byte 1\n")
+    );
+}
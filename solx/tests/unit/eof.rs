@@ -0,0 +1,138 @@
+//!
+//! Unit tests for the EOF container.
+//!
+
+use std::collections::BTreeSet;
+
+use test_case::test_case;
+
+#[test]
+fn round_trip() {
+    // PUSH1 0x01, PUSH1 0x02, ADD, POP, STOP
+    let code = vec![0x60, 0x01, 0x60, 0x02, 0x01, 0x50, 0x00];
+    let data = vec![0xAA, 0xBB];
+
+    let container = solx::EOFContainer::new(code.clone(), data.clone()).expect("Test failure");
+    assert_eq!(container.max_stack_height, 2);
+
+    let bytes = container.to_bytes();
+    assert_eq!(&bytes[..3], &[0xEF, 0x00, 0x01]);
+    assert_eq!(
+        bytes.len(),
+        solx::EOFContainer::HEADER_SIZE + 4 + code.len() + data.len()
+    );
+
+    let parsed = solx::EOFContainer::try_from_bytes(bytes.as_slice()).expect("Test failure");
+    assert_eq!(parsed, container);
+    assert_eq!(parsed.code, code);
+    assert_eq!(parsed.data, data);
+}
+
+#[test]
+fn max_stack_height_below_initial() {
+    // JUMPDEST, ADD, STOP: the linear sequence consumes the items pushed by the predecessors.
+    let container = solx::EOFContainer::new(vec![0x5B, 0x01, 0x00], vec![]).expect("Test failure");
+    assert_eq!(container.max_stack_height, 2);
+}
+
+#[test_case(&[0xEF, 0x01, 0x01], "magic" ; "invalid_magic")]
+#[test_case(&[0xEF, 0x00, 0x02], "version" ; "invalid_version")]
+#[test_case(&[0xEF, 0x00, 0x01, 0x01, 0x00], "truncated" ; "truncated")]
+fn invalid(bytes: &[u8], expected: &str) {
+    let error = solx::EOFContainer::try_from_bytes(bytes).expect_err("Test failure");
+    assert!(
+        error.to_string().contains(expected),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test]
+fn deprecated_instructions() {
+    // PUSH1 0x00, JUMP, GAS, STOP
+    let error = solx::EOFContainer::new(vec![0x60, 0x00, 0x56, 0x5A, 0x00], vec![])
+        .expect_err("Test failure");
+    assert_eq!(
+        error.to_string(),
+        "EOF code section contains instructions deprecated in EOF: `JUMP` at offset 2, `GAS` at offset 3."
+    );
+}
+
+#[test]
+fn deprecated_opcode_in_push_operand() {
+    // PUSH2 0x5656, POP, STOP
+    let container =
+        solx::EOFContainer::new(vec![0x61, 0x56, 0x56, 0x50, 0x00], vec![]).expect("Test failure");
+    assert_eq!(container.max_stack_height, 1);
+}
+
+#[test]
+fn replace_embedded() {
+    // PUSH1 0x01, POP, STOP
+    let runtime_code = vec![0x60, 0x01, 0x50, 0x00];
+    // PUSH1 size, PUSH1 offset, PUSH1 0x00, CODECOPY, PUSH1 size, PUSH1 0x00, RETURN
+    let deploy_code_prefix = |size: u8| {
+        vec![
+            0x60, size, 0x60, 0x0C, 0x60, 0x00, 0x39, 0x60, size, 0x60, 0x00, 0xF3,
+        ]
+    };
+    let mut deploy_code = deploy_code_prefix(runtime_code.len() as u8);
+    deploy_code.extend(runtime_code.as_slice());
+
+    let container = solx::EOFContainer::new(runtime_code.clone(), vec![]).expect("Test failure");
+    let container_bytes = container.to_bytes();
+    let wrapped = container
+        .replace_embedded(deploy_code.as_slice(), runtime_code.as_slice())
+        .expect("Test failure");
+
+    let mut expected = deploy_code_prefix(container_bytes.len() as u8);
+    expected.extend(container_bytes);
+    assert_eq!(wrapped, expected);
+}
+
+#[test]
+fn replace_embedded_not_found() {
+    let container = solx::EOFContainer::new(vec![0x00], vec![]).expect("Test failure");
+    let error = container
+        .replace_embedded(&[0x60, 0x01, 0xF3], &[0x60, 0x02])
+        .expect_err("Test failure");
+    assert!(error.to_string().contains("not found"));
+}
+
+#[test]
+fn trailing_bytes() {
+    let container = solx::EOFContainer::new(vec![0x00], vec![]).expect("Test failure");
+    let mut bytes = container.to_bytes();
+    bytes.push(0x00);
+
+    let error = solx::EOFContainer::try_from_bytes(bytes.as_slice()).expect_err("Test failure");
+    assert!(error.to_string().contains("trailing"));
+}
+
+#[test]
+fn contract() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    for result in build.results.into_values() {
+        let mut contract = result.expect("Test failure");
+        if contract.runtime_object.is_none() {
+            continue;
+        }
+
+        // The legacy code generator emits dynamic jumps, which are deprecated in EOF.
+        let error = contract.wrap_runtime_eof().expect_err("Test failure");
+        assert!(
+            error.to_string().contains("deprecated in EOF"),
+            "Unexpected error: {error}"
+        );
+    }
+}
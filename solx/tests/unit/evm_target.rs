@@ -0,0 +1,10 @@
+//!
+//! Unit tests for the LLVM EVM target check.
+//!
+
+#[test]
+fn default() {
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EVM);
+
+    solx::check_evm_target().expect("Test failure");
+}
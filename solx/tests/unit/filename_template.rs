@@ -0,0 +1,85 @@
+//!
+//! Unit tests for the output file name template.
+//!
+
+use std::path::Path;
+
+#[test]
+fn default() {
+    let template = solx::EVMContractFilenameTemplate::default();
+
+    assert_eq!(
+        template.render(Path::new("build"), "Test.sol", "Test", "bin"),
+        Path::new("build").join("Test.sol").join("Test.bin"),
+    );
+}
+
+#[test]
+fn custom() {
+    let template: solx::EVMContractFilenameTemplate =
+        "{path}__{name}.{ext}".parse().expect("Test failure");
+
+    assert_eq!(
+        template.render(Path::new("build"), "Test.sol", "Test_meta", "json"),
+        Path::new("build").join("Test.sol__Test_meta.json"),
+    );
+}
+
+#[test]
+fn escaped_braces() {
+    let template: solx::EVMContractFilenameTemplate =
+        "{{{name}}}.{ext}".parse().expect("Test failure");
+
+    assert_eq!(
+        template.render(Path::new(""), "Test.sol", "Test", "abi"),
+        Path::new("{Test}.abi"),
+    );
+}
+
+#[test]
+fn placeholder_missing() {
+    let error = "{path}/{name}"
+        .parse::<solx::EVMContractFilenameTemplate>()
+        .expect_err("Test failure");
+
+    assert!(error
+        .to_string()
+        .contains("must contain the `{ext}` placeholder"));
+}
+
+#[test]
+fn placeholder_unknown() {
+    let error = "{name}.{extension}"
+        .parse::<solx::EVMContractFilenameTemplate>()
+        .expect_err("Test failure");
+
+    assert!(error
+        .to_string()
+        .contains("unknown placeholder `{extension}`"));
+}
+
+#[test]
+fn placeholder_unclosed() {
+    let error = "{name}.{ext"
+        .parse::<solx::EVMContractFilenameTemplate>()
+        .expect_err("Test failure");
+
+    assert!(error.to_string().contains("unclosed placeholder"));
+}
+
+#[test]
+fn outside_output_directory() {
+    for template in [
+        "../{name}.{ext}",
+        "/{name}.{ext}",
+        "{path}/../../{name}.{ext}",
+    ] {
+        let error = template
+            .parse::<solx::EVMContractFilenameTemplate>()
+            .expect_err("Test failure");
+
+        assert!(error
+            .to_string()
+            .contains("must produce paths inside the output directory"));
+    }
+}
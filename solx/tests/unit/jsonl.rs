@@ -0,0 +1,28 @@
+//!
+//! Unit tests for the JSON Lines output.
+//!
+
+#[test]
+fn default() {
+    let build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let mut output = Vec::new();
+    build.write_to_jsonl(&mut output).expect("Test failure");
+
+    let records = String::from_utf8(output)
+        .expect("Always valid")
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).expect("Invalid JSON line"))
+        .collect::<Vec<serde_json::Value>>();
+    assert!(records
+        .iter()
+        .all(|record| record["solxOutputVersion"] == solx_standard_json::Output::VERSION));
+    for kind in ["deploy", "runtime"] {
+        assert!(
+            records.iter().any(|record| record["kind"] == kind
+                && !record["bytecode"].as_str().unwrap_or_default().is_empty()),
+            "The `{kind}` record is missing"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+//!
+//! Unit tests for writing the successfully compiled contracts of a partially failed build.
+//!
+
+use std::collections::BTreeSet;
+
+use tempfile::TempDir;
+
+#[test]
+fn default() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, mut build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    build.results.insert(
+        "Failed.sol:Failed".to_owned(),
+        Err(solx_standard_json::OutputError::new_error(
+            None,
+            "Compilation failed.",
+            None,
+            None,
+        )),
+    );
+    build.options.keep_going = true;
+
+    let output_directory = TempDir::with_prefix("solx_output").expect("Test failure");
+    let plan = build.plan_directory_writes(output_directory.path(), true, false);
+    build
+        .write_to_directory(None, output_directory.path(), false, true, false)
+        .expect("Test failure");
+
+    assert!(!plan.is_empty(), "The plan must not be empty");
+    assert!(
+        plan.iter().all(|(path, _)| path.exists()),
+        "The successfully compiled contracts must be written"
+    );
+}
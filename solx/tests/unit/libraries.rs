@@ -4,6 +4,7 @@
 
 use std::collections::BTreeSet;
 
+use solx_standard_json::CollectableError;
 use test_case::test_case;
 
 #[test_case(false)]
@@ -88,3 +89,275 @@ fn specified(via_ir: bool) {
         "The list of unlinked libraries must be empty"
     );
 }
+
+#[test_case(false)]
+#[test_case(true)]
+fn not_specified_warning(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    assert!(
+        output.errors.iter().any(|error| error.severity == "warning"
+            && error.message.contains(
+                format!(
+                    "`{}:SimpleLibrary`",
+                    crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+                )
+                .as_str()
+            )),
+        "Missing library warning not emitted"
+    );
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn not_specified_warnings_as_errors(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let (_solc_output, mut build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    assert!(
+        !build.has_errors(),
+        "Warnings must not be errors by default"
+    );
+
+    build.options.warnings_as_errors = true;
+    assert!(build.has_errors(), "Warnings must be treated as errors");
+    assert!(
+        build.take_warnings().is_empty(),
+        "Warnings must be promoted to errors"
+    );
+    assert!(
+        build.errors().iter().all(|error| error.severity == "error"),
+        "Promoted warnings must have the error severity"
+    );
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn relink(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    assert!(
+        !build.unresolved_symbols().is_empty(),
+        "Missing library not detected"
+    );
+
+    let mut bytecodes = Vec::with_capacity(2);
+    let mut build = build;
+    for address in [
+        "0x00000000000000000000000000000000DEADBEEF",
+        "0x00000000000000000000000000000000CAFEBABE",
+    ] {
+        let mut libraries = era_compiler_common::Libraries::default();
+        libraries
+            .as_inner_mut()
+            .entry(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH.to_string())
+            .or_default()
+            .entry("SimpleLibrary".to_string())
+            .or_insert(address.to_string());
+
+        build = build
+            .relink(libraries.as_linker_symbols().expect("Test failure"))
+            .expect("Test failure");
+        assert!(
+            build.unresolved_symbols().is_empty(),
+            "The list of unlinked libraries must be empty"
+        );
+
+        let contract = build
+            .results
+            .get(
+                format!(
+                    "{}:SimpleContract",
+                    crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+                )
+                .as_str(),
+            )
+            .expect("Always exists")
+            .as_ref()
+            .expect("Always valid");
+        bytecodes.push(
+            contract
+                .runtime_object
+                .as_ref()
+                .expect("Always exists")
+                .bytecode
+                .clone(),
+        );
+    }
+    assert_ne!(
+        bytecodes[0], bytecodes[1],
+        "The relinked bytecode must change with the library address"
+    );
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn required_symbols(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    assert_eq!(
+        build.required_symbols(),
+        BTreeSet::from([format!(
+            "{}:SimpleLibrary",
+            crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+        )]),
+        "The missing library must be required"
+    );
+
+    let mut libraries = era_compiler_common::Libraries::default();
+    libraries
+        .as_inner_mut()
+        .entry(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH.to_string())
+        .or_default()
+        .entry("SimpleLibrary".to_string())
+        .or_insert("0x00000000000000000000000000000000DEADBEEF".to_string());
+    let build = build
+        .relink(libraries.as_linker_symbols().expect("Test failure"))
+        .expect("Test failure");
+    assert!(
+        build.required_symbols().is_empty(),
+        "No libraries must be required after linking"
+    );
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn object_formats(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources.clone(),
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    assert!(
+        !build.is_fully_linked(),
+        "The build with missing libraries must not be fully linked"
+    );
+    assert_eq!(
+        build
+            .object_formats()
+            .get(
+                format!(
+                    "{}:SimpleContract",
+                    crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+                )
+                .as_str()
+            )
+            .copied(),
+        Some(era_compiler_common::ObjectFormat::ELF),
+        "The contract with missing libraries must remain in ELF format"
+    );
+
+    let mut libraries = era_compiler_common::Libraries::default();
+    libraries
+        .as_inner_mut()
+        .entry(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH.to_string())
+        .or_default()
+        .entry("SimpleLibrary".to_string())
+        .or_insert("0x00000000000000000000000000000000DEADBEEF".to_string());
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        libraries,
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    assert!(
+        build.is_fully_linked(),
+        "The build with all libraries specified must be fully linked"
+    );
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn link_with_resolver(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+    let library = format!(
+        "{}:SimpleLibrary",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    );
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    let build = build.link_with_resolver(|_symbol| None, None);
+    assert_eq!(
+        build.required_symbols(),
+        BTreeSet::from([library.clone()]),
+        "The library not resolved must remain required"
+    );
+
+    let mut requested = Vec::new();
+    let build = build.link_with_resolver(
+        |symbol| {
+            requested.push(symbol.to_owned());
+            Some([0xAA; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS])
+        },
+        None,
+    );
+    assert_eq!(
+        requested,
+        vec![library],
+        "The resolver must be called once for each required library"
+    );
+    assert!(
+        build.required_symbols().is_empty(),
+        "No libraries must be required after resolving"
+    );
+}
@@ -0,0 +1,57 @@
+//!
+//! Unit tests for the linker symbol file loader.
+//!
+
+use std::io::Write;
+
+const ADDRESS: &str = "0x1234567890123456789012345678901234567890";
+
+fn load(content: &str) -> anyhow::Result<std::collections::BTreeMap<String, [u8; 20]>> {
+    let mut file = tempfile::NamedTempFile::new().expect("Test failure");
+    file.write_all(content.as_bytes()).expect("Test failure");
+    solx::load_linker_symbols(file.path())
+}
+
+#[test]
+fn lines() {
+    let symbols = load(
+        format!(
+            "# Deployed libraries\n\ntest.sol:Library={ADDRESS}\nC:\\lib.sol:Math = {ADDRESS}\n"
+        )
+        .as_str(),
+    )
+    .expect("Test failure");
+
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(
+        symbols
+            .get("test.sol:Library")
+            .expect("Always exists")
+            .to_vec(),
+        hex::decode(ADDRESS.strip_prefix("0x").expect("Always exists")).expect("Test failure")
+    );
+    assert!(symbols.contains_key("C:\\lib.sol:Math"));
+}
+
+#[test]
+fn standard_json() {
+    let symbols = load(format!(r#"{{"test.sol": {{"Library": "{ADDRESS}"}}}}"#).as_str())
+        .expect("Test failure");
+
+    assert!(symbols.contains_key("test.sol:Library"));
+}
+
+#[test]
+fn invalid_address() {
+    let error = load(format!("test.sol:Library={ADDRESS}\ntest.sol:Math=0x1234\n").as_str())
+        .expect_err("Test failure");
+
+    assert!(error.to_string().contains("line 2"));
+}
+
+#[test]
+fn missing_library_name() {
+    let error = load(format!("test.sol={ADDRESS}\n").as_str()).expect_err("Test failure");
+
+    assert!(error.to_string().contains("line 1"));
+}
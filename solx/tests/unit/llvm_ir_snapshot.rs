@@ -0,0 +1,42 @@
+//!
+//! Unit tests for the LLVM IR snapshots.
+//!
+
+const DUMP: &str = r#"warning: some unrelated diagnostic
+; *** IR Dump After InstCombinePass on __entry ***
+; ModuleID = 'Test.sol:Test.runtime'
+define void @__entry() {
+  ret void
+}
+
+; *** IR Dump After InstCombinePass on __entry ***
+; ModuleID = 'Test.sol:Test'
+define void @__entry() {
+  unreachable
+}
+"#;
+
+#[test]
+fn parse_dump() {
+    let snapshots = solx::EVMContractLLVMIRSnapshot::parse_dump(DUMP);
+
+    assert_eq!(snapshots.len(), 2);
+    assert!(snapshots
+        .iter()
+        .all(|snapshot| snapshot.pass == "InstCombinePass"));
+    assert_eq!(
+        snapshots[0].code_segment,
+        era_compiler_common::CodeSegment::Runtime
+    );
+    assert!(snapshots[0].ir.contains("ret void"));
+    assert_eq!(
+        snapshots[1].code_segment,
+        era_compiler_common::CodeSegment::Deploy
+    );
+    assert!(snapshots[1].ir.ends_with('}'));
+}
+
+#[test]
+fn parse_dump_empty() {
+    assert!(solx::EVMContractLLVMIRSnapshot::parse_dump("warning: no dumps here").is_empty());
+}
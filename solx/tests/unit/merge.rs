@@ -0,0 +1,44 @@
+//!
+//! Unit tests for merging builds.
+//!
+
+use solx_standard_json::CollectableError;
+
+#[test]
+fn default() {
+    let mut build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let other = crate::common::build_solidity_default(&[
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
+    ]);
+    let expected_length = build.results.len() + other.results.len();
+
+    build.merge(other).expect("Test failure");
+
+    assert_eq!(build.results.len(), expected_length);
+    assert!(
+        !build.has_errors(),
+        "The merged build must not contain errors"
+    );
+}
+
+#[test]
+fn collision() {
+    let mut build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let other =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let expected_length = build.results.len();
+
+    build.merge(other).expect("Test failure");
+
+    assert_eq!(build.results.len(), expected_length);
+    assert!(
+        build
+            .messages
+            .iter()
+            .any(|message| message.severity == "error"
+                && message.message.contains("is present in both builds")),
+        "The collision must be reported"
+    );
+}
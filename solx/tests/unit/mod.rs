@@ -2,9 +2,40 @@
 //! The unit tests entry module.
 //!
 
+mod archive;
+mod bytecode_analysis;
+mod bytecode_assembly;
+mod compile_solidity;
+mod contract_lookup;
+mod coverage_map;
+mod dependency_graph;
+mod diagnostics;
+mod diff;
+mod eof;
+mod evm_target;
+mod filename_template;
 mod ir_artifacts;
+mod jsonl;
+mod keep_going;
 mod libraries;
+mod llvm_ir_snapshot;
 // mod linker; TODO: Fix the deploy-time linker
+mod linker_symbols;
+mod merge;
 mod optimizer;
+mod output_selection;
+mod plan_directory_writes;
 mod remappings;
+mod resolve_imports;
+mod selection;
+mod server;
+mod source_hashes;
+mod split;
 mod standard_json;
+mod statistics;
+mod timeout;
+mod timings;
+mod verify_against;
+mod warning_codes;
+mod watcher;
+mod yul_literal;
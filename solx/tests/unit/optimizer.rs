@@ -65,3 +65,32 @@ fn default(via_ir: bool) {
         "Expected gas-optimized bytecode to be different from size-optimized. Gas-optimized: {optimized_for_gas:?}, size-optimized: {optimized_for_size:?}",
     );
 }
+
+#[test]
+fn contract_override() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let source =
+        std::fs::read_to_string(crate::common::TEST_SOLIDITY_CONTRACT_PATH).expect("Test failure");
+    let mut settings: solx_standard_json::InputSettings =
+        serde_json::from_str(r#"{"optimizer": {"mode": "z", "contractModes": {"Test": "3"}}}"#)
+            .expect("Test failure");
+    assert_eq!(settings.optimizer.contract_modes.get("Test"), Some(&'3'));
+
+    let build = solx::compile_solidity(source.as_str(), &settings).expect("Test failure");
+    let output = build.into_standard_json_output().expect("Test failure");
+    let contract = output
+        .contracts
+        .get(solx::COMPILE_SOLIDITY_SOURCE_PATH)
+        .expect("Always exists")
+        .get("Test")
+        .expect("Always exists");
+    assert_eq!(contract.optimization_mode, Some('3'));
+
+    settings
+        .optimizer
+        .contract_modes
+        .insert("Test".to_owned(), '9');
+    assert!(solx::compile_solidity(source.as_str(), &settings).is_err());
+}
@@ -0,0 +1,92 @@
+//!
+//! Unit tests for the per-file and per-contract output selection.
+//!
+
+///
+/// Parses the output selection from its JSON representation.
+///
+fn selection(value: serde_json::Value) -> solx_standard_json::InputSelection {
+    serde_json::from_value(value).expect("Test failure")
+}
+
+#[test]
+fn wildcard_and_exact_merged() {
+    let selection = selection(serde_json::json!({
+        "*": { "*": ["abi"] },
+        "Token.sol": { "Token": ["evm.bytecode.object"] },
+    }));
+
+    assert!(selection.check_selection(
+        "Token.sol",
+        Some("Token"),
+        solx_standard_json::InputSelector::ABI
+    ));
+    assert!(selection.check_selection(
+        "Token.sol",
+        Some("Token"),
+        solx_standard_json::InputSelector::BytecodeObject
+    ));
+    assert!(selection.check_selection(
+        "Other.sol",
+        Some("Other"),
+        solx_standard_json::InputSelector::ABI
+    ));
+    assert!(!selection.check_selection(
+        "Other.sol",
+        Some("Other"),
+        solx_standard_json::InputSelector::BytecodeObject
+    ));
+}
+
+#[test]
+fn per_contract() {
+    let selection = selection(serde_json::json!({
+        "Token.sol": {
+            "*": ["metadata"],
+            "Token": ["evm.deployedBytecode.object"],
+        },
+    }));
+
+    assert!(selection.check_selection(
+        "Token.sol",
+        Some("Token"),
+        solx_standard_json::InputSelector::RuntimeBytecodeObject
+    ));
+    assert!(selection.check_selection(
+        "Token.sol",
+        Some("Library"),
+        solx_standard_json::InputSelector::Metadata
+    ));
+    assert!(!selection.check_selection(
+        "Token.sol",
+        Some("Library"),
+        solx_standard_json::InputSelector::RuntimeBytecodeObject
+    ));
+    assert!(!selection.check_selection(
+        "Other.sol",
+        Some("Token"),
+        solx_standard_json::InputSelector::Metadata
+    ));
+}
+
+#[test]
+fn build_respects_selection() {
+    let mut build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    build.options.output_selection = Some(selection(serde_json::json!({
+        "*": { "*": ["evm.deployedBytecode.object"] },
+    })));
+
+    let output = build.into_standard_json_output().expect("Test failure");
+    let contracts = output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+        .expect("Always exists");
+    assert!(!contracts.is_empty());
+    for contract in contracts.values() {
+        let evm = contract.evm.as_ref().expect("Always exists");
+        assert!(evm.bytecode.is_none());
+        assert!(evm.deployed_bytecode.is_some());
+        assert!(contract.metadata.is_none());
+    }
+}
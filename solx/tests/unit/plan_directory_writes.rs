@@ -0,0 +1,43 @@
+//!
+//! Unit tests for planning the output directory writes.
+//!
+
+use std::collections::BTreeSet;
+
+use tempfile::TempDir;
+
+#[test]
+fn default() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let output_directory = TempDir::with_prefix("solx_output").expect("Test failure");
+    let plan = build.plan_directory_writes(output_directory.path(), true, false);
+    assert!(!plan.is_empty(), "The plan must not be empty");
+    assert!(
+        plan.iter().all(|(path, exists)| !exists
+            && path.starts_with(output_directory.path())
+            && !path.exists()),
+        "The plan must not touch the file system"
+    );
+
+    let (existing_path, _) = plan.first().expect("Always exists");
+    std::fs::create_dir_all(existing_path.parent().expect("Always exists")).expect("Test failure");
+    std::fs::write(existing_path, "").expect("Test failure");
+
+    let plan = build.plan_directory_writes(output_directory.path(), true, false);
+    assert!(
+        plan.iter()
+            .any(|(path, exists)| path == existing_path && *exists),
+        "The existing file must be detected"
+    );
+}
@@ -0,0 +1,64 @@
+//!
+//! Unit tests for the import resolution.
+//!
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+#[test]
+fn default() {
+    crate::common::setup().expect("Test failure");
+
+    let imports = solx::resolve_imports(
+        Path::new(crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH),
+        None,
+        None,
+        BTreeSet::new(),
+    )
+    .expect("Test failure");
+    assert_eq!(
+        imports,
+        vec![PathBuf::from(
+            crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH
+        )]
+    );
+}
+
+#[test]
+fn remappings() {
+    crate::common::setup().expect("Test failure");
+
+    let remappings = BTreeSet::from([format!(
+        "@dependency/={}/",
+        crate::common::TEST_SOLIDITY_CONTRACT_MONOREPO_DEPENDENCY_PATH
+    )]);
+    let imports = solx::resolve_imports(
+        Path::new(crate::common::TEST_SOLIDITY_CONTRACT_MONOREPO_REMAPPED_PATH),
+        None,
+        None,
+        remappings,
+    )
+    .expect("Test failure");
+    assert_eq!(
+        imports,
+        vec![PathBuf::from(format!(
+            "{}/Counter.sol",
+            crate::common::TEST_SOLIDITY_CONTRACT_MONOREPO_DEPENDENCY_PATH
+        ))]
+    );
+}
+
+#[test]
+fn unresolved() {
+    crate::common::setup().expect("Test failure");
+
+    let error = solx::resolve_imports(
+        Path::new(crate::common::TEST_SOLIDITY_CONTRACT_MONOREPO_REMAPPED_PATH),
+        None,
+        None,
+        BTreeSet::new(),
+    )
+    .expect_err("Test failure");
+    assert!(error.to_string().contains("@dependency/Counter.sol"));
+}
@@ -0,0 +1,29 @@
+//!
+//! Unit tests for the contract selection.
+//!
+
+use test_case::test_case;
+
+#[test_case("tests/data/contracts/solidity/*", true ; "matched")]
+#[test_case("contracts/token/*", false ; "unmatched")]
+fn default(pattern: &str, is_written: bool) {
+    let build =
+        crate::common::build_solidity_default(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let output_directory = tempfile::TempDir::with_prefix("solx_selection").expect("Test failure");
+    let selection = [glob::Pattern::new(pattern).expect("Test failure")];
+
+    build
+        .write_to_directory(
+            Some(&selection),
+            output_directory.path(),
+            false,
+            false,
+            false,
+        )
+        .expect("Test failure");
+
+    let entries = std::fs::read_dir(output_directory.path())
+        .expect("Test failure")
+        .count();
+    assert_eq!(entries > 0, is_written);
+}
@@ -0,0 +1,105 @@
+//!
+//! Unit tests for the compiler server.
+//!
+
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::net::TcpStream;
+use std::time::Duration;
+
+fn input_line() -> String {
+    let input_json = std::fs::read_to_string(crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH)
+        .expect("Test failure");
+    let input_value: serde_json::Value =
+        serde_json::from_str(input_json.as_str()).expect("Test failure");
+    serde_json::to_string(&input_value).expect("Always valid")
+}
+
+#[test]
+fn compile_invalid() {
+    let server =
+        solx::Server::bind("127.0.0.1:0", None, vec![], None, false, None).expect("Test failure");
+
+    let output: serde_json::Value =
+        serde_json::from_str(server.compile("{ not a JSON").as_str()).expect("Test failure");
+    let message = output["errors"][0]["message"]
+        .as_str()
+        .expect("Test failure");
+    assert!(message.contains("Standard JSON parsing"));
+}
+
+#[test]
+fn requests_are_isolated() {
+    crate::common::setup().expect("Test failure");
+
+    let server =
+        solx::Server::bind("127.0.0.1:0", None, vec![], None, false, None).expect("Test failure");
+    let address = server.local_address().expect("Test failure");
+    std::thread::spawn(move || server.run());
+
+    let mut stream = TcpStream::connect(address).expect("Test failure");
+    let mut reader = BufReader::new(stream.try_clone().expect("Test failure"));
+    for request in ["{ not a JSON".to_owned(), input_line()] {
+        stream.write_all(request.as_bytes()).expect("Test failure");
+        stream.write_all(b"\n").expect("Test failure");
+    }
+
+    let mut responses = Vec::with_capacity(2);
+    for _ in 0..2 {
+        let mut response = String::new();
+        reader.read_line(&mut response).expect("Test failure");
+        let response: serde_json::Value =
+            serde_json::from_str(response.as_str()).expect("Test failure");
+        responses.push(response);
+    }
+
+    assert!(responses[0]["contracts"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .next()
+        .is_none());
+    assert!(!responses[1]["contracts"]
+        .as_object()
+        .expect("Test failure")
+        .is_empty());
+    assert!(responses[1]["errors"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .all(|error| error["severity"] != "error"));
+}
+
+#[test]
+fn max_connections() {
+    let mut server =
+        solx::Server::bind("127.0.0.1:0", None, vec![], None, false, None).expect("Test failure");
+    server.set_max_connections(1);
+    let address = server.local_address().expect("Test failure");
+    std::thread::spawn(move || server.run());
+
+    let idle_stream = TcpStream::connect(address).expect("Test failure");
+    let mut stream = TcpStream::connect(address).expect("Test failure");
+    stream
+        .set_read_timeout(Some(Duration::from_millis(500)))
+        .expect("Test failure");
+    let mut reader = BufReader::new(stream.try_clone().expect("Test failure"));
+    stream.write_all(b"{ not a JSON\n").expect("Test failure");
+
+    let mut response = String::new();
+    assert!(
+        reader.read_line(&mut response).is_err(),
+        "The connection must wait while the only worker is busy"
+    );
+
+    drop(idle_stream);
+    stream.set_read_timeout(None).expect("Test failure");
+    reader.read_line(&mut response).expect("Test failure");
+    let response: serde_json::Value =
+        serde_json::from_str(response.as_str()).expect("Test failure");
+    assert!(response["errors"][0]["message"]
+        .as_str()
+        .expect("Test failure")
+        .contains("Standard JSON parsing"));
+}
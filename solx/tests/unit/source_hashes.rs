@@ -0,0 +1,63 @@
+//!
+//! Unit tests for the source file hashes.
+//!
+
+use std::collections::BTreeMap;
+
+#[test]
+fn empty() {
+    let sources = BTreeMap::from([(
+        "Empty.sol".to_owned(),
+        solx_standard_json::InputSource {
+            content: Some(String::new()),
+            urls: None,
+        },
+    )]);
+
+    let source_hashes = solx::EVMBuild::hash_sources(&sources);
+    assert_eq!(
+        source_hashes.get("Empty.sol").map(String::as_str),
+        Some("0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+    );
+}
+
+#[test]
+fn matches_solc_metadata() {
+    crate::common::setup().expect("Test failure");
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+
+    let source =
+        std::fs::read_to_string(crate::common::TEST_SOLIDITY_CONTRACT_PATH).expect("Test failure");
+    let settings: solx_standard_json::InputSettings =
+        serde_json::from_str("{}").expect("Test failure");
+
+    let build = solx::compile_solidity(source.as_str(), &settings).expect("Test failure");
+    let contract = build
+        .results
+        .values()
+        .next()
+        .expect("Test failure")
+        .as_ref()
+        .expect("Test failure");
+    let metadata: serde_json::Value =
+        serde_json::from_str(contract.metadata.as_deref().expect("Test failure"))
+            .expect("Test failure");
+    let solc_hash = metadata["sources"][solx::COMPILE_SOLIDITY_SOURCE_PATH]["keccak256"]
+        .as_str()
+        .expect("Test failure");
+
+    assert_eq!(
+        build
+            .input_source_hashes
+            .get(solx::COMPILE_SOLIDITY_SOURCE_PATH)
+            .map(String::as_str),
+        Some(solc_hash)
+    );
+    assert_eq!(
+        build
+            .source_hashes()
+            .get(solx::COMPILE_SOLIDITY_SOURCE_PATH)
+            .map(String::as_str),
+        Some(solc_hash)
+    );
+}
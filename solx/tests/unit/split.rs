@@ -0,0 +1,39 @@
+//!
+//! Unit tests for splitting builds into successful and failed contracts.
+//!
+
+use std::collections::BTreeSet;
+
+#[test]
+fn default() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, mut build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    let successful_count = build.results.len();
+    build.results.insert(
+        "Failed.sol:Failed".to_owned(),
+        Err(solx_standard_json::OutputError::new_error(
+            None,
+            "Compilation failed.",
+            None,
+            None,
+        )),
+    );
+
+    let (contracts, errors) = build.split();
+
+    assert_eq!(contracts.len(), successful_count);
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors.contains_key("Failed.sol:Failed"),
+        "The failed contract must be reported"
+    );
+}
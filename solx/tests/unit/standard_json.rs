@@ -6,9 +6,10 @@ use std::path::PathBuf;
 
 #[test]
 fn standard_json_yul_solc() {
-    let solc_input = solx_standard_json::Input::try_from(Some(
-        PathBuf::from("tests/data/standard_json_input/yul_solc.json").as_path(),
-    ))
+    let solc_input = solx_standard_json::Input::try_from(
+        Some(PathBuf::from("tests/data/standard_json_input/yul_solc.json").as_path()),
+        false,
+    )
     .expect("Standard JSON reading error");
     let solc_output = crate::common::build_yul_standard_json(solc_input).expect("Test failure");
 
@@ -30,9 +31,10 @@ fn standard_json_yul_solc() {
 
 #[test]
 fn standard_json_yul_solc_validated() {
-    let solc_input = solx_standard_json::Input::try_from(Some(
-        PathBuf::from("tests/data/standard_json_input/yul_solc.json").as_path(),
-    ))
+    let solc_input = solx_standard_json::Input::try_from(
+        Some(PathBuf::from("tests/data/standard_json_input/yul_solc.json").as_path()),
+        false,
+    )
     .expect("Standard JSON reading error");
 
     let solc_output = crate::common::build_yul_standard_json(solc_input).expect("Test failure");
@@ -55,9 +57,10 @@ fn standard_json_yul_solc_validated() {
 
 #[test]
 fn standard_json_yul_solc_urls() {
-    let solc_input = solx_standard_json::Input::try_from(Some(
-        PathBuf::from("tests/data/standard_json_input/yul_solc_urls.json").as_path(),
-    ))
+    let solc_input = solx_standard_json::Input::try_from(
+        Some(PathBuf::from("tests/data/standard_json_input/yul_solc_urls.json").as_path()),
+        false,
+    )
     .expect("Standard JSON reading error");
     let solc_output = crate::common::build_yul_standard_json(solc_input).expect("Test failure");
 
@@ -79,9 +82,10 @@ fn standard_json_yul_solc_urls() {
 
 #[test]
 fn standard_json_yul_solc_urls_validated() {
-    let solc_input = solx_standard_json::Input::try_from(Some(
-        PathBuf::from("tests/data/standard_json_input/yul_solc_urls.json").as_path(),
-    ))
+    let solc_input = solx_standard_json::Input::try_from(
+        Some(PathBuf::from("tests/data/standard_json_input/yul_solc_urls.json").as_path()),
+        false,
+    )
     .expect("Standard JSON reading error");
 
     let solc_output = crate::common::build_yul_standard_json(solc_input).expect("Test failure");
@@ -102,11 +106,41 @@ fn standard_json_yul_solc_urls_validated() {
         .is_empty())
 }
 
+#[test]
+fn standard_json_yul_verbatim() {
+    let solc_input = solx_standard_json::Input::try_from(
+        Some(PathBuf::from("tests/data/standard_json_input/yul_verbatim.json").as_path()),
+        false,
+    )
+    .expect("Standard JSON reading error");
+    let solc_output = crate::common::build_yul_standard_json(solc_input).expect("Test failure");
+
+    let deployed_bytecode = solc_output
+        .contracts
+        .get("Test")
+        .expect("The `Test` contract is missing")
+        .get("Verbatim")
+        .expect("The `Verbatim` contract is missing")
+        .evm
+        .as_ref()
+        .expect("The `evm` field is missing")
+        .deployed_bytecode
+        .as_ref()
+        .expect("The `deployedBytecode` field is missing")
+        .object
+        .as_str();
+    assert!(
+        deployed_bytecode.contains("600502"),
+        "The `verbatim` bytecode must be emitted as is: {deployed_bytecode}"
+    );
+}
+
 #[test]
 fn standard_json_llvm_ir_urls() {
-    let solc_input = solx_standard_json::Input::try_from(Some(
-        PathBuf::from("tests/data/standard_json_input/llvm_ir_urls.json").as_path(),
-    ))
+    let solc_input = solx_standard_json::Input::try_from(
+        Some(PathBuf::from("tests/data/standard_json_input/llvm_ir_urls.json").as_path()),
+        false,
+    )
     .expect("Standard JSON reading error");
     let solc_output = crate::common::build_llvm_ir_standard_json(solc_input).expect("Test failure");
 
@@ -125,3 +159,212 @@ fn standard_json_llvm_ir_urls() {
         .object
         .is_empty())
 }
+
+#[test]
+fn standard_json_llvm_ir_immutable_references() {
+    let solc_input = solx_standard_json::Input::try_from(
+        Some(PathBuf::from("tests/data/standard_json_input/llvm_ir_urls.json").as_path()),
+        false,
+    )
+    .expect("Standard JSON reading error");
+    let solc_output = crate::common::build_llvm_ir_standard_json(solc_input).expect("Test failure");
+
+    let deployed_bytecode = solc_output
+        .contracts
+        .get("Test")
+        .expect("The `Test` contract is missing")
+        .get("Test")
+        .expect("The `Test` contract is missing")
+        .evm
+        .as_ref()
+        .expect("The `evm` field is missing")
+        .deployed_bytecode
+        .as_ref()
+        .expect("The `deployedBytecode` field is missing");
+    let deployed_bytecode = serde_json::to_value(deployed_bytecode).expect("Test failure");
+    assert_eq!(
+        deployed_bytecode.get("immutableReferences"),
+        Some(&serde_json::json!({})),
+        "The `immutableReferences` field must be present and empty"
+    );
+}
+
+#[test]
+fn standard_json_solidity_deterministic_order() {
+    let build = || {
+        let sources = crate::common::read_sources(&[
+            crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        ]);
+        let output = crate::common::build_solidity_standard_json(
+            sources,
+            era_compiler_common::Libraries::default(),
+            era_compiler_common::EVMMetadataHashType::IPFS,
+            std::collections::BTreeSet::new(),
+            false,
+            era_compiler_llvm_context::OptimizerSettings::cycles(),
+        )
+        .expect("Test failure");
+        serde_json::to_vec(&output).expect("Always valid")
+    };
+
+    let first = build();
+    let second = build();
+    assert_eq!(
+        first, second,
+        "The standard JSON output is not reproducible"
+    );
+
+    let output: solx_standard_json::Output =
+        serde_json::from_slice(first.as_slice()).expect("Always valid");
+    let names = output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .expect("Always exists")
+        .keys()
+        .cloned()
+        .collect::<Vec<String>>();
+    let mut sorted_names = names.clone();
+    sorted_names.sort();
+    assert_eq!(names, sorted_names, "Contracts are not sorted by name");
+}
+
+#[test]
+fn standard_json_solidity_source_map() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let solc_output = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        std::collections::BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    assert!(
+        solc_output
+            .sources
+            .get(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+            .expect("Always exists")
+            .ast
+            .is_none(),
+        "The AST requested for source maps must not leak into the output"
+    );
+
+    let evm = solc_output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+        .expect("Always exists")
+        .values()
+        .next()
+        .expect("Always exists")
+        .evm
+        .as_ref()
+        .expect("The `evm` field is missing");
+    for bytecode in [evm.bytecode.as_ref(), evm.deployed_bytecode.as_ref()] {
+        let bytecode = serde_json::to_value(bytecode.expect("The bytecode field is missing"))
+            .expect("Test failure");
+        let source_map = bytecode
+            .get("sourceMap")
+            .expect("The `sourceMap` field must be present")
+            .as_str()
+            .expect("The source map must be set for Solidity contracts");
+        let object = hex::decode(
+            bytecode
+                .get("object")
+                .and_then(|object| object.as_str())
+                .expect("Always exists"),
+        )
+        .expect("Always valid");
+
+        let mut entries = source_map.split(';');
+        let first = entries.next().expect("Always exists");
+        assert!(
+            first.ends_with(":0:-"),
+            "The first entry `{first}` must point at the contract definition"
+        );
+        assert!(entries.all(|entry| entry.is_empty()));
+        assert_eq!(
+            source_map.split(';').count(),
+            solx::BytecodeAnalysis::new(object.as_slice()).instructions_count(),
+            "The source map must have an entry per instruction"
+        );
+    }
+}
+
+#[test]
+fn standard_json_solidity_into_output() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        std::collections::BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let output = build.into_standard_json_output().expect("Test failure");
+    assert!(output
+        .sources
+        .contains_key(crate::common::TEST_SOLIDITY_CONTRACT_PATH));
+    assert!(output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+        .expect("Always exists")
+        .values()
+        .all(|contract| contract.evm.is_some()));
+}
+
+#[test]
+fn standard_json_build_warnings_suppressed() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (mut solc_output, mut build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        std::collections::BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    build.options.suppression =
+        solx_standard_json::OutputErrorSuppression::new(["1234".to_owned()].into());
+    build
+        .messages
+        .push(solx_standard_json::OutputError::new_warning(
+            Some(1234),
+            "Suppressed build warning",
+            None,
+            None,
+        ));
+    build
+        .messages
+        .push(solx_standard_json::OutputError::new_warning(
+            Some(4321),
+            "Retained build warning",
+            None,
+            None,
+        ));
+
+    build
+        .write_to_standard_json(&mut solc_output)
+        .expect("Test failure");
+    assert!(solc_output
+        .errors
+        .iter()
+        .all(|error| !error.message.contains("Suppressed build warning")));
+    assert!(solc_output
+        .errors
+        .iter()
+        .any(|error| error.message.contains("Retained build warning")));
+
+    let suppressed = solc_output.take_suppressed();
+    assert_eq!(suppressed.len(), 1);
+    assert!(suppressed[0].message.contains("Suppressed build warning"));
+}
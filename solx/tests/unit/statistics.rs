@@ -0,0 +1,127 @@
+//!
+//! Unit tests for the build statistics.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use test_case::test_case;
+
+#[test]
+fn default() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let statistics = build.statistics();
+    assert!(
+        !statistics.contracts.is_empty(),
+        "The statistics must include the compiled contracts"
+    );
+    assert_eq!(
+        statistics.total_runtime_size,
+        statistics
+            .contracts
+            .values()
+            .map(|(_deploy, runtime)| runtime)
+            .sum::<usize>()
+    );
+    assert!(statistics.largest_contract.is_some());
+    assert!(
+        statistics.warnings.is_empty(),
+        "The test contract must not exceed the size limit"
+    );
+
+    for optimization in statistics.optimizations.values() {
+        assert_eq!(
+            optimization.mode,
+            era_compiler_llvm_context::OptimizerSettings::cycles().to_string()
+        );
+        assert_eq!(
+            optimization.priority,
+            solx::EVMContractOptimizationPriority::Speed
+        );
+    }
+    assert_eq!(
+        statistics.optimizations.len(),
+        statistics.contracts.len(),
+        "The optimizer settings must be reported for each compiled contract"
+    );
+}
+
+#[test]
+fn size_limit_exceeded() {
+    let contracts = BTreeMap::from([
+        ("Small.sol:Small".to_owned(), (128, 64)),
+        (
+            "Large.sol:Large".to_owned(),
+            (
+                solx::EVMBuildStatistics::RUNTIME_SIZE_LIMIT * 2,
+                solx::EVMBuildStatistics::RUNTIME_SIZE_LIMIT + 1,
+            ),
+        ),
+    ]);
+
+    let statistics = solx::EVMBuildStatistics::new(contracts);
+    assert_eq!(
+        statistics.largest_contract.as_deref(),
+        Some("Large.sol:Large")
+    );
+    assert_eq!(statistics.warnings.len(), 1);
+    assert_eq!(statistics.warnings[0].severity, "warning");
+    assert!(statistics.warnings[0].message.contains("Large.sol:Large"));
+
+    let mut table = Vec::new();
+    statistics.write_table(&mut table).expect("Test failure");
+    let table = String::from_utf8(table).expect("Test failure");
+    assert!(table.contains("Total"));
+    assert!(table.contains("(!)"));
+}
+
+#[test_case(false, "warning")]
+#[test_case(true, "error")]
+fn size_limit_reported(strict_size_limit: bool, expected_severity: &str) {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, mut build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    build.options.strict_size_limit = strict_size_limit;
+
+    let oversized_length = solx::EVMBuildStatistics::RUNTIME_SIZE_LIMIT + 1;
+    for contract in build.results.values_mut() {
+        let contract = contract.as_mut().expect("Test failure");
+        if let Some(object) = contract.runtime_object.as_mut() {
+            object.bytecode = vec![0; oversized_length];
+        }
+    }
+
+    let mut output = solx_standard_json::Output::new_with_messages(vec![]);
+    build
+        .write_to_standard_json(&mut output)
+        .expect("Test failure");
+    assert!(
+        output
+            .errors
+            .iter()
+            .any(|error| error.severity == expected_severity
+                && error
+                    .message
+                    .contains(format!("{oversized_length} bytes").as_str())),
+        "The size limit violation must be reported"
+    );
+}
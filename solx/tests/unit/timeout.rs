@@ -0,0 +1,40 @@
+//!
+//! Unit tests for the code generation timeout.
+//!
+
+use std::time::Duration;
+
+#[test]
+fn exceeded() {
+    let build = crate::common::build_yul(
+        crate::common::read_sources(&[crate::common::TEST_YUL_CONTRACT_PATH]),
+        solx::CompileOptions {
+            timeout: Some(Duration::from_nanos(1)),
+            ..solx::CompileOptions::default()
+        },
+    );
+
+    let error = build
+        .results
+        .into_values()
+        .find_map(|result| result.err())
+        .expect("Test failure");
+    assert_eq!(error.r#type, solx_standard_json::OutputError::TYPE_TIMEOUT);
+    assert_eq!(
+        error.exit_reason().code(),
+        solx_standard_json::OutputErrorExitReason::EXIT_CODE_TIMEOUT
+    );
+}
+
+#[test]
+fn not_exceeded() {
+    let build = crate::common::build_yul(
+        crate::common::read_sources(&[crate::common::TEST_YUL_CONTRACT_PATH]),
+        solx::CompileOptions {
+            timeout: Some(Duration::from_secs(600)),
+            ..solx::CompileOptions::default()
+        },
+    );
+
+    assert!(build.results.values().all(|result| result.is_ok()));
+}
@@ -0,0 +1,32 @@
+//!
+//! Unit tests for the compilation timings.
+//!
+
+use std::collections::BTreeSet;
+
+#[test]
+fn default() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let timings = build.timings();
+    assert_eq!(
+        timings.keys().collect::<Vec<&String>>(),
+        build.results.keys().collect::<Vec<&String>>()
+    );
+    assert!(
+        timings
+            .values()
+            .all(|(deploy, runtime)| !deploy.is_zero() && !runtime.is_zero()),
+        "The timings must be recorded"
+    );
+}
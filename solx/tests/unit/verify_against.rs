@@ -0,0 +1,109 @@
+//!
+//! Unit tests for the contract bytecode verification.
+//!
+
+use std::collections::BTreeSet;
+
+use solx::build::diff::ObjectDiff;
+
+fn contract() -> solx::EVMContractBuild {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let (_solc_output, mut build) = crate::common::build_solidity(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    build
+        .results
+        .remove(format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH).as_str())
+        .expect("Always exists")
+        .expect("Always valid")
+}
+
+fn runtime_bytecode(contract: &solx::EVMContractBuild) -> Vec<u8> {
+    contract
+        .runtime_object
+        .as_ref()
+        .expect("Always exists")
+        .bytecode
+        .clone()
+}
+
+#[test]
+fn default() {
+    let contract = contract();
+    let deployed = runtime_bytecode(&contract);
+
+    assert_eq!(
+        contract.verify_against(deployed.as_slice()),
+        solx::EVMContractVerificationResult::Match
+    );
+}
+
+#[test]
+fn metadata_mismatch() {
+    let contract = contract();
+    let mut deployed = runtime_bytecode(&contract);
+    let code_length = ObjectDiff::strip_metadata(deployed.as_slice()).len();
+    assert!(
+        code_length < deployed.len(),
+        "The metadata must be appended"
+    );
+    deployed[code_length + 2] ^= 0xFF;
+
+    let result = contract.verify_against(deployed.as_slice());
+    assert_eq!(
+        result,
+        solx::EVMContractVerificationResult::MetadataMismatch
+    );
+    assert!(result.is_code_match());
+}
+
+#[test]
+fn code_mismatch() {
+    let contract = contract();
+    let mut deployed = runtime_bytecode(&contract);
+    deployed[1] ^= 0xFF;
+
+    let result = contract.verify_against(deployed.as_slice());
+    assert_eq!(
+        result,
+        solx::EVMContractVerificationResult::CodeMismatch(ObjectDiff {
+            length_delta: 0,
+            first_difference: 1,
+        })
+    );
+    assert!(!result.is_code_match());
+}
+
+#[test]
+fn immutables() {
+    let compiled = [0x60, 0x00, 0x7F]
+        .into_iter()
+        .chain([0x00; era_compiler_common::BYTE_LENGTH_FIELD])
+        .collect::<Vec<u8>>();
+    let mut deployed = compiled.clone();
+    deployed[3..].fill(0xAA);
+    let immutable_references =
+        std::collections::BTreeMap::from([("1".to_owned(), BTreeSet::from([3]))]);
+
+    assert_eq!(
+        solx::EVMContractVerificationResult::new(
+            compiled.as_slice(),
+            deployed.as_slice(),
+            &immutable_references
+        ),
+        solx::EVMContractVerificationResult::Match
+    );
+    assert!(!solx::EVMContractVerificationResult::new(
+        compiled.as_slice(),
+        deployed.as_slice(),
+        &std::collections::BTreeMap::new()
+    )
+    .is_code_match());
+}
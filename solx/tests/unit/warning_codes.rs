@@ -0,0 +1,68 @@
+//!
+//! Unit tests for the warning suppression and escalation by code.
+//!
+
+use solx_standard_json::CollectableError;
+
+fn warning() -> solx_standard_json::OutputError {
+    solx_standard_json::OutputError::new_warning(Some(2072), "Unused local variable.", None, None)
+}
+
+#[test]
+fn suppression_per_output() {
+    let mut suppressed_output = solx_standard_json::Output::new_with_messages(vec![warning()]);
+    suppressed_output.set_warning_codes(
+        solx_standard_json::OutputErrorEscalation::default(),
+        solx_standard_json::OutputErrorSuppression::new(["2072".to_owned()].into()),
+    );
+    let mut output = solx_standard_json::Output::new_with_messages(vec![warning()]);
+
+    assert!(suppressed_output.take_warnings().is_empty());
+    assert_eq!(suppressed_output.take_suppressed().len(), 1);
+    assert!(suppressed_output.take_suppressed().is_empty());
+
+    assert_eq!(output.take_warnings().len(), 1);
+    assert!(output.take_suppressed().is_empty());
+}
+
+#[test]
+fn suppression_keeps_errors() {
+    let suppression = solx_standard_json::OutputErrorSuppression::new(["2072".to_owned()].into());
+    let error = solx_standard_json::OutputError::new_error(
+        Some(2072),
+        "Unused local variable.",
+        None,
+        None,
+    );
+
+    let (messages, suppressed) = suppression.filter(vec![warning(), error]);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].severity, "error");
+    assert_eq!(suppressed.len(), 1);
+}
+
+#[test]
+fn escalation_per_output() {
+    let mut escalated_output = solx_standard_json::Output::new_with_messages(vec![warning()]);
+    escalated_output.set_warning_codes(
+        solx_standard_json::OutputErrorEscalation::new(["2072".to_owned()].into()),
+        solx_standard_json::OutputErrorSuppression::default(),
+    );
+    let output = solx_standard_json::Output::new_with_messages(vec![warning()]);
+
+    assert!(escalated_output.has_errors());
+    assert!(!output.has_errors());
+}
+
+#[test]
+fn escalation_per_build() {
+    let mut escalated_build = solx::EVMBuild::new(Default::default(), &mut vec![warning()]);
+    escalated_build.options.escalation =
+        solx_standard_json::OutputErrorEscalation::new(["2072".to_owned()].into());
+    let mut build = solx::EVMBuild::new(Default::default(), &mut vec![warning()]);
+
+    assert!(escalated_build.has_errors());
+    assert!(escalated_build.take_warnings().is_empty());
+    assert!(!build.has_errors());
+    assert_eq!(build.take_warnings().len(), 1);
+}
@@ -0,0 +1,19 @@
+//!
+//! Unit tests for the source file watcher.
+//!
+
+use std::io::Write;
+
+#[test]
+fn default() {
+    let mut file = tempfile::NamedTempFile::new().expect("Test failure");
+    let path = file.path().to_owned();
+    let mut watcher = solx::Watcher::new(&[path.clone()]);
+
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    let modified = std::time::SystemTime::now() + std::time::Duration::from_secs(1);
+    file.write_all(b"contract Test {}").expect("Test failure");
+    file.as_file().set_modified(modified).expect("Test failure");
+
+    assert_eq!(watcher.wait(), vec![path]);
+}
@@ -0,0 +1,82 @@
+//!
+//! Unit tests for the Yul literal lowering.
+//!
+
+use std::collections::BTreeMap;
+
+use num::Num;
+use test_case::test_case;
+
+fn build(literal: &str, emit_llvm: bool) -> solx::EVMBuild {
+    let source_code = format!(
+        r#"
+object "Test" {{
+    code {{
+        mstore(0, {literal})
+        return(0, 32)
+    }}
+    object "Test_deployed" {{
+        code {{
+            return(0, 0)
+        }}
+    }}
+}}
+"#
+    );
+    crate::common::build_yul(
+        BTreeMap::from([("Test.yul".to_owned(), source_code)]),
+        solx::CompileOptions {
+            emit_llvm,
+            ..solx::CompileOptions::default()
+        },
+    )
+}
+
+fn first_error(literal: &str) -> String {
+    build(literal, false)
+        .results
+        .into_values()
+        .find_map(|result| result.err())
+        .expect("Test failure")
+        .message
+}
+
+#[test_case("0x1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff" ; "hexadecimal")]
+#[test_case("231584178474632390847141970017375815706539969331281128078915168015826259279872" ; "decimal")]
+fn overflow(literal: &str) {
+    let error = first_error(literal);
+    assert!(
+        error.contains(format!("Integer literal `{literal}` does not fit into 256 bits").as_str())
+    );
+}
+
+#[test_case("\"0123456789012345678901234567890123456789\"" ; "string")]
+#[test_case("hex\"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20\"" ; "hexadecimal")]
+fn string_too_long(literal: &str) {
+    let error = first_error(literal);
+    assert!(error.contains("exceeding the limit of 32 bytes"));
+}
+
+#[test_case("\"abc\"", "616263" ; "short_string")]
+#[test_case("\"\\x41\\t\\n\"", "41090a" ; "escapes")]
+#[test_case("hex\"1234\"", "1234" ; "hexadecimal")]
+#[test_case("hex\"0000ff\"", "0000ff" ; "hexadecimal_leading_zeros")]
+#[test_case("\"a\\u00e9\"", "61c3a9" ; "unicode_two_bytes")]
+#[test_case("\"a\\u20ac\"", "61e282ac" ; "unicode_three_bytes")]
+fn value(literal: &str, expected: &str) {
+    let expected = format!("{expected:0<64}");
+    let expected =
+        num::BigUint::from_str_radix(expected.as_str(), era_compiler_common::BASE_HEXADECIMAL)
+            .expect("Test failure")
+            .to_string();
+
+    let llvm_ir = build(literal, true)
+        .results
+        .into_values()
+        .find_map(|result| result.ok()?.deploy_object?.llvm_ir)
+        .expect("Test failure");
+    assert!(
+        llvm_ir.contains(format!("i256 {expected}").as_str()),
+        "The literal `{literal}` must be lowered to `{expected}`:\n{llvm_ir}"
+    );
+}